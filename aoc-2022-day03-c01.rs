@@ -1,17 +1,55 @@
 // https://adventofcode.com/2022/day/3
 // (part 1)
 
-fn item_priority(c: char) -> i32 {
+// every item type is one bit of a 52 bits mask, bit n being the item of priority n + 1
+type Items = u64;
+
+fn item_priority(c: char) -> Result<i32, String> {
     if c >= 'a' && c <= 'z' {
-        return c as i32 - 'a' as i32 + 1;
+        return Ok(c as i32 - 'a' as i32 + 1);
     } else if c >= 'A' && c <= 'Z' {
-        return c as i32 - 'A' as i32 + 27;
+        return Ok(c as i32 - 'A' as i32 + 27);
     } else {
-        panic!();
+        return Err(format!("invalid item '{}'", c));
+    }
+}
+
+fn items_get(list: &str) -> Result<Items, String> {
+    let mut items: Items = 0;
+    for c in list.chars() {
+        items |= 1 << (item_priority(c)? - 1);
+    }
+    return Ok(items);
+}
+
+fn items_priority_sum(items: Items) -> i32 {
+    let mut sum: i32 = 0;
+    let mut remaining: Items = items;
+    while remaining != 0 {
+        sum += remaining.trailing_zeros() as i32 + 1;
+        remaining &= remaining - 1;
     }
+    return sum;
 }
 
-fn rucksack_sum(list: &str) -> i32 {
+fn rucksack_sum(list: &str) -> Result<i32, String> {
+    // items are ASCII letters, anything else would not split at a character boundary
+    if let Some(c) = list.chars().find(|c| !c.is_ascii()) {
+        return Err(format!("invalid item '{}'", c));
+    }
+    if list.len() % 2 != 0 {
+        return Err(format!("rucksack '{}' has an odd number of items", list));
+    }
+
+    let mid: usize = list.len() / 2;
+    let part1: Items = items_get(&list[0..mid])?;
+    let part2: Items = items_get(&list[mid..])?;
+
+    return Ok(items_priority_sum(part1 & part2));
+}
+
+// original per character implementation, kept to check and benchmark the bitset one against
+fn rucksack_sum_reference(list: &str) -> Result<i32, String> {
     assert_eq!(0, list.len() % 2);
 
     let mut sum: i32 = 0;
@@ -25,30 +63,286 @@ fn rucksack_sum(list: &str) -> i32 {
     for i in mid..list.len() {
         let c: char = list.chars().nth(i).unwrap();
         if part1.contains(&c) && !matches.contains(&c) {
-            sum += item_priority(c);
+            sum += item_priority(c)?;
             matches.insert(c);
         }
     }
 
-    return sum;
+    return Ok(sum);
 }
 
-fn rucksacks_sum(list: &str) -> i32 {
+fn rucksacks_sum_with(
+    list: &str,
+    rucksack_sum_fn: fn(&str) -> Result<i32, String>,
+) -> Result<i32, String> {
     let mut sum: i32 = 0;
 
     let lines: Vec<&str> = list.split('\n').collect();
-    for line in lines {
-        sum += rucksack_sum(line);
+    for (i, line) in lines.iter().enumerate() {
+        match rucksack_sum_fn(line) {
+            Ok(value) => sum += value,
+            Err(e) => return Err(format!("line {}: {}", i + 1, e)),
+        }
     }
 
-    return sum;
+    return Ok(sum);
+}
+
+fn rucksacks_sum(list: &str) -> Result<i32, String> {
+    return rucksacks_sum_with(list, rucksack_sum);
+}
+
+#[derive(Debug, PartialEq)]
+enum DiagnosticStatus {
+    // exactly one item type in both compartments, as expected
+    Ok,
+    NoDuplicate,
+    SeveralDuplicates,
+    OddLength,
+    InvalidItem(char),
+}
+
+#[derive(Debug, PartialEq)]
+struct Duplicate {
+    item: char,
+    first_count: usize,
+    second_count: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct RucksackDiagnostic {
+    line: usize,
+    length: usize,
+    status: DiagnosticStatus,
+    duplicates: Vec<Duplicate>,
+}
+
+fn rucksack_diagnose(line: usize, list: &str) -> RucksackDiagnostic {
+    let mut diagnostic: RucksackDiagnostic = RucksackDiagnostic {
+        line: line,
+        length: list.len(),
+        status: DiagnosticStatus::Ok,
+        duplicates: Vec::new(),
+    };
+
+    if let Some(c) = list.chars().find(|c| item_priority(*c).is_err()) {
+        diagnostic.status = DiagnosticStatus::InvalidItem(c);
+        return diagnostic;
+    }
+    if list.len() % 2 != 0 {
+        diagnostic.status = DiagnosticStatus::OddLength;
+        return diagnostic;
+    }
+
+    let mid: usize = list.len() / 2;
+    let mut counts: [[usize; 2]; 52] = [[0; 2]; 52];
+    for (i, c) in list.chars().enumerate() {
+        let priority: i32 = item_priority(c).unwrap();
+        counts[(priority - 1) as usize][if i < mid { 0 } else { 1 }] += 1;
+    }
+    for c in ('a'..='z').chain('A'..='Z') {
+        let count: [usize; 2] = counts[(item_priority(c).unwrap() - 1) as usize];
+        if count[0] > 0 && count[1] > 0 {
+            diagnostic.duplicates.push(Duplicate {
+                item: c,
+                first_count: count[0],
+                second_count: count[1],
+            });
+        }
+    }
+    diagnostic.status = match diagnostic.duplicates.len() {
+        0 => DiagnosticStatus::NoDuplicate,
+        1 => DiagnosticStatus::Ok,
+        _ => DiagnosticStatus::SeveralDuplicates,
+    };
+
+    return diagnostic;
+}
+
+fn rucksacks_diagnose(list: &str) -> Vec<RucksackDiagnostic> {
+    let mut diagnostics: Vec<RucksackDiagnostic> = Vec::new();
+
+    let lines: Vec<&str> = list.split('\n').collect();
+    for (i, line) in lines.iter().enumerate() {
+        diagnostics.push(rucksack_diagnose(i + 1, line));
+    }
+
+    return diagnostics;
+}
+
+fn diagnostic_status_str(status: &DiagnosticStatus) -> String {
+    return match status {
+        DiagnosticStatus::Ok => String::from("ok"),
+        DiagnosticStatus::NoDuplicate => String::from("no duplicate"),
+        DiagnosticStatus::SeveralDuplicates => String::from("several duplicates"),
+        DiagnosticStatus::OddLength => String::from("odd length"),
+        DiagnosticStatus::InvalidItem(c) => format!("invalid item {:?}", c),
+    };
+}
+
+// one row per rucksack, duplicates as `item first_count/second_count`
+fn diagnostics_table(diagnostics: &[RucksackDiagnostic]) -> String {
+    let mut table: String = String::from("line  length  status              duplicates\n");
+    for diagnostic in diagnostics {
+        let duplicates: Vec<String> = diagnostic
+            .duplicates
+            .iter()
+            .map(|d| format!("{} {}/{}", d.item, d.first_count, d.second_count))
+            .collect();
+        let row: String = format!(
+            "{:<5} {:<7} {:<19} {}",
+            diagnostic.line,
+            diagnostic.length,
+            diagnostic_status_str(&diagnostic.status),
+            duplicates.join(", ")
+        );
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+    return table;
+}
+
+fn json_string(s: &str) -> String {
+    let mut json: String = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    return json;
+}
+
+fn diagnostics_json(diagnostics: &[RucksackDiagnostic]) -> String {
+    let mut rucksacks: Vec<String> = Vec::new();
+    for diagnostic in diagnostics {
+        let duplicates: Vec<String> = diagnostic
+            .duplicates
+            .iter()
+            .map(|d| {
+                format!(
+                    "{{\"item\":{},\"priority\":{},\"first\":{},\"second\":{}}}",
+                    json_string(&d.item.to_string()),
+                    item_priority(d.item).unwrap(),
+                    d.first_count,
+                    d.second_count
+                )
+            })
+            .collect();
+        rucksacks.push(format!(
+            "{{\"line\":{},\"length\":{},\"status\":{},\"duplicates\":[{}]}}",
+            diagnostic.line,
+            diagnostic.length,
+            json_string(&diagnostic_status_str(&diagnostic.status)),
+            duplicates.join(",")
+        ));
+    }
+    return format!("[{}]", rucksacks.join(","));
+}
+
+// xorshift, good enough to generate inputs without any dependency
+fn random_next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    return *state;
+}
+
+fn rucksacks_generate(count: usize, items_per_compartment: usize, seed: u64) -> String {
+    const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut state: u64 = seed;
+    let mut list: String = String::with_capacity(count * (items_per_compartment * 2 + 1));
+    for i in 0..count {
+        if i > 0 {
+            list.push('\n');
+        }
+        for _ in 0..items_per_compartment * 2 {
+            list.push(ITEMS[(random_next(&mut state) % ITEMS.len() as u64) as usize] as char);
+        }
+    }
+    return list;
+}
+
+fn benchmark() {
+    for (count, items_per_compartment) in [(100_000, 12), (10_000, 200)] {
+        let list: String = rucksacks_generate(count, items_per_compartment, 0x2022_0003);
+
+        let start = std::time::Instant::now();
+        let bitset: Result<i32, String> = rucksacks_sum(&list);
+        let bitset_duration = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let reference: Result<i32, String> = rucksacks_sum_with(&list, rucksack_sum_reference);
+        let reference_duration = start.elapsed();
+
+        assert_eq!(reference, bitset);
+        println!(
+            "{} rucksacks of {} items: bitset {:?}, reference {:?}",
+            count,
+            items_per_compartment * 2,
+            bitset_duration,
+            reference_duration
+        );
+    }
 }
 
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("bench") {
+        benchmark();
+        return;
+    }
+    if std::env::args().nth(1).as_deref() == Some("diagnose") {
+        // rucksacks list read from stdin, `diagnose json` for a JSON output instead of a table
+        let mut list: String = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut list).unwrap();
+        let diagnostics: Vec<RucksackDiagnostic> = rucksacks_diagnose(list.trim_end_matches('\n'));
+        if std::env::args().nth(2).as_deref() == Some("json") {
+            println!("{}", diagnostics_json(&diagnostics));
+        } else {
+            print!("{}", diagnostics_table(&diagnostics));
+        }
+        return;
+    }
+
     // example
-    assert_eq!(157, rucksacks_sum("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw"));
+    assert_eq!(Ok(157), rucksacks_sum("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw"));
+    assert_eq!(Ok(157), rucksacks_sum_with("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw", rucksack_sum_reference));
     //// user puzzle input
-    assert_eq!(8243, rucksacks_sum("FqdWDFppHWhmwwzdjvjTRTznjdMv\nZBJrDVfQcfSRMLjZnjjM\ncBffPfbrbQcgQJggfVQJBPbCwlPtWFDWHFHhpmmGlGmlqmDG\nPNbMLgmPgRDgRtMPDdmdbmdmQrTBVCZnVnpCnNHHVZBNVZHc\nljvvqhlvshhnrcpBZqpTcr\nzGhWzFTJvsFttddWbMRdmP\nhhGdDhfdDhmdnHwtzzRtdzbzQQQg\nWTTZJLsvLRJCcWJWScWWLtzjwBzBttbBzSwVQjQBtt\nvLJcLLTRsvsTZPqHGhFHGhhPhfqD\ndJszLvzvCZZsJmszCrrdFmpppMpDMQPMgmDcDgSS\nnRjRbnnjlNhblnjtVtQlWttMFPfMfPgDMpBgSBPgBS\nGWVQTVTnTNhjblQNRsdJLLdTCdzdZLrdrJ\nRnLJBfmJfmNBHlQvvbdQ\ngrhgrtqgjJhhggNHqvwWqvbNlbHw\nGVTTsFFjJjVVFVGCFTJDDjhFcZmRMZfnZcncSpMSGcRPZpLp\nbwSNRNSRzSWnPnJRldMBMQmMlsCcPPCP\nFGpDqTTVFFprpjLVQMMGtMclcmHGtBdc\nTjhhgTLpVZhpLDZqrTqZVpBSwJwzNnWNWJvzzNSggwwN\nPHTMsmwrJMwLJvJddvdHwvcWnnWfccqGnhhfGcDqsnGc\ntZVlzQZblBgcSqqRhRmzqf\nCmlgmZlQtFtZNlVZdHLMrMvvCrrvvTTC\nPrDGBBddprmzddrSqccRgSTpqbsMRR\nhvLtfFNvvZNfGGfRgbqsRNlTSSgsbM\nQQQjZHHQjvththFffCHPPzmGrmDzDWrWBw\nGrjGrpjjCsnwhsGGPwlPTPLPVttPqLVl\nHzSHHhczRlLTHqqq\nDbhvFSSzQcZbcFbcQjrJrMJmmZnGJmJnjn\nmvTDsJLwzlWNDDnZngZNdd\nBVtPqFMqtvQFqPqjFBMVtRZGNGhfNcfQdpfgnQgNcNgp\nHBHtPbHCLCzsLJvT\nnVHVFfggbQVmFFfhLpBpBTrfLBCB\ncjjRwJdqtwwwNNjcwRMwnLNTpNsGCnLsLGhBhpNn\ntJqStcRMMMjPwlnvzgQWzmHzlmQl\nnNSRcDHRmHhhDZZZdBDfWJdfrJ\nLGrGVtjCPCbbQQQQLvQpbVQZzsqsBMdBqMvZMMJZqJvdBW\nGCPLlLPPPbTpbCbpLPpVlmrmmrHhHcHnHrHHgTFFnm\nrQbnBrDTQcdpHttt\nqNsNpfjLpNLRNqLMtdVsddcmVzdzVh\nWJfLCfvpWpNLbwwwBwbZFvlZ\nZslGPhBWBJbNjmbTmSWN\nqtDCZcfZtDjbjCQvmmSj\nttpRzqtqRLDzpRtDdtPlhhZZGBHGPsGZPhwd\nWjtcNnMtztGFrsNjcgRHvdwHhhjRwlhwlg\npPSpPBPVqJqBCCPvZdHlVGddwdhvgH\nqpDBpBqPTDLqGLmpTPqbDmWMNrFrrFQnFMNnMNQtMm\ndNwfsjFLQLFNBhTCTPPTBJhhTP\nVmVMgqgRVHtztmgqgzgqRzgMPWCRWTpPCCPWPThDdZPCRZJp\nVMqtMgGHmgVMvmqMMtGMSmbslnQFcsndfjNNsnfscnbddF\nqbWcqrFNCJGSChvLGv\nMnslRSpSVsGgGhDDwLvl\nRjMtfnpmmmjSWSbjrNPN\nrsCsqTVgfCnQFdCznQ\nZRsBvMvmZMGQQmFnDmQzzQ\nMlGLlBjRGjjWWGRGMlNrLLrssTPNfLcTgrVr\nvpSBBcJnWnSmcqmcChdcgf\nNRNPFDwwDbThqTJffZQHhd\nDzPDNVNbLwPzMLRbNMDjBlvpWjrvrVJjjSsBjr\ndndGpnWdVnBPFFHTBgGH\nqCcCmjqNNJDtcJQjqJqMCQJcFTBRZRwBZBHDPRHBHZDbgBBR\nszJcCtNQqjqCNjjNhdgWfLLdhVWsSVnr\nNjJLgSLGGCLWvqNqNBvwFb\nhtmVmtHnlZmDVhtmhblmDMHddBRvwwZQQqWPFvFwdvRWdR\nHnlnHfDsbSTbJzsz\nJvZfsPsPhRfZZnCdtnmjHVRLCH\nTzcczTtgqpgGSTlHHVHCGjCdHdnQQQ\nwDBDSDlWzwwzDqqMtrMrfsfbNvJNJB\nThfQTdQzQbgdhdNbJFJSlbBLlmqqHBSHCRHsHm\nrWBWWPcvpGGwjPPpvpPGplRSnLLnCrLRsRlLsLmLmR\ncGWPPMtpZDwpMpJQBQQQDBBVNfdQ\nWcWDRLSzFrRFFccPmnssMrGtmMnnGm\njvvgCCTvNqTtJqsnqwPBmspZMB\ngbVgbvlChJVbCdbtTgjdLWSHWcHSDcQzFzRzDF\nzTrHgrFWRrWvMpPNBVZZHVfN\nhdnlltGLtGSQPVLSNBQN\nCqGGtGwlhlGdtGmbtjtmmvDRFvVFTTRDRbRgFJRbDr\nDTFpQFrZDFBDFrTNFjSWJsWlWjsRJcslsp\nzzPqvdqNzvqzfzMfzqmCzzfJsclWjSSRWglgjclWSmcjlJ\nhfPGwhhvMGCVCdddhQQZQnNrTDQnFrtn\nDDMFjwVTgVmMWgVpdqtlJnpvHHnslw\nfFfZzLSfzBfZBZtqJJHsnSsldlld\nGLcNhRGLZBhmDWTjDTWF\nFbVcQRVRBFfNFfccVfZcWddnGrrHncWtdHsZ\nhwGwjgTSGrssZHHTdn\nLvPvCJgJLwjlSJmSPLvvgGfFBpBVfLLMBQMRRbzMVb\nrfMCJPBMMCrSCSBGZZqRlRLzqhqh\nLDvdNmbgHjHgnmnvnHjgDjqlZhZzszhqzWsRWRRNcRNZ\ndmbvHDdnjDjVmjTmHjJVrfprPCCVtLSMrSpJ\nfdfTBfNVZffMmdfdhGhcJLJrvnlJvrBJ\nFFWFWTzWSWtFgPHgRPWTzggpJcvvSCGSGJnrvhhrrJhlGlGn\npztFjzRTqWzgHqHWtPtPFgmsNfqbDdwDNVwbmfwbdNsb\nzCHvDWwvCwgpNRCWWHttCwvNPVTqrRrVbbsnbqQPbVsbPrqG\njBBcmZBGmBSSJdmhBVbnnQrVbVqqVPbhbP\nZfJBmLjfJZMcdZmJffGMtHtwNCvWwwwztMzg\nrgFgllfdpFlTHfTnfnNPNtPBBVtpzmVVPmmP\nbLSSbGhGWSWmLzztcQPCQC\njvwhhwRwbwshjGhWwsRRTFflFJFzfddFrHzTrJ\nGSwgSdwfvdfvwgGwBLdJbjjpmFjSTRpqHmRrjptrqt\nNDMVMCsWQVCsQFjFTHjVLqjFrp\nPzWDDCNNlCWfbhZZLfBGwl\nclFLFpFJcVmmWWgWWcWTdwZqCZCZqDqDDSwvwrdHCZ\nGGLfGtbfMNbbLGtzjBNnsMjjZCwDvQZHrHQvDHHHHHHrrNHq\nBthbnfPGMstmJgRPpFRTLL\nVRcdVRPTgVTLVMwmggJBwblJFlmb\nCqDDQsjCCrnnnQQtDcrnGCssJZNvZbvNQmZwmZlbNbwFNwmF\npstjcqrprsHrfDpnrCnHCzRhSMhMMMRVPLMhddfhzT\nQNDQThccDghdcLLgVsrVLVlMGM\nBbbWpppFFpnfnFbBBPRMTJMRsGlJGlJVLf\nwpnbmvbnSwSnmzHHzwwhQqTctNCcqdNqZvhQjj\nlwCLwLjzLhLHCvwjGCZJbQSHdBQdDdbDtdSQ\npnzcTVsszpncgFdJpbtDBDttDSJS\nzTPcRrfzgzCCvGRhLLqW\nBgDbztvdDzLZZwMbDDcFHQQJPLWWPJQJHTHF\njfpNqSrpCqNfNSpjCqSqshNFRQJcJWRRFssgWRHWWFWHQJ\nmrnNNgNfMZwmDtZw\nZrrFPQsQPRLcvPJvhg\nHpmTjnBmDDDwqtVcHNvJhNVRhgvh\ntTpTGfnttqwnqQJzFdzfSdzSzr\nqVVZqfVNdnBZMNzNnPzfMqbzJvFSjSllvjBwrvrvFrlsrjJs\nLhHtDTmWmWmGDhGLWHghHLCwrwRjjsPJrSjFjFrFClvrww\nmDDgtcLcmtTWTTHhpWLDHhDNMqVnqfdqbZdQpqVbzZVPVb\nWGpVMtGZplgHVWMtZpZFHJjndvFdjddJhnjLHF\nDTrRcSSccfzcCPDCTnvJvjbrrhjNvqjqJL\nzczwPDTmfZZtgLwWlp\nsTtTpvMjpBdmTlhlBTdvsvdncmVcVVVVHzFqVnFfqzHzWV\nGGwQRZNCRgDgsDDbSbSgfGFFrHfVHVVWrHWWcVFF\nRZRPgbNSJDsTsplPLsvd\nGrGNGhpnPFFBfCQCMwrVlwTC\nPPWHmmSvSvdTfJvJVVfCfJ\nRjWDmDdDbjcSHmRRLRRHjdDBsFFbBngBzhqbBhpqBPgFFg\nTfdNjJjmShGcWvQNQqcNFN\ngsRZRMqbDpsHHnZnngMZFPlzQRPWvRtPwtRWzlPv\nbrgMMgZCLsDpHGGjfGJVSCqddT\nBdSRjHScGMVjGdcScwLgqQqphNqNDqBBQW\nzzQClvtttrwqrwgCwp\nPQJTzvJJTtJQlvQftmfdmdmGRcSdcMSGdS\nPjPwvwlfGlGCGwppWBsWQVVQnpCQ\nFRSHzMJdrfRnpmpWmp\nTzJTMNNrHLJfTJccvjqwwgGLZLLc\ndCpjsGvcsLvszTrRRlRrDJ\nhPNqqpbhFlrDnPrRnz\nSBbphFNtLvvSfLfc\nzTFnnZzqrjFVnZTrtwMDptbpMwMMBDzb\nJGPCjWGgJjPWGJjNchmbBRpNNDsRsNBbbRBppD\nJWCggWSCmhCmvWHTQrTQfHjdVlQZll\nWWbrmZjbmjpbWSmcWHSbLddwvDggLFDhFrRlFFDw\nqPMVzTPQVfzvVzBQTMtRswwlDdhDghhsfLwdhs\nMMMPQBPJPzCVzvzQVtBJJMzCbmSZNWbSccHHmmZpWSZGZS\nBgmMgjlBMjHPssBnwphtFwhSVVmGGwFS\nrfCqQQrfvCQNRqCnCthFVSGSGtVS\nTrzLNvWfQvrWQZNWsnHsssBBcZBMsDdZ\nCtJCddDHDDPGHCdNVLPBdLDbbGFbwZSsSSZrfFFrGbGlsb\ngvjpnvhRphjmpmSfsTSnPZZrZfrZ\nghRRRhQgWvmPWtHHtLdMtH\nqNgQgNgNQFVbqVQDMRZMDRBHHJHRFh\njnTTPzWZCzrWzGRHMrhmBvJBSBBM\njtCGWnCWPTsTzlctWPtqLbwqZggNwgLbwdVq\nDJgJDgFqCGlhFDGDCWhqCwRfpbcpbsgsVscRpwbwws\nSmLZQmMVvLbsbssNQsQN\ntHZMLZZZMVtmnMHWDHjWlGhFDqCl\nPZRmjlDBWRBWBQMdQQBDPRhfzSSTTnfFnfzFpVFjfSVFgS\nwwHCHLtLwnzNHVTZZV\nrJLtZtLZcCrvJwcbrGLvrcDQDhPldhGdmmPRQhhRBlPM\nzShzVhbqlbpbRNRscBNwCc\nDfmFWnfmnMFDdngdngvJFHgQQNwNsHHCQCwNTTzRcN\nFFFZMWWZZJMmJJMFFpztlPtSllLVphttGZ\ngWhGwmwQGhVwGzBMnDFmdmDDLbCnLn\ntRRHNHHlPHPfPltllNNNRsVPFrTrTbrMMTTDMdLFCrFdPP\nHvRvvHNpNvfqSshwqwVQZWhggwhw\njDDRDVqNsRMMVFjFbtzpBlpllCBlhSLHSStH\nJJWZZTwWcmZCzQShHhzhhm\nwTrgvTwccvdcfvJvWJrvJTNzNMfGNNGjFRsjNDbfVzDs\ncVWPPThWctcFRdQpzLTzBL\nNNCqwNSrsDqNSSgLgffDCNpBdRvvdpmHRQvBdBqvdzBB\nCgbffsDJsSsNgbgJrlcGhGtjnWcJPncjWL\nRwwTGRjGlwWNgjgfQVNmjj\nHJPdLhLzhbtbdLPLbHHJLdcfBffBgrFQVZgzvgBfvrvvmr\ncmchnhtPqLSJJbdPLntlsDGCTWWDsDRwWWWTwS\nDDDBsPGPbwhDcDcj\nrgfNgCmHMvrrttvtfmNLgrLcQQJTJhcnjTQHcZlwlQHnwj\nwgfLmgFgWdBFzSVR\nRwsbssJjnbJwwsGPPdDLfTDLLLWvWNDGDpWD\nzVtHqzHHVcBQTdpSgvQDSpTS\nHHhFCdrrHchmrhcmwsjZwRwMJlshJJJZ\nBdQgtJMLBqshLfNhbccfFhffNc\nDnVWvvpvWvzrpwRWDbTvbTjTFGlfFSfNSFHGmFGNFmnPlmFS\nwDRDZzVWVjrRVjzrQgQdbgCZLCsCQtbd\njQSgWjQmFFvHmjHWVVpZbGlbGlfGpbfGGWpf\nzBPtTqzPBcdwrzPJwqCztTNvCGpbGfDnfhZpNGGZGZbG\nMTzPtcrcwzTttdBJwPvFVHjMLFHQMgsSLHvs\nBHtjmmTtmDtHZjMMdNzCzCWcWZsZdsZs\nJRRJVPLwQJrVMPJLVVwChpRRhcdzcNzhzChNdW\nSrFwfGwGSnnGPlBMDtjbllggBn\nrsMDTrgsBNBgMgDBhfhDghrtcRWJttcmGRWLGQQLJRrqRL\nlnVVjvPbwpndvVwlVCjVwtLcsmLLqWWtttlsmGGcmm\nSsjVSCZbgTBTfNZH\nwlddvlldlBzqSmStdqmmngwgDCgrpMpgCngrMCfn\njVLTHGRHjjvPGcDrfNMbnpngVbpf\nRJRTTRZJLRJQZcGGHLhHvTdmBFWFtWzqdWWQlFzqBFWt\nHDgZHpZSDpBQdRpHHRsDBNNzTvfTQqcqNNTqTqPQvl\nrnMFFMFJwmNgvmfTzcfq\nCWMtjCWMCCLWrWVWJwBbpgtRZSHbddZHBDDb\nhjLWPZJpZptwJghSfgHTMTgRMR\nlCcrblcnlzqDsvbfffTRSMnffjHTgj\nsqscDcblGDDpWPwFGwjLLp\nplQtRqRlGpPPPLZtmtpttRtJjHJvddLTHrLHJJjbdHvrrN\ngcMgTBWBCTczjnvNznnbgH\nFBhSwsWFWDhQZQqTTZllsT\nWsVttWDbvbtRjDVtDbDbJjrjFpCCcBrSCwrrjwww\nnzHqGNMfHqMMwJLLsNBrNcBS\nqgnHflgzHlqlZzhnzssfzbZtmVvmTQvQdTtbbDbTvv\nRtQCBbJDFhJtQtZtCbMnVnPVfGPpJVJWWmwJPG\nNsczRsrdrNrjlcSTGGnwmGmnmVPWwmVT\nSHcljRHzNHHjSHcrgNslcczgZtDgMDBCvQQbhDBCbFBCFCCF\ndRTFRJTRTgJzSSJmzJfN\njLbQllLvvvrQlLQBrvQmFBGzCFtPSMmSGCtPPF\nQjsvDvqlvrQQvnsLjDWghTwZWcccFdRVpc\nbWnDbMJMFbhZSfngpfpd\nHjcrlvjRjrjlLqTqpwQgpfSQgghZgvgf\nTHNTjClLHDCCpWmbtC\nNpBNsnFMLBcPMZccbQGblWRgGZmbRWdW\nCqrJTHCvDfrfwwJHRBvRGvgQmvmlWtlR\nrHzJJJDDwJCqjHTwBLccMpFhshnSSsMz\nbqVqqrDMpLFbLpJJQDMjbpZndwsvwHHswvnvnnZslG\nhgfRghhBWgfzBgZvncvcGvWrnZsG\nfTSSSBtTzhPththRrrCBzVLqMMJQjVJJCLjFjjqjJq\npQlCJQjVvVGGnjNqFbFP\nwTqtmSmTBhstmstTmWRSdLdfPdNGnhGhdPNnNnrN\nmzTZBwWTcSTsTmZRRmzTsVMMVMQMDJgVqqMgcJlVDl\nvVwCqdCDvMrlDJCqrDMrPdTFWwZNbRcbmZWQbRQZWZcQWm\nfGSfhfjHGBlhSpRmpZFZNbQWjj\nntGlgSSGgStVqdMCDCJnqd\nTlTRCrhCpmnCRtMRRWbvMBfwBB\nDVdQcsccdczbtQBWvmQBBW\nPgHPNcmcqqTphlHTCGrT\nGVLCBmdLVtlrmqGCqrTCGnjGSfwzNfPzfNNGzSSw\nrMDcWMJsDWRvRcsZPwpJpSzjwwfFjfjJ\nhcsscQQsDcWbHbmLgTbVgTmlrbgq\nVwJndPThQQjdvbrb\nBHlzFFjlZGBBlZBDFSmllfGggLNbNvggtCgNrLQLbfvbfg\nSHSHHlZSGZSDHGzHssGmnjhjphJPPpPwnsJRwPMM\nQFFGpfGtwgtjwvpwpGGjjBHgqzNNllHRlRllNdBRJl\nSWVDWVrDhnWhnqVnWPrhcSldBJPsRMMdPHsRdJHRMMBP\nSWZZcmnnZnSCLZDZftFFCvbfTTqTCTQF\nTvZBTFZpshPggBNN\nbbctWQDjcnwtwDDDllQzLfgsPzqgrsTPLshWfP\nbwRbQRnmDwtbTjmwRJQRQVZHpCpHVdvpMHZZMHZJvd\nCfqzMCGvGqNrCFFNwcMBbnnbbtBbDStw\nsJjsTjvVjLVlLLbtHSnSSwQsDBSH\ndJlvLVgJTRVPWWjjggCzhNGzzGZdhqrdmqrq\nzGSHWGjzpRsWVfsNwZ\nBmPCLzPlJBBvQmLFQrwwrVtfqtqZtsvwfR\nhLLzlmmBPFFLJMlmgDTMgdSGbDgGHbSp\nppngVjZwNZwwVJjjnnVVJJJpLrsgmsmrbctsLcLmscDggDsL\nvHvRzRPvQPPRqPTlffRTrssbtqmmtbcMcNrDrrtD\nCHWdWzWQzdChNVZjZZwB\nLjVFhwjbFggMdVggTVMNRWRGWqQWtRNWGlQW\nDCzCpJppzJJDScFBzvBGnRWGWrNRQNNWGtNGqB\nvFJSzDSszzzccSZHzDDJmvSJhVwLjfVTPZLfwLgjThLZwhVh\nFMSSNScRlSGzfqWbqqcpWBhpmW\nTrPrnQNnJwtQpmmpmbbL\nvwvCsJZZnnwVnVHsfNjDRgSjRzgRjZjN\nCRhsghlqlvjhPslQdrMndMTWdPnTMJ\ntcDNFDpDSDwDtHrSDwDtFmtpJzWTnzQJpzQGnGWQMWnnTGBd\nZfFSmSfwcwcScZHtVRhRCqLqCfrVrrfg\nvHPsBzvRvhCzbwbnjHLVmVbW\nggdlpZNdZdflWTGpVMjgVbqjVmngnVbr\nlNpfllFGpZTtclDZzSWQFCWWzChPzQhS\nddbJQGsgJcQccCjjTC\nDDflHqNNNjCCPNbT\nLFpLzfzqHzLbLvtndJhs\nmHtbGdwzmtHZrtrHtHGwrmtcFgBFRsBFcdglRsfsdgJBNN\nWvpSVjCCTCSPVDqTVjLqJwWRcWsNlgBcBlfWBRfl\nqDwjvqjVTDDLSPpnjqVnzbhbZbrGMmHzbMHHhh\ncMrrVsdtCdVtwqdHgLjPLFLfcfZZJB\nzGSlGGvpDWWvbSSNTggZPPTTJLZPjPzJ\nbNQDWbjlGSGNnWGblbQDQCmdqhsMtrsVnCrtnwdnCt\nWZSnCCMMdMMMSJMSVZmmFqVjqjVwVjjc\nzvzTlQQQQTNGbmQTjqBjcwwwGgBHGwqB\nmthbtmlPhTrNzNhhPLzlPzWfWPJdRCWspPWSSRsWSnJp\nnmqsjNFqNLcVlPNvRdvPPv\nMrggHrMpbtTpgpDptlQRJJldVRPBsDQvvV\ngrgrTzfTGSFGsGSCLc\nhjdjCfQCLdQcWMfDDQhLsLCjqvVqzzZZprBFbzVdvVnqnBbd\nNTglmJGJRnRZVrVvFr\ngPGgPJSTPJtHncnjWDhDMDhP\nZWWqBqqmgFFDMTTWDMwwcW\nddGPpJQPprRSCrQzJPJGjHDvssMTDZDwcHhMsHsMHS\nzNRGrCRRCjPpdGQJNglqntnbtgNBZtFqtt\npslQSspQrqHfgMRl\ntJBTjsTLGMBGMFMg\ntDwnhZdnLdZDwczzcPvsVvVW\nnZnMSDnGtnzmzWZZcMmgMcHwFFVHhHwbHsVrwQhVhF\ndlTqNqfTjdJflCppCQQRHVwFRJrvJsFVQs\nLNTqBLPprpLLzWtSnMZBMDGm\nPBjlPvvcJlJzwqjnnjLnwm\ndMfdpVfVhGVfVpVTtWWbzwngLMLwMRCmgmLMmsww\nSWppdTrprThhrGVztcrllrQJZNlBZlrNZB\nTTrNcjGNWDdDPDpPjDPNrbmFPfQSFmfSMCmCfSmSSR\nsshhqshzBVnzHgwCMbCwcfmRFmRMbm\ngHVVnBzJcBgVhtZZtgJhpGrGNpvDpddvprNGrlTJ"));
+    assert_eq!(Ok(8243), rucksacks_sum("FqdWDFppHWhmwwzdjvjTRTznjdMv\nZBJrDVfQcfSRMLjZnjjM\ncBffPfbrbQcgQJggfVQJBPbCwlPtWFDWHFHhpmmGlGmlqmDG\nPNbMLgmPgRDgRtMPDdmdbmdmQrTBVCZnVnpCnNHHVZBNVZHc\nljvvqhlvshhnrcpBZqpTcr\nzGhWzFTJvsFttddWbMRdmP\nhhGdDhfdDhmdnHwtzzRtdzbzQQQg\nWTTZJLsvLRJCcWJWScWWLtzjwBzBttbBzSwVQjQBtt\nvLJcLLTRsvsTZPqHGhFHGhhPhfqD\ndJszLvzvCZZsJmszCrrdFmpppMpDMQPMgmDcDgSS\nnRjRbnnjlNhblnjtVtQlWttMFPfMfPgDMpBgSBPgBS\nGWVQTVTnTNhjblQNRsdJLLdTCdzdZLrdrJ\nRnLJBfmJfmNBHlQvvbdQ\ngrhgrtqgjJhhggNHqvwWqvbNlbHw\nGVTTsFFjJjVVFVGCFTJDDjhFcZmRMZfnZcncSpMSGcRPZpLp\nbwSNRNSRzSWnPnJRldMBMQmMlsCcPPCP\nFGpDqTTVFFprpjLVQMMGtMclcmHGtBdc\nTjhhgTLpVZhpLDZqrTqZVpBSwJwzNnWNWJvzzNSggwwN\nPHTMsmwrJMwLJvJddvdHwvcWnnWfccqGnhhfGcDqsnGc\ntZVlzQZblBgcSqqRhRmzqf\nCmlgmZlQtFtZNlVZdHLMrMvvCrrvvTTC\nPrDGBBddprmzddrSqccRgSTpqbsMRR\nhvLtfFNvvZNfGGfRgbqsRNlTSSgsbM\nQQQjZHHQjvththFffCHPPzmGrmDzDWrWBw\nGrjGrpjjCsnwhsGGPwlPTPLPVttPqLVl\nHzSHHhczRlLTHqqq\nDbhvFSSzQcZbcFbcQjrJrMJmmZnGJmJnjn\nmvTDsJLwzlWNDDnZngZNdd\nBVtPqFMqtvQFqPqjFBMVtRZGNGhfNcfQdpfgnQgNcNgp\nHBHtPbHCLCzsLJvT\nnVHVFfggbQVmFFfhLpBpBTrfLBCB\ncjjRwJdqtwwwNNjcwRMwnLNTpNsGCnLsLGhBhpNn\ntJqStcRMMMjPwlnvzgQWzmHzlmQl\nnNSRcDHRmHhhDZZZdBDfWJdfrJ\nLGrGVtjCPCbbQQQQLvQpbVQZzsqsBMdBqMvZMMJZqJvdBW\nGCPLlLPPPbTpbCbpLPpVlmrmmrHhHcHnHrHHgTFFnm\nrQbnBrDTQcdpHttt\nqNsNpfjLpNLRNqLMtdVsddcmVzdzVh\nWJfLCfvpWpNLbwwwBwbZFvlZ\nZslGPhBWBJbNjmbTmSWN\nqtDCZcfZtDjbjCQvmmSj\nttpRzqtqRLDzpRtDdtPlhhZZGBHGPsGZPhwd\nWjtcNnMtztGFrsNjcgRHvdwHhhjRwlhwlg\npPSpPBPVqJqBCCPvZdHlVGddwdhvgH\nqpDBpBqPTDLqGLmpTPqbDmWMNrFrrFQnFMNnMNQtMm\ndNwfsjFLQLFNBhTCTPPTBJhhTP\nVmVMgqgRVHtztmgqgzgqRzgMPWCRWTpPCCPWPThDdZPCRZJp\nVMqtMgGHmgVMvmqMMtGMSmbslnQFcsndfjNNsnfscnbddF\nqbWcqrFNCJGSChvLGv\nMnslRSpSVsGgGhDDwLvl\nRjMtfnpmmmjSWSbjrNPN\nrsCsqTVgfCnQFdCznQ\nZRsBvMvmZMGQQmFnDmQzzQ\nMlGLlBjRGjjWWGRGMlNrLLrssTPNfLcTgrVr\nvpSBBcJnWnSmcqmcChdcgf\nNRNPFDwwDbThqTJffZQHhd\nDzPDNVNbLwPzMLRbNMDjBlvpWjrvrVJjjSsBjr\ndndGpnWdVnBPFFHTBgGH\nqCcCmjqNNJDtcJQjqJqMCQJcFTBRZRwBZBHDPRHBHZDbgBBR\nszJcCtNQqjqCNjjNhdgWfLLdhVWsSVnr\nNjJLgSLGGCLWvqNqNBvwFb\nhtmVmtHnlZmDVhtmhblmDMHddBRvwwZQQqWPFvFwdvRWdR\nHnlnHfDsbSTbJzsz\nJvZfsPsPhRfZZnCdtnmjHVRLCH\nTzcczTtgqpgGSTlHHVHCGjCdHdnQQQ\nwDBDSDlWzwwzDqqMtrMrfsfbNvJNJB\nThfQTdQzQbgdhdNbJFJSlbBLlmqqHBSHCRHsHm\nrWBWWPcvpGGwjPPpvpPGplRSnLLnCrLRsRlLsLmLmR\ncGWPPMtpZDwpMpJQBQQQDBBVNfdQ\nWcWDRLSzFrRFFccPmnssMrGtmMnnGm\njvvgCCTvNqTtJqsnqwPBmspZMB\ngbVgbvlChJVbCdbtTgjdLWSHWcHSDcQzFzRzDF\nzTrHgrFWRrWvMpPNBVZZHVfN\nhdnlltGLtGSQPVLSNBQN\nCqGGtGwlhlGdtGmbtjtmmvDRFvVFTTRDRbRgFJRbDr\nDTFpQFrZDFBDFrTNFjSWJsWlWjsRJcslsp\nzzPqvdqNzvqzfzMfzqmCzzfJsclWjSSRWglgjclWSmcjlJ\nhfPGwhhvMGCVCdddhQQZQnNrTDQnFrtn\nDDMFjwVTgVmMWgVpdqtlJnpvHHnslw\nfFfZzLSfzBfZBZtqJJHsnSsldlld\nGLcNhRGLZBhmDWTjDTWF\nFbVcQRVRBFfNFfccVfZcWddnGrrHncWtdHsZ\nhwGwjgTSGrssZHHTdn\nLvPvCJgJLwjlSJmSPLvvgGfFBpBVfLLMBQMRRbzMVb\nrfMCJPBMMCrSCSBGZZqRlRLzqhqh\nLDvdNmbgHjHgnmnvnHjgDjqlZhZzszhqzWsRWRRNcRNZ\ndmbvHDdnjDjVmjTmHjJVrfprPCCVtLSMrSpJ\nfdfTBfNVZffMmdfdhGhcJLJrvnlJvrBJ\nFFWFWTzWSWtFgPHgRPWTzggpJcvvSCGSGJnrvhhrrJhlGlGn\npztFjzRTqWzgHqHWtPtPFgmsNfqbDdwDNVwbmfwbdNsb\nzCHvDWwvCwgpNRCWWHttCwvNPVTqrRrVbbsnbqQPbVsbPrqG\njBBcmZBGmBSSJdmhBVbnnQrVbVqqVPbhbP\nZfJBmLjfJZMcdZmJffGMtHtwNCvWwwwztMzg\nrgFgllfdpFlTHfTnfnNPNtPBBVtpzmVVPmmP\nbLSSbGhGWSWmLzztcQPCQC\njvwhhwRwbwshjGhWwsRRTFflFJFzfddFrHzTrJ\nGSwgSdwfvdfvwgGwBLdJbjjpmFjSTRpqHmRrjptrqt\nNDMVMCsWQVCsQFjFTHjVLqjFrp\nPzWDDCNNlCWfbhZZLfBGwl\nclFLFpFJcVmmWWgWWcWTdwZqCZCZqDqDDSwvwrdHCZ\nGGLfGtbfMNbbLGtzjBNnsMjjZCwDvQZHrHQvDHHHHHHrrNHq\nBthbnfPGMstmJgRPpFRTLL\nVRcdVRPTgVTLVMwmggJBwblJFlmb\nCqDDQsjCCrnnnQQtDcrnGCssJZNvZbvNQmZwmZlbNbwFNwmF\npstjcqrprsHrfDpnrCnHCzRhSMhMMMRVPLMhddfhzT\nQNDQThccDghdcLLgVsrVLVlMGM\nBbbWpppFFpnfnFbBBPRMTJMRsGlJGlJVLf\nwpnbmvbnSwSnmzHHzwwhQqTctNCcqdNqZvhQjj\nlwCLwLjzLhLHCvwjGCZJbQSHdBQdDdbDtdSQ\npnzcTVsszpncgFdJpbtDBDttDSJS\nzTPcRrfzgzCCvGRhLLqW\nBgDbztvdDzLZZwMbDDcFHQQJPLWWPJQJHTHF\njfpNqSrpCqNfNSpjCqSqshNFRQJcJWRRFssgWRHWWFWHQJ\nmrnNNgNfMZwmDtZw\nZrrFPQsQPRLcvPJvhg\nHpmTjnBmDDDwqtVcHNvJhNVRhgvh\ntTpTGfnttqwnqQJzFdzfSdzSzr\nqVVZqfVNdnBZMNzNnPzfMqbzJvFSjSllvjBwrvrvFrlsrjJs\nLhHtDTmWmWmGDhGLWHghHLCwrwRjjsPJrSjFjFrFClvrww\nmDDgtcLcmtTWTTHhpWLDHhDNMqVnqfdqbZdQpqVbzZVPVb\nWGpVMtGZplgHVWMtZpZFHJjndvFdjddJhnjLHF\nDTrRcSSccfzcCPDCTnvJvjbrrhjNvqjqJL\nzczwPDTmfZZtgLwWlp\nsTtTpvMjpBdmTlhlBTdvsvdncmVcVVVVHzFqVnFfqzHzWV\nGGwQRZNCRgDgsDDbSbSgfGFFrHfVHVVWrHWWcVFF\nRZRPgbNSJDsTsplPLsvd\nGrGNGhpnPFFBfCQCMwrVlwTC\nPPWHmmSvSvdTfJvJVVfCfJ\nRjWDmDdDbjcSHmRRLRRHjdDBsFFbBngBzhqbBhpqBPgFFg\nTfdNjJjmShGcWvQNQqcNFN\ngsRZRMqbDpsHHnZnngMZFPlzQRPWvRtPwtRWzlPv\nbrgMMgZCLsDpHGGjfGJVSCqddT\nBdSRjHScGMVjGdcScwLgqQqphNqNDqBBQW\nzzQClvtttrwqrwgCwp\nPQJTzvJJTtJQlvQftmfdmdmGRcSdcMSGdS\nPjPwvwlfGlGCGwppWBsWQVVQnpCQ\nFRSHzMJdrfRnpmpWmp\nTzJTMNNrHLJfTJccvjqwwgGLZLLc\ndCpjsGvcsLvszTrRRlRrDJ\nhPNqqpbhFlrDnPrRnz\nSBbphFNtLvvSfLfc\nzTFnnZzqrjFVnZTrtwMDptbpMwMMBDzb\nJGPCjWGgJjPWGJjNchmbBRpNNDsRsNBbbRBppD\nJWCggWSCmhCmvWHTQrTQfHjdVlQZll\nWWbrmZjbmjpbWSmcWHSbLddwvDggLFDhFrRlFFDw\nqPMVzTPQVfzvVzBQTMtRswwlDdhDghhsfLwdhs\nMMMPQBPJPzCVzvzQVtBJJMzCbmSZNWbSccHHmmZpWSZGZS\nBgmMgjlBMjHPssBnwphtFwhSVVmGGwFS\nrfCqQQrfvCQNRqCnCthFVSGSGtVS\nTrzLNvWfQvrWQZNWsnHsssBBcZBMsDdZ\nCtJCddDHDDPGHCdNVLPBdLDbbGFbwZSsSSZrfFFrGbGlsb\ngvjpnvhRphjmpmSfsTSnPZZrZfrZ\nghRRRhQgWvmPWtHHtLdMtH\nqNgQgNgNQFVbqVQDMRZMDRBHHJHRFh\njnTTPzWZCzrWzGRHMrhmBvJBSBBM\njtCGWnCWPTsTzlctWPtqLbwqZggNwgLbwdVq\nDJgJDgFqCGlhFDGDCWhqCwRfpbcpbsgsVscRpwbwws\nSmLZQmMVvLbsbssNQsQN\ntHZMLZZZMVtmnMHWDHjWlGhFDqCl\nPZRmjlDBWRBWBQMdQQBDPRhfzSSTTnfFnfzFpVFjfSVFgS\nwwHCHLtLwnzNHVTZZV\nrJLtZtLZcCrvJwcbrGLvrcDQDhPldhGdmmPRQhhRBlPM\nzShzVhbqlbpbRNRscBNwCc\nDfmFWnfmnMFDdngdngvJFHgQQNwNsHHCQCwNTTzRcN\nFFFZMWWZZJMmJJMFFpztlPtSllLVphttGZ\ngWhGwmwQGhVwGzBMnDFmdmDDLbCnLn\ntRRHNHHlPHPfPltllNNNRsVPFrTrTbrMMTTDMdLFCrFdPP\nHvRvvHNpNvfqSshwqwVQZWhggwhw\njDDRDVqNsRMMVFjFbtzpBlpllCBlhSLHSStH\nJJWZZTwWcmZCzQShHhzhhm\nwTrgvTwccvdcfvJvWJrvJTNzNMfGNNGjFRsjNDbfVzDs\ncVWPPThWctcFRdQpzLTzBL\nNNCqwNSrsDqNSSgLgffDCNpBdRvvdpmHRQvBdBqvdzBB\nCgbffsDJsSsNgbgJrlcGhGtjnWcJPncjWL\nRwwTGRjGlwWNgjgfQVNmjj\nHJPdLhLzhbtbdLPLbHHJLdcfBffBgrFQVZgzvgBfvrvvmr\ncmchnhtPqLSJJbdPLntlsDGCTWWDsDRwWWWTwS\nDDDBsPGPbwhDcDcj\nrgfNgCmHMvrrttvtfmNLgrLcQQJTJhcnjTQHcZlwlQHnwj\nwgfLmgFgWdBFzSVR\nRwsbssJjnbJwwsGPPdDLfTDLLLWvWNDGDpWD\nzVtHqzHHVcBQTdpSgvQDSpTS\nHHhFCdrrHchmrhcmwsjZwRwMJlshJJJZ\nBdQgtJMLBqshLfNhbccfFhffNc\nDnVWvvpvWvzrpwRWDbTvbTjTFGlfFSfNSFHGmFGNFmnPlmFS\nwDRDZzVWVjrRVjzrQgQdbgCZLCsCQtbd\njQSgWjQmFFvHmjHWVVpZbGlbGlfGpbfGGWpf\nzBPtTqzPBcdwrzPJwqCztTNvCGpbGfDnfhZpNGGZGZbG\nMTzPtcrcwzTttdBJwPvFVHjMLFHQMgsSLHvs\nBHtjmmTtmDtHZjMMdNzCzCWcWZsZdsZs\nJRRJVPLwQJrVMPJLVVwChpRRhcdzcNzhzChNdW\nSrFwfGwGSnnGPlBMDtjbllggBn\nrsMDTrgsBNBgMgDBhfhDghrtcRWJttcmGRWLGQQLJRrqRL\nlnVVjvPbwpndvVwlVCjVwtLcsmLLqWWtttlsmGGcmm\nSsjVSCZbgTBTfNZH\nwlddvlldlBzqSmStdqmmngwgDCgrpMpgCngrMCfn\njVLTHGRHjjvPGcDrfNMbnpngVbpf\nRJRTTRZJLRJQZcGGHLhHvTdmBFWFtWzqdWWQlFzqBFWt\nHDgZHpZSDpBQdRpHHRsDBNNzTvfTQqcqNNTqTqPQvl\nrnMFFMFJwmNgvmfTzcfq\nCWMtjCWMCCLWrWVWJwBbpgtRZSHbddZHBDDb\nhjLWPZJpZptwJghSfgHTMTgRMR\nlCcrblcnlzqDsvbfffTRSMnffjHTgj\nsqscDcblGDDpWPwFGwjLLp\nplQtRqRlGpPPPLZtmtpttRtJjHJvddLTHrLHJJjbdHvrrN\ngcMgTBWBCTczjnvNznnbgH\nFBhSwsWFWDhQZQqTTZllsT\nWsVttWDbvbtRjDVtDbDbJjrjFpCCcBrSCwrrjwww\nnzHqGNMfHqMMwJLLsNBrNcBS\nqgnHflgzHlqlZzhnzssfzbZtmVvmTQvQdTtbbDbTvv\nRtQCBbJDFhJtQtZtCbMnVnPVfGPpJVJWWmwJPG\nNsczRsrdrNrjlcSTGGnwmGmnmVPWwmVT\nSHcljRHzNHHjSHcrgNslcczgZtDgMDBCvQQbhDBCbFBCFCCF\ndRTFRJTRTgJzSSJmzJfN\njLbQllLvvvrQlLQBrvQmFBGzCFtPSMmSGCtPPF\nQjsvDvqlvrQQvnsLjDWghTwZWcccFdRVpc\nbWnDbMJMFbhZSfngpfpd\nHjcrlvjRjrjlLqTqpwQgpfSQgghZgvgf\nTHNTjClLHDCCpWmbtC\nNpBNsnFMLBcPMZccbQGblWRgGZmbRWdW\nCqrJTHCvDfrfwwJHRBvRGvgQmvmlWtlR\nrHzJJJDDwJCqjHTwBLccMpFhshnSSsMz\nbqVqqrDMpLFbLpJJQDMjbpZndwsvwHHswvnvnnZslG\nhgfRghhBWgfzBgZvncvcGvWrnZsG\nfTSSSBtTzhPththRrrCBzVLqMMJQjVJJCLjFjjqjJq\npQlCJQjVvVGGnjNqFbFP\nwTqtmSmTBhstmstTmWRSdLdfPdNGnhGhdPNnNnrN\nmzTZBwWTcSTsTmZRRmzTsVMMVMQMDJgVqqMgcJlVDl\nvVwCqdCDvMrlDJCqrDMrPdTFWwZNbRcbmZWQbRQZWZcQWm\nfGSfhfjHGBlhSpRmpZFZNbQWjj\nntGlgSSGgStVqdMCDCJnqd\nTlTRCrhCpmnCRtMRRWbvMBfwBB\nDVdQcsccdczbtQBWvmQBBW\nPgHPNcmcqqTphlHTCGrT\nGVLCBmdLVtlrmqGCqrTCGnjGSfwzNfPzfNNGzSSw\nrMDcWMJsDWRvRcsZPwpJpSzjwwfFjfjJ\nhcsscQQsDcWbHbmLgTbVgTmlrbgq\nVwJndPThQQjdvbrb\nBHlzFFjlZGBBlZBDFSmllfGggLNbNvggtCgNrLQLbfvbfg\nSHSHHlZSGZSDHGzHssGmnjhjphJPPpPwnsJRwPMM\nQFFGpfGtwgtjwvpwpGGjjBHgqzNNllHRlRllNdBRJl\nSWVDWVrDhnWhnqVnWPrhcSldBJPsRMMdPHsRdJHRMMBP\nSWZZcmnnZnSCLZDZftFFCvbfTTqTCTQF\nTvZBTFZpshPggBNN\nbbctWQDjcnwtwDDDllQzLfgsPzqgrsTPLshWfP\nbwRbQRnmDwtbTjmwRJQRQVZHpCpHVdvpMHZZMHZJvd\nCfqzMCGvGqNrCFFNwcMBbnnbbtBbDStw\nsJjsTjvVjLVlLLbtHSnSSwQsDBSH\ndJlvLVgJTRVPWWjjggCzhNGzzGZdhqrdmqrq\nzGSHWGjzpRsWVfsNwZ\nBmPCLzPlJBBvQmLFQrwwrVtfqtqZtsvwfR\nhLLzlmmBPFFLJMlmgDTMgdSGbDgGHbSp\nppngVjZwNZwwVJjjnnVVJJJpLrsgmsmrbctsLcLmscDggDsL\nvHvRzRPvQPPRqPTlffRTrssbtqmmtbcMcNrDrrtD\nCHWdWzWQzdChNVZjZZwB\nLjVFhwjbFggMdVggTVMNRWRGWqQWtRNWGlQW\nDCzCpJppzJJDScFBzvBGnRWGWrNRQNNWGtNGqB\nvFJSzDSszzzccSZHzDDJmvSJhVwLjfVTPZLfwLgjThLZwhVh\nFMSSNScRlSGzfqWbqqcpWBhpmW\nTrPrnQNnJwtQpmmpmbbL\nvwvCsJZZnnwVnVHsfNjDRgSjRzgRjZjN\nCRhsghlqlvjhPslQdrMndMTWdPnTMJ\ntcDNFDpDSDwDtHrSDwDtFmtpJzWTnzQJpzQGnGWQMWnnTGBd\nZfFSmSfwcwcScZHtVRhRCqLqCfrVrrfg\nvHPsBzvRvhCzbwbnjHLVmVbW\nggdlpZNdZdflWTGpVMjgVbqjVmngnVbr\nlNpfllFGpZTtclDZzSWQFCWWzChPzQhS\nddbJQGsgJcQccCjjTC\nDDflHqNNNjCCPNbT\nLFpLzfzqHzLbLvtndJhs\nmHtbGdwzmtHZrtrHtHGwrmtcFgBFRsBFcdglRsfsdgJBNN\nWvpSVjCCTCSPVDqTVjLqJwWRcWsNlgBcBlfWBRfl\nqDwjvqjVTDDLSPpnjqVnzbhbZbrGMmHzbMHHhh\ncMrrVsdtCdVtwqdHgLjPLFLfcfZZJB\nzGSlGGvpDWWvbSSNTggZPPTTJLZPjPzJ\nbNQDWbjlGSGNnWGblbQDQCmdqhsMtrsVnCrtnwdnCt\nWZSnCCMMdMMMSJMSVZmmFqVjqjVwVjjc\nzvzTlQQQQTNGbmQTjqBjcwwwGgBHGwqB\nmthbtmlPhTrNzNhhPLzlPzWfWPJdRCWspPWSSRsWSnJp\nnmqsjNFqNLcVlPNvRdvPPv\nMrggHrMpbtTpgpDptlQRJJldVRPBsDQvvV\ngrgrTzfTGSFGsGSCLc\nhjdjCfQCLdQcWMfDDQhLsLCjqvVqzzZZprBFbzVdvVnqnBbd\nNTglmJGJRnRZVrVvFr\ngPGgPJSTPJtHncnjWDhDMDhP\nZWWqBqqmgFFDMTTWDMwwcW\nddGPpJQPprRSCrQzJPJGjHDvssMTDZDwcHhMsHsMHS\nzNRGrCRRCjPpdGQJNglqntnbtgNBZtFqtt\npslQSspQrqHfgMRl\ntJBTjsTLGMBGMFMg\ntDwnhZdnLdZDwczzcPvsVvVW\nnZnMSDnGtnzmzWZZcMmgMcHwFFVHhHwbHsVrwQhVhF\ndlTqNqfTjdJflCppCQQRHVwFRJrvJsFVQs\nLNTqBLPprpLLzWtSnMZBMDGm\nPBjlPvvcJlJzwqjnnjLnwm\ndMfdpVfVhGVfVpVTtWWbzwngLMLwMRCmgmLMmsww\nSWppdTrprThhrGVztcrllrQJZNlBZlrNZB\nTTrNcjGNWDdDPDpPjDPNrbmFPfQSFmfSMCmCfSmSSR\nsshhqshzBVnzHgwCMbCwcfmRFmRMbm\ngHVVnBzJcBgVhtZZtgJhpGrGNpvDpddvprNGrlTJ"));
+    // invalid inputs
+    assert_eq!(Err(String::from("invalid item '1'")), rucksack_sum("ab1b"));
+    assert_eq!(Err(String::from("invalid item 'é'")), rucksack_sum("aéa"));
+    assert_eq!(
+        Err(String::from("line 2: invalid item 'é'")),
+        rucksacks_sum("aa\nabé")
+    );
+    assert_eq!(
+        Err(String::from(
+            "line 2: rucksack 'abc' has an odd number of items"
+        )),
+        rucksacks_sum("aa\nabc")
+    );
+    // diagnostics
+    assert_eq!(
+        "line  length  status              duplicates\n\
+         1     24      ok                  p 1/1\n\
+         2     6       several duplicates  a 1/1, b 2/2\n\
+         3     4       no duplicate\n\
+         4     3       odd length\n\
+         5     2       invalid item '!'\n",
+        diagnostics_table(&rucksacks_diagnose(
+            "vJrwpWtwJgWrhcsFMMfFFhFp\nabbabb\nabcd\nabc\na!"
+        ))
+    );
+    assert_eq!(
+        "[{\"line\":1,\"length\":4,\"status\":\"ok\",\"duplicates\":[{\"item\":\"Z\",\"priority\":52,\"first\":2,\"second\":1}]},\
+         {\"line\":2,\"length\":1,\"status\":\"odd length\",\"duplicates\":[]}]",
+        diagnostics_json(&rucksacks_diagnose("ZZaZ\nb"))
+    );
 }
 
 /*
@@ -1,60 +1,298 @@
 // https://adventofcode.com/2022/day/3
 // (part 2)
 
-fn item_priority(c: char) -> i32 {
+// every item type is one bit of a 52 bits mask, bit n being the item of priority n + 1
+type Items = u64;
+
+fn item_priority(c: char) -> Result<i32, String> {
     if c >= 'a' && c <= 'z' {
-        return c as i32 - 'a' as i32 + 1;
+        return Ok(c as i32 - 'a' as i32 + 1);
     } else if c >= 'A' && c <= 'Z' {
-        return c as i32 - 'A' as i32 + 27;
+        return Ok(c as i32 - 'A' as i32 + 27);
+    } else {
+        return Err(format!("invalid item '{}'", c));
+    }
+}
+
+fn item_from_priority(priority: i32) -> char {
+    if priority <= 26 {
+        return (b'a' + (priority - 1) as u8) as char;
     } else {
-        panic!();
+        return (b'A' + (priority - 27) as u8) as char;
+    }
+}
+
+fn items_get(list: &str) -> Result<Items, String> {
+    let mut items: Items = 0;
+    for c in list.chars() {
+        items |= 1 << (item_priority(c)? - 1);
+    }
+    return Ok(items);
+}
+
+fn badge_find(list: &[&str]) -> Result<char, String> {
+    if list.is_empty() {
+        return Err(String::from("empty group"));
+    }
+    let mut common: Items = items_get(list[0])?;
+    for elf in &list[1..] {
+        common &= items_get(elf)?;
     }
+    if common.count_ones() != 1 {
+        return Err(format!(
+            "group shares {} items instead of 1",
+            common.count_ones()
+        ));
+    }
+    return Ok(item_from_priority(common.trailing_zeros() as i32 + 1));
 }
 
-fn badge_find(list: &[&str; 3]) -> char {
-    let mut elf1: std::collections::HashSet<char> = std::collections::HashSet::new();
+// original per character implementation, kept to check and benchmark the bitset one against
+fn badge_find_reference(list: &[&str]) -> Result<char, String> {
+    let mut matches: std::collections::HashSet<char> = std::collections::HashSet::new();
     for i in 0..list[0].len() {
-        elf1.insert(list[0].chars().nth(i).unwrap());
+        matches.insert(list[0].chars().nth(i).unwrap());
     }
-    let mut elf2_matches: std::collections::HashSet<char> = std::collections::HashSet::new();
-    for i in 0..list[1].len() {
-        let c: char = list[1].chars().nth(i).unwrap();
-        if elf1.contains(&c) {
-            elf2_matches.insert(c);
+    for elf in &list[1..] {
+        let mut elf_matches: std::collections::HashSet<char> = std::collections::HashSet::new();
+        for i in 0..elf.len() {
+            let c: char = elf.chars().nth(i).unwrap();
+            if matches.contains(&c) {
+                elf_matches.insert(c);
+            }
         }
+        matches = elf_matches;
     }
-    let mut elf3_matches: std::collections::HashSet<char> = std::collections::HashSet::new();
-    for i in 0..list[2].len() {
-        let c: char = list[2].chars().nth(i).unwrap();
-        if elf2_matches.contains(&c) {
-            elf3_matches.insert(c);
+    assert_eq!(1, matches.len());
+    return Ok(*matches.iter().nth(0).unwrap());
+}
+
+fn badges_sum_with(
+    list: &str,
+    group_size: usize,
+    badge_find_fn: fn(&[&str]) -> Result<char, String>,
+) -> Result<i32, String> {
+    let mut sum: i32 = 0;
+
+    let lines: Vec<&str> = list.split('\n').collect();
+    if group_size == 0 || lines.len() % group_size != 0 {
+        return Err(format!(
+            "{} rucksacks can't be split in groups of {}",
+            lines.len(),
+            group_size
+        ));
+    }
+    for i in (0..lines.len()).step_by(group_size) {
+        match badge_find_fn(&lines[i..i + group_size]) {
+            Ok(badge) => sum += item_priority(badge)?,
+            Err(e) => return Err(format!("line {}: {}", i + 1, e)),
         }
     }
-    assert_eq!(1, elf3_matches.len());
-    return *elf3_matches.iter().nth(0).unwrap();
+
+    return Ok(sum);
 }
 
-fn badge_priority(list: &[&str; 3]) -> i32 {
-    let badge: char = badge_find(list);
-    return item_priority(badge);
+fn badges_sum(list: &str, group_size: usize) -> Result<i32, String> {
+    return badges_sum_with(list, group_size, badge_find);
 }
 
-fn badges_sum(list: &str) -> i32 {
-    let mut sum: i32 = 0;
+// fills `group` with rucksacks picked after `from`, backtracking whenever the items shared by the
+// group being built drop to zero, then continues with the next ungrouped rucksack
+fn groups_detect_from(
+    rucksacks: &[Items],
+    group_size: usize,
+    assigned: &mut Vec<bool>,
+    group: &mut Vec<usize>,
+    common: Items,
+    from: usize,
+    groups: &mut Vec<Vec<usize>>,
+) -> bool {
+    if group.len() == group_size {
+        if common.count_ones() != 1 {
+            return false;
+        }
+        groups.push(group.clone());
+        let mut next_group: Vec<usize> = Vec::with_capacity(group_size);
+        if groups_detect_from(
+            rucksacks,
+            group_size,
+            assigned,
+            &mut next_group,
+            0,
+            0,
+            groups,
+        ) {
+            return true;
+        }
+        groups.pop();
+        return false;
+    }
+
+    if group.is_empty() {
+        // the first ungrouped rucksack has to belong to some group, no need to try the others
+        let first: Option<usize> = assigned.iter().position(|a| !a);
+        if first.is_none() {
+            return true;
+        }
+        let first: usize = first.unwrap();
+        assigned[first] = true;
+        group.push(first);
+        let found: bool = groups_detect_from(
+            rucksacks,
+            group_size,
+            assigned,
+            group,
+            rucksacks[first],
+            first + 1,
+            groups,
+        );
+        group.pop();
+        assigned[first] = false;
+        return found;
+    }
+
+    for i in from..rucksacks.len() {
+        if assigned[i] || common & rucksacks[i] == 0 {
+            continue;
+        }
+        assigned[i] = true;
+        group.push(i);
+        let found: bool = groups_detect_from(
+            rucksacks,
+            group_size,
+            assigned,
+            group,
+            common & rucksacks[i],
+            i + 1,
+            groups,
+        );
+        group.pop();
+        assigned[i] = false;
+        if found {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+// partitions an ungrouped list in groups of `group_size` rucksacks sharing exactly one item,
+// returns the line indices (starting at 0) of every group
+fn groups_detect(list: &str, group_size: usize) -> Result<Vec<Vec<usize>>, String> {
+    let lines: Vec<&str> = list.split('\n').collect();
+    if group_size == 0 || lines.len() % group_size != 0 {
+        return Err(format!(
+            "{} rucksacks can't be split in groups of {}",
+            lines.len(),
+            group_size
+        ));
+    }
+    let mut rucksacks: Vec<Items> = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        match items_get(line) {
+            Ok(items) => rucksacks.push(items),
+            Err(e) => return Err(format!("line {}: {}", i + 1, e)),
+        }
+    }
+
+    let mut assigned: Vec<bool> = vec![false; rucksacks.len()];
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group: Vec<usize> = Vec::with_capacity(group_size);
+    if !groups_detect_from(
+        &rucksacks,
+        group_size,
+        &mut assigned,
+        &mut group,
+        0,
+        0,
+        &mut groups,
+    ) {
+        return Err(format!(
+            "no partition in groups of {} sharing exactly one item",
+            group_size
+        ));
+    }
 
+    return Ok(groups);
+}
+
+fn badges_sum_ungrouped(list: &str, group_size: usize) -> Result<i32, String> {
     let lines: Vec<&str> = list.split('\n').collect();
-    assert_eq!(0, lines.len() % 3);
-    for i in (0..lines.len()).step_by(3) {
-        sum += badge_priority(&[lines[i], lines[i + 1], lines[i + 2]]);
+    let mut sum: i32 = 0;
+    for group in groups_detect(list, group_size)? {
+        let rucksacks: Vec<&str> = group.iter().map(|i| lines[*i]).collect();
+        sum += item_priority(badge_find(&rucksacks)?)?;
     }
+    return Ok(sum);
+}
 
-    return sum;
+// xorshift, good enough to generate inputs without any dependency
+fn random_next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    return *state;
+}
+
+// groups of 3 rucksacks sharing exactly one badge item, other items being split in three disjoint
+// pools (one per elf) so no other item can be common to the whole group
+fn rucksacks_generate(groups: usize, items_per_rucksack: usize, seed: u64) -> String {
+    const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut state: u64 = seed;
+    let mut list: String = String::with_capacity(groups * 3 * (items_per_rucksack + 1));
+    for g in 0..groups {
+        let badge: usize = (random_next(&mut state) % ITEMS.len() as u64) as usize;
+        let mut pool: Vec<u8> = Vec::new();
+        for (i, item) in ITEMS.iter().enumerate() {
+            if i != badge {
+                pool.push(*item);
+            }
+        }
+        let pool_len: usize = pool.len() / 3;
+        for elf in 0..3 {
+            if g > 0 || elf > 0 {
+                list.push('\n');
+            }
+            list.push(ITEMS[badge] as char);
+            for _ in 1..items_per_rucksack {
+                let i: usize = (random_next(&mut state) % pool_len as u64) as usize;
+                list.push(pool[elf * pool_len + i] as char);
+            }
+        }
+    }
+    return list;
+}
+
+fn benchmark() {
+    for (groups, items_per_rucksack) in [(100_000, 24), (10_000, 400)] {
+        let list: String = rucksacks_generate(groups, items_per_rucksack, 0x2022_0003);
+
+        let start = std::time::Instant::now();
+        let bitset: Result<i32, String> = badges_sum(&list, 3);
+        let bitset_duration = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let reference: Result<i32, String> = badges_sum_with(&list, 3, badge_find_reference);
+        let reference_duration = start.elapsed();
+
+        assert_eq!(reference, bitset);
+        println!(
+            "{} groups of {} items rucksacks: bitset {:?}, reference {:?}",
+            groups, items_per_rucksack, bitset_duration, reference_duration
+        );
+    }
 }
 
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("bench") {
+        benchmark();
+        return;
+    }
+
     // example
     assert_eq!(
-        'r',
+        Ok('r'),
         badge_find(&[
             "vJrwpWtwJgWrhcsFMMfFFhFp",
             "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
@@ -62,16 +300,52 @@ fn main() {
         ])
     );
     assert_eq!(
-        'Z',
+        Ok('Z'),
         badge_find(&[
             "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
             "ttgJtRGJQctTZtZT",
             "CrZsJsPPZsGzwwsLwLmpwMDw"
         ])
     );
-    assert_eq!(70, badges_sum("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw"));
+    assert_eq!(Ok(70), badges_sum("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw", 3));
+    assert_eq!(Ok(70), badges_sum_with("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw", 3, badge_find_reference));
     //// user puzzle input
-    assert_eq!(2631, badges_sum("FqdWDFppHWhmwwzdjvjTRTznjdMv\nZBJrDVfQcfSRMLjZnjjM\ncBffPfbrbQcgQJggfVQJBPbCwlPtWFDWHFHhpmmGlGmlqmDG\nPNbMLgmPgRDgRtMPDdmdbmdmQrTBVCZnVnpCnNHHVZBNVZHc\nljvvqhlvshhnrcpBZqpTcr\nzGhWzFTJvsFttddWbMRdmP\nhhGdDhfdDhmdnHwtzzRtdzbzQQQg\nWTTZJLsvLRJCcWJWScWWLtzjwBzBttbBzSwVQjQBtt\nvLJcLLTRsvsTZPqHGhFHGhhPhfqD\ndJszLvzvCZZsJmszCrrdFmpppMpDMQPMgmDcDgSS\nnRjRbnnjlNhblnjtVtQlWttMFPfMfPgDMpBgSBPgBS\nGWVQTVTnTNhjblQNRsdJLLdTCdzdZLrdrJ\nRnLJBfmJfmNBHlQvvbdQ\ngrhgrtqgjJhhggNHqvwWqvbNlbHw\nGVTTsFFjJjVVFVGCFTJDDjhFcZmRMZfnZcncSpMSGcRPZpLp\nbwSNRNSRzSWnPnJRldMBMQmMlsCcPPCP\nFGpDqTTVFFprpjLVQMMGtMclcmHGtBdc\nTjhhgTLpVZhpLDZqrTqZVpBSwJwzNnWNWJvzzNSggwwN\nPHTMsmwrJMwLJvJddvdHwvcWnnWfccqGnhhfGcDqsnGc\ntZVlzQZblBgcSqqRhRmzqf\nCmlgmZlQtFtZNlVZdHLMrMvvCrrvvTTC\nPrDGBBddprmzddrSqccRgSTpqbsMRR\nhvLtfFNvvZNfGGfRgbqsRNlTSSgsbM\nQQQjZHHQjvththFffCHPPzmGrmDzDWrWBw\nGrjGrpjjCsnwhsGGPwlPTPLPVttPqLVl\nHzSHHhczRlLTHqqq\nDbhvFSSzQcZbcFbcQjrJrMJmmZnGJmJnjn\nmvTDsJLwzlWNDDnZngZNdd\nBVtPqFMqtvQFqPqjFBMVtRZGNGhfNcfQdpfgnQgNcNgp\nHBHtPbHCLCzsLJvT\nnVHVFfggbQVmFFfhLpBpBTrfLBCB\ncjjRwJdqtwwwNNjcwRMwnLNTpNsGCnLsLGhBhpNn\ntJqStcRMMMjPwlnvzgQWzmHzlmQl\nnNSRcDHRmHhhDZZZdBDfWJdfrJ\nLGrGVtjCPCbbQQQQLvQpbVQZzsqsBMdBqMvZMMJZqJvdBW\nGCPLlLPPPbTpbCbpLPpVlmrmmrHhHcHnHrHHgTFFnm\nrQbnBrDTQcdpHttt\nqNsNpfjLpNLRNqLMtdVsddcmVzdzVh\nWJfLCfvpWpNLbwwwBwbZFvlZ\nZslGPhBWBJbNjmbTmSWN\nqtDCZcfZtDjbjCQvmmSj\nttpRzqtqRLDzpRtDdtPlhhZZGBHGPsGZPhwd\nWjtcNnMtztGFrsNjcgRHvdwHhhjRwlhwlg\npPSpPBPVqJqBCCPvZdHlVGddwdhvgH\nqpDBpBqPTDLqGLmpTPqbDmWMNrFrrFQnFMNnMNQtMm\ndNwfsjFLQLFNBhTCTPPTBJhhTP\nVmVMgqgRVHtztmgqgzgqRzgMPWCRWTpPCCPWPThDdZPCRZJp\nVMqtMgGHmgVMvmqMMtGMSmbslnQFcsndfjNNsnfscnbddF\nqbWcqrFNCJGSChvLGv\nMnslRSpSVsGgGhDDwLvl\nRjMtfnpmmmjSWSbjrNPN\nrsCsqTVgfCnQFdCznQ\nZRsBvMvmZMGQQmFnDmQzzQ\nMlGLlBjRGjjWWGRGMlNrLLrssTPNfLcTgrVr\nvpSBBcJnWnSmcqmcChdcgf\nNRNPFDwwDbThqTJffZQHhd\nDzPDNVNbLwPzMLRbNMDjBlvpWjrvrVJjjSsBjr\ndndGpnWdVnBPFFHTBgGH\nqCcCmjqNNJDtcJQjqJqMCQJcFTBRZRwBZBHDPRHBHZDbgBBR\nszJcCtNQqjqCNjjNhdgWfLLdhVWsSVnr\nNjJLgSLGGCLWvqNqNBvwFb\nhtmVmtHnlZmDVhtmhblmDMHddBRvwwZQQqWPFvFwdvRWdR\nHnlnHfDsbSTbJzsz\nJvZfsPsPhRfZZnCdtnmjHVRLCH\nTzcczTtgqpgGSTlHHVHCGjCdHdnQQQ\nwDBDSDlWzwwzDqqMtrMrfsfbNvJNJB\nThfQTdQzQbgdhdNbJFJSlbBLlmqqHBSHCRHsHm\nrWBWWPcvpGGwjPPpvpPGplRSnLLnCrLRsRlLsLmLmR\ncGWPPMtpZDwpMpJQBQQQDBBVNfdQ\nWcWDRLSzFrRFFccPmnssMrGtmMnnGm\njvvgCCTvNqTtJqsnqwPBmspZMB\ngbVgbvlChJVbCdbtTgjdLWSHWcHSDcQzFzRzDF\nzTrHgrFWRrWvMpPNBVZZHVfN\nhdnlltGLtGSQPVLSNBQN\nCqGGtGwlhlGdtGmbtjtmmvDRFvVFTTRDRbRgFJRbDr\nDTFpQFrZDFBDFrTNFjSWJsWlWjsRJcslsp\nzzPqvdqNzvqzfzMfzqmCzzfJsclWjSSRWglgjclWSmcjlJ\nhfPGwhhvMGCVCdddhQQZQnNrTDQnFrtn\nDDMFjwVTgVmMWgVpdqtlJnpvHHnslw\nfFfZzLSfzBfZBZtqJJHsnSsldlld\nGLcNhRGLZBhmDWTjDTWF\nFbVcQRVRBFfNFfccVfZcWddnGrrHncWtdHsZ\nhwGwjgTSGrssZHHTdn\nLvPvCJgJLwjlSJmSPLvvgGfFBpBVfLLMBQMRRbzMVb\nrfMCJPBMMCrSCSBGZZqRlRLzqhqh\nLDvdNmbgHjHgnmnvnHjgDjqlZhZzszhqzWsRWRRNcRNZ\ndmbvHDdnjDjVmjTmHjJVrfprPCCVtLSMrSpJ\nfdfTBfNVZffMmdfdhGhcJLJrvnlJvrBJ\nFFWFWTzWSWtFgPHgRPWTzggpJcvvSCGSGJnrvhhrrJhlGlGn\npztFjzRTqWzgHqHWtPtPFgmsNfqbDdwDNVwbmfwbdNsb\nzCHvDWwvCwgpNRCWWHttCwvNPVTqrRrVbbsnbqQPbVsbPrqG\njBBcmZBGmBSSJdmhBVbnnQrVbVqqVPbhbP\nZfJBmLjfJZMcdZmJffGMtHtwNCvWwwwztMzg\nrgFgllfdpFlTHfTnfnNPNtPBBVtpzmVVPmmP\nbLSSbGhGWSWmLzztcQPCQC\njvwhhwRwbwshjGhWwsRRTFflFJFzfddFrHzTrJ\nGSwgSdwfvdfvwgGwBLdJbjjpmFjSTRpqHmRrjptrqt\nNDMVMCsWQVCsQFjFTHjVLqjFrp\nPzWDDCNNlCWfbhZZLfBGwl\nclFLFpFJcVmmWWgWWcWTdwZqCZCZqDqDDSwvwrdHCZ\nGGLfGtbfMNbbLGtzjBNnsMjjZCwDvQZHrHQvDHHHHHHrrNHq\nBthbnfPGMstmJgRPpFRTLL\nVRcdVRPTgVTLVMwmggJBwblJFlmb\nCqDDQsjCCrnnnQQtDcrnGCssJZNvZbvNQmZwmZlbNbwFNwmF\npstjcqrprsHrfDpnrCnHCzRhSMhMMMRVPLMhddfhzT\nQNDQThccDghdcLLgVsrVLVlMGM\nBbbWpppFFpnfnFbBBPRMTJMRsGlJGlJVLf\nwpnbmvbnSwSnmzHHzwwhQqTctNCcqdNqZvhQjj\nlwCLwLjzLhLHCvwjGCZJbQSHdBQdDdbDtdSQ\npnzcTVsszpncgFdJpbtDBDttDSJS\nzTPcRrfzgzCCvGRhLLqW\nBgDbztvdDzLZZwMbDDcFHQQJPLWWPJQJHTHF\njfpNqSrpCqNfNSpjCqSqshNFRQJcJWRRFssgWRHWWFWHQJ\nmrnNNgNfMZwmDtZw\nZrrFPQsQPRLcvPJvhg\nHpmTjnBmDDDwqtVcHNvJhNVRhgvh\ntTpTGfnttqwnqQJzFdzfSdzSzr\nqVVZqfVNdnBZMNzNnPzfMqbzJvFSjSllvjBwrvrvFrlsrjJs\nLhHtDTmWmWmGDhGLWHghHLCwrwRjjsPJrSjFjFrFClvrww\nmDDgtcLcmtTWTTHhpWLDHhDNMqVnqfdqbZdQpqVbzZVPVb\nWGpVMtGZplgHVWMtZpZFHJjndvFdjddJhnjLHF\nDTrRcSSccfzcCPDCTnvJvjbrrhjNvqjqJL\nzczwPDTmfZZtgLwWlp\nsTtTpvMjpBdmTlhlBTdvsvdncmVcVVVVHzFqVnFfqzHzWV\nGGwQRZNCRgDgsDDbSbSgfGFFrHfVHVVWrHWWcVFF\nRZRPgbNSJDsTsplPLsvd\nGrGNGhpnPFFBfCQCMwrVlwTC\nPPWHmmSvSvdTfJvJVVfCfJ\nRjWDmDdDbjcSHmRRLRRHjdDBsFFbBngBzhqbBhpqBPgFFg\nTfdNjJjmShGcWvQNQqcNFN\ngsRZRMqbDpsHHnZnngMZFPlzQRPWvRtPwtRWzlPv\nbrgMMgZCLsDpHGGjfGJVSCqddT\nBdSRjHScGMVjGdcScwLgqQqphNqNDqBBQW\nzzQClvtttrwqrwgCwp\nPQJTzvJJTtJQlvQftmfdmdmGRcSdcMSGdS\nPjPwvwlfGlGCGwppWBsWQVVQnpCQ\nFRSHzMJdrfRnpmpWmp\nTzJTMNNrHLJfTJccvjqwwgGLZLLc\ndCpjsGvcsLvszTrRRlRrDJ\nhPNqqpbhFlrDnPrRnz\nSBbphFNtLvvSfLfc\nzTFnnZzqrjFVnZTrtwMDptbpMwMMBDzb\nJGPCjWGgJjPWGJjNchmbBRpNNDsRsNBbbRBppD\nJWCggWSCmhCmvWHTQrTQfHjdVlQZll\nWWbrmZjbmjpbWSmcWHSbLddwvDggLFDhFrRlFFDw\nqPMVzTPQVfzvVzBQTMtRswwlDdhDghhsfLwdhs\nMMMPQBPJPzCVzvzQVtBJJMzCbmSZNWbSccHHmmZpWSZGZS\nBgmMgjlBMjHPssBnwphtFwhSVVmGGwFS\nrfCqQQrfvCQNRqCnCthFVSGSGtVS\nTrzLNvWfQvrWQZNWsnHsssBBcZBMsDdZ\nCtJCddDHDDPGHCdNVLPBdLDbbGFbwZSsSSZrfFFrGbGlsb\ngvjpnvhRphjmpmSfsTSnPZZrZfrZ\nghRRRhQgWvmPWtHHtLdMtH\nqNgQgNgNQFVbqVQDMRZMDRBHHJHRFh\njnTTPzWZCzrWzGRHMrhmBvJBSBBM\njtCGWnCWPTsTzlctWPtqLbwqZggNwgLbwdVq\nDJgJDgFqCGlhFDGDCWhqCwRfpbcpbsgsVscRpwbwws\nSmLZQmMVvLbsbssNQsQN\ntHZMLZZZMVtmnMHWDHjWlGhFDqCl\nPZRmjlDBWRBWBQMdQQBDPRhfzSSTTnfFnfzFpVFjfSVFgS\nwwHCHLtLwnzNHVTZZV\nrJLtZtLZcCrvJwcbrGLvrcDQDhPldhGdmmPRQhhRBlPM\nzShzVhbqlbpbRNRscBNwCc\nDfmFWnfmnMFDdngdngvJFHgQQNwNsHHCQCwNTTzRcN\nFFFZMWWZZJMmJJMFFpztlPtSllLVphttGZ\ngWhGwmwQGhVwGzBMnDFmdmDDLbCnLn\ntRRHNHHlPHPfPltllNNNRsVPFrTrTbrMMTTDMdLFCrFdPP\nHvRvvHNpNvfqSshwqwVQZWhggwhw\njDDRDVqNsRMMVFjFbtzpBlpllCBlhSLHSStH\nJJWZZTwWcmZCzQShHhzhhm\nwTrgvTwccvdcfvJvWJrvJTNzNMfGNNGjFRsjNDbfVzDs\ncVWPPThWctcFRdQpzLTzBL\nNNCqwNSrsDqNSSgLgffDCNpBdRvvdpmHRQvBdBqvdzBB\nCgbffsDJsSsNgbgJrlcGhGtjnWcJPncjWL\nRwwTGRjGlwWNgjgfQVNmjj\nHJPdLhLzhbtbdLPLbHHJLdcfBffBgrFQVZgzvgBfvrvvmr\ncmchnhtPqLSJJbdPLntlsDGCTWWDsDRwWWWTwS\nDDDBsPGPbwhDcDcj\nrgfNgCmHMvrrttvtfmNLgrLcQQJTJhcnjTQHcZlwlQHnwj\nwgfLmgFgWdBFzSVR\nRwsbssJjnbJwwsGPPdDLfTDLLLWvWNDGDpWD\nzVtHqzHHVcBQTdpSgvQDSpTS\nHHhFCdrrHchmrhcmwsjZwRwMJlshJJJZ\nBdQgtJMLBqshLfNhbccfFhffNc\nDnVWvvpvWvzrpwRWDbTvbTjTFGlfFSfNSFHGmFGNFmnPlmFS\nwDRDZzVWVjrRVjzrQgQdbgCZLCsCQtbd\njQSgWjQmFFvHmjHWVVpZbGlbGlfGpbfGGWpf\nzBPtTqzPBcdwrzPJwqCztTNvCGpbGfDnfhZpNGGZGZbG\nMTzPtcrcwzTttdBJwPvFVHjMLFHQMgsSLHvs\nBHtjmmTtmDtHZjMMdNzCzCWcWZsZdsZs\nJRRJVPLwQJrVMPJLVVwChpRRhcdzcNzhzChNdW\nSrFwfGwGSnnGPlBMDtjbllggBn\nrsMDTrgsBNBgMgDBhfhDghrtcRWJttcmGRWLGQQLJRrqRL\nlnVVjvPbwpndvVwlVCjVwtLcsmLLqWWtttlsmGGcmm\nSsjVSCZbgTBTfNZH\nwlddvlldlBzqSmStdqmmngwgDCgrpMpgCngrMCfn\njVLTHGRHjjvPGcDrfNMbnpngVbpf\nRJRTTRZJLRJQZcGGHLhHvTdmBFWFtWzqdWWQlFzqBFWt\nHDgZHpZSDpBQdRpHHRsDBNNzTvfTQqcqNNTqTqPQvl\nrnMFFMFJwmNgvmfTzcfq\nCWMtjCWMCCLWrWVWJwBbpgtRZSHbddZHBDDb\nhjLWPZJpZptwJghSfgHTMTgRMR\nlCcrblcnlzqDsvbfffTRSMnffjHTgj\nsqscDcblGDDpWPwFGwjLLp\nplQtRqRlGpPPPLZtmtpttRtJjHJvddLTHrLHJJjbdHvrrN\ngcMgTBWBCTczjnvNznnbgH\nFBhSwsWFWDhQZQqTTZllsT\nWsVttWDbvbtRjDVtDbDbJjrjFpCCcBrSCwrrjwww\nnzHqGNMfHqMMwJLLsNBrNcBS\nqgnHflgzHlqlZzhnzssfzbZtmVvmTQvQdTtbbDbTvv\nRtQCBbJDFhJtQtZtCbMnVnPVfGPpJVJWWmwJPG\nNsczRsrdrNrjlcSTGGnwmGmnmVPWwmVT\nSHcljRHzNHHjSHcrgNslcczgZtDgMDBCvQQbhDBCbFBCFCCF\ndRTFRJTRTgJzSSJmzJfN\njLbQllLvvvrQlLQBrvQmFBGzCFtPSMmSGCtPPF\nQjsvDvqlvrQQvnsLjDWghTwZWcccFdRVpc\nbWnDbMJMFbhZSfngpfpd\nHjcrlvjRjrjlLqTqpwQgpfSQgghZgvgf\nTHNTjClLHDCCpWmbtC\nNpBNsnFMLBcPMZccbQGblWRgGZmbRWdW\nCqrJTHCvDfrfwwJHRBvRGvgQmvmlWtlR\nrHzJJJDDwJCqjHTwBLccMpFhshnSSsMz\nbqVqqrDMpLFbLpJJQDMjbpZndwsvwHHswvnvnnZslG\nhgfRghhBWgfzBgZvncvcGvWrnZsG\nfTSSSBtTzhPththRrrCBzVLqMMJQjVJJCLjFjjqjJq\npQlCJQjVvVGGnjNqFbFP\nwTqtmSmTBhstmstTmWRSdLdfPdNGnhGhdPNnNnrN\nmzTZBwWTcSTsTmZRRmzTsVMMVMQMDJgVqqMgcJlVDl\nvVwCqdCDvMrlDJCqrDMrPdTFWwZNbRcbmZWQbRQZWZcQWm\nfGSfhfjHGBlhSpRmpZFZNbQWjj\nntGlgSSGgStVqdMCDCJnqd\nTlTRCrhCpmnCRtMRRWbvMBfwBB\nDVdQcsccdczbtQBWvmQBBW\nPgHPNcmcqqTphlHTCGrT\nGVLCBmdLVtlrmqGCqrTCGnjGSfwzNfPzfNNGzSSw\nrMDcWMJsDWRvRcsZPwpJpSzjwwfFjfjJ\nhcsscQQsDcWbHbmLgTbVgTmlrbgq\nVwJndPThQQjdvbrb\nBHlzFFjlZGBBlZBDFSmllfGggLNbNvggtCgNrLQLbfvbfg\nSHSHHlZSGZSDHGzHssGmnjhjphJPPpPwnsJRwPMM\nQFFGpfGtwgtjwvpwpGGjjBHgqzNNllHRlRllNdBRJl\nSWVDWVrDhnWhnqVnWPrhcSldBJPsRMMdPHsRdJHRMMBP\nSWZZcmnnZnSCLZDZftFFCvbfTTqTCTQF\nTvZBTFZpshPggBNN\nbbctWQDjcnwtwDDDllQzLfgsPzqgrsTPLshWfP\nbwRbQRnmDwtbTjmwRJQRQVZHpCpHVdvpMHZZMHZJvd\nCfqzMCGvGqNrCFFNwcMBbnnbbtBbDStw\nsJjsTjvVjLVlLLbtHSnSSwQsDBSH\ndJlvLVgJTRVPWWjjggCzhNGzzGZdhqrdmqrq\nzGSHWGjzpRsWVfsNwZ\nBmPCLzPlJBBvQmLFQrwwrVtfqtqZtsvwfR\nhLLzlmmBPFFLJMlmgDTMgdSGbDgGHbSp\nppngVjZwNZwwVJjjnnVVJJJpLrsgmsmrbctsLcLmscDggDsL\nvHvRzRPvQPPRqPTlffRTrssbtqmmtbcMcNrDrrtD\nCHWdWzWQzdChNVZjZZwB\nLjVFhwjbFggMdVggTVMNRWRGWqQWtRNWGlQW\nDCzCpJppzJJDScFBzvBGnRWGWrNRQNNWGtNGqB\nvFJSzDSszzzccSZHzDDJmvSJhVwLjfVTPZLfwLgjThLZwhVh\nFMSSNScRlSGzfqWbqqcpWBhpmW\nTrPrnQNnJwtQpmmpmbbL\nvwvCsJZZnnwVnVHsfNjDRgSjRzgRjZjN\nCRhsghlqlvjhPslQdrMndMTWdPnTMJ\ntcDNFDpDSDwDtHrSDwDtFmtpJzWTnzQJpzQGnGWQMWnnTGBd\nZfFSmSfwcwcScZHtVRhRCqLqCfrVrrfg\nvHPsBzvRvhCzbwbnjHLVmVbW\nggdlpZNdZdflWTGpVMjgVbqjVmngnVbr\nlNpfllFGpZTtclDZzSWQFCWWzChPzQhS\nddbJQGsgJcQccCjjTC\nDDflHqNNNjCCPNbT\nLFpLzfzqHzLbLvtndJhs\nmHtbGdwzmtHZrtrHtHGwrmtcFgBFRsBFcdglRsfsdgJBNN\nWvpSVjCCTCSPVDqTVjLqJwWRcWsNlgBcBlfWBRfl\nqDwjvqjVTDDLSPpnjqVnzbhbZbrGMmHzbMHHhh\ncMrrVsdtCdVtwqdHgLjPLFLfcfZZJB\nzGSlGGvpDWWvbSSNTggZPPTTJLZPjPzJ\nbNQDWbjlGSGNnWGblbQDQCmdqhsMtrsVnCrtnwdnCt\nWZSnCCMMdMMMSJMSVZmmFqVjqjVwVjjc\nzvzTlQQQQTNGbmQTjqBjcwwwGgBHGwqB\nmthbtmlPhTrNzNhhPLzlPzWfWPJdRCWspPWSSRsWSnJp\nnmqsjNFqNLcVlPNvRdvPPv\nMrggHrMpbtTpgpDptlQRJJldVRPBsDQvvV\ngrgrTzfTGSFGsGSCLc\nhjdjCfQCLdQcWMfDDQhLsLCjqvVqzzZZprBFbzVdvVnqnBbd\nNTglmJGJRnRZVrVvFr\ngPGgPJSTPJtHncnjWDhDMDhP\nZWWqBqqmgFFDMTTWDMwwcW\nddGPpJQPprRSCrQzJPJGjHDvssMTDZDwcHhMsHsMHS\nzNRGrCRRCjPpdGQJNglqntnbtgNBZtFqtt\npslQSspQrqHfgMRl\ntJBTjsTLGMBGMFMg\ntDwnhZdnLdZDwczzcPvsVvVW\nnZnMSDnGtnzmzWZZcMmgMcHwFFVHhHwbHsVrwQhVhF\ndlTqNqfTjdJflCppCQQRHVwFRJrvJsFVQs\nLNTqBLPprpLLzWtSnMZBMDGm\nPBjlPvvcJlJzwqjnnjLnwm\ndMfdpVfVhGVfVpVTtWWbzwngLMLwMRCmgmLMmsww\nSWppdTrprThhrGVztcrllrQJZNlBZlrNZB\nTTrNcjGNWDdDPDpPjDPNrbmFPfQSFmfSMCmCfSmSSR\nsshhqshzBVnzHgwCMbCwcfmRFmRMbm\ngHVVnBzJcBgVhtZZtgJhpGrGNpvDpddvprNGrlTJ"));
+    assert_eq!(Ok(2631), badges_sum("FqdWDFppHWhmwwzdjvjTRTznjdMv\nZBJrDVfQcfSRMLjZnjjM\ncBffPfbrbQcgQJggfVQJBPbCwlPtWFDWHFHhpmmGlGmlqmDG\nPNbMLgmPgRDgRtMPDdmdbmdmQrTBVCZnVnpCnNHHVZBNVZHc\nljvvqhlvshhnrcpBZqpTcr\nzGhWzFTJvsFttddWbMRdmP\nhhGdDhfdDhmdnHwtzzRtdzbzQQQg\nWTTZJLsvLRJCcWJWScWWLtzjwBzBttbBzSwVQjQBtt\nvLJcLLTRsvsTZPqHGhFHGhhPhfqD\ndJszLvzvCZZsJmszCrrdFmpppMpDMQPMgmDcDgSS\nnRjRbnnjlNhblnjtVtQlWttMFPfMfPgDMpBgSBPgBS\nGWVQTVTnTNhjblQNRsdJLLdTCdzdZLrdrJ\nRnLJBfmJfmNBHlQvvbdQ\ngrhgrtqgjJhhggNHqvwWqvbNlbHw\nGVTTsFFjJjVVFVGCFTJDDjhFcZmRMZfnZcncSpMSGcRPZpLp\nbwSNRNSRzSWnPnJRldMBMQmMlsCcPPCP\nFGpDqTTVFFprpjLVQMMGtMclcmHGtBdc\nTjhhgTLpVZhpLDZqrTqZVpBSwJwzNnWNWJvzzNSggwwN\nPHTMsmwrJMwLJvJddvdHwvcWnnWfccqGnhhfGcDqsnGc\ntZVlzQZblBgcSqqRhRmzqf\nCmlgmZlQtFtZNlVZdHLMrMvvCrrvvTTC\nPrDGBBddprmzddrSqccRgSTpqbsMRR\nhvLtfFNvvZNfGGfRgbqsRNlTSSgsbM\nQQQjZHHQjvththFffCHPPzmGrmDzDWrWBw\nGrjGrpjjCsnwhsGGPwlPTPLPVttPqLVl\nHzSHHhczRlLTHqqq\nDbhvFSSzQcZbcFbcQjrJrMJmmZnGJmJnjn\nmvTDsJLwzlWNDDnZngZNdd\nBVtPqFMqtvQFqPqjFBMVtRZGNGhfNcfQdpfgnQgNcNgp\nHBHtPbHCLCzsLJvT\nnVHVFfggbQVmFFfhLpBpBTrfLBCB\ncjjRwJdqtwwwNNjcwRMwnLNTpNsGCnLsLGhBhpNn\ntJqStcRMMMjPwlnvzgQWzmHzlmQl\nnNSRcDHRmHhhDZZZdBDfWJdfrJ\nLGrGVtjCPCbbQQQQLvQpbVQZzsqsBMdBqMvZMMJZqJvdBW\nGCPLlLPPPbTpbCbpLPpVlmrmmrHhHcHnHrHHgTFFnm\nrQbnBrDTQcdpHttt\nqNsNpfjLpNLRNqLMtdVsddcmVzdzVh\nWJfLCfvpWpNLbwwwBwbZFvlZ\nZslGPhBWBJbNjmbTmSWN\nqtDCZcfZtDjbjCQvmmSj\nttpRzqtqRLDzpRtDdtPlhhZZGBHGPsGZPhwd\nWjtcNnMtztGFrsNjcgRHvdwHhhjRwlhwlg\npPSpPBPVqJqBCCPvZdHlVGddwdhvgH\nqpDBpBqPTDLqGLmpTPqbDmWMNrFrrFQnFMNnMNQtMm\ndNwfsjFLQLFNBhTCTPPTBJhhTP\nVmVMgqgRVHtztmgqgzgqRzgMPWCRWTpPCCPWPThDdZPCRZJp\nVMqtMgGHmgVMvmqMMtGMSmbslnQFcsndfjNNsnfscnbddF\nqbWcqrFNCJGSChvLGv\nMnslRSpSVsGgGhDDwLvl\nRjMtfnpmmmjSWSbjrNPN\nrsCsqTVgfCnQFdCznQ\nZRsBvMvmZMGQQmFnDmQzzQ\nMlGLlBjRGjjWWGRGMlNrLLrssTPNfLcTgrVr\nvpSBBcJnWnSmcqmcChdcgf\nNRNPFDwwDbThqTJffZQHhd\nDzPDNVNbLwPzMLRbNMDjBlvpWjrvrVJjjSsBjr\ndndGpnWdVnBPFFHTBgGH\nqCcCmjqNNJDtcJQjqJqMCQJcFTBRZRwBZBHDPRHBHZDbgBBR\nszJcCtNQqjqCNjjNhdgWfLLdhVWsSVnr\nNjJLgSLGGCLWvqNqNBvwFb\nhtmVmtHnlZmDVhtmhblmDMHddBRvwwZQQqWPFvFwdvRWdR\nHnlnHfDsbSTbJzsz\nJvZfsPsPhRfZZnCdtnmjHVRLCH\nTzcczTtgqpgGSTlHHVHCGjCdHdnQQQ\nwDBDSDlWzwwzDqqMtrMrfsfbNvJNJB\nThfQTdQzQbgdhdNbJFJSlbBLlmqqHBSHCRHsHm\nrWBWWPcvpGGwjPPpvpPGplRSnLLnCrLRsRlLsLmLmR\ncGWPPMtpZDwpMpJQBQQQDBBVNfdQ\nWcWDRLSzFrRFFccPmnssMrGtmMnnGm\njvvgCCTvNqTtJqsnqwPBmspZMB\ngbVgbvlChJVbCdbtTgjdLWSHWcHSDcQzFzRzDF\nzTrHgrFWRrWvMpPNBVZZHVfN\nhdnlltGLtGSQPVLSNBQN\nCqGGtGwlhlGdtGmbtjtmmvDRFvVFTTRDRbRgFJRbDr\nDTFpQFrZDFBDFrTNFjSWJsWlWjsRJcslsp\nzzPqvdqNzvqzfzMfzqmCzzfJsclWjSSRWglgjclWSmcjlJ\nhfPGwhhvMGCVCdddhQQZQnNrTDQnFrtn\nDDMFjwVTgVmMWgVpdqtlJnpvHHnslw\nfFfZzLSfzBfZBZtqJJHsnSsldlld\nGLcNhRGLZBhmDWTjDTWF\nFbVcQRVRBFfNFfccVfZcWddnGrrHncWtdHsZ\nhwGwjgTSGrssZHHTdn\nLvPvCJgJLwjlSJmSPLvvgGfFBpBVfLLMBQMRRbzMVb\nrfMCJPBMMCrSCSBGZZqRlRLzqhqh\nLDvdNmbgHjHgnmnvnHjgDjqlZhZzszhqzWsRWRRNcRNZ\ndmbvHDdnjDjVmjTmHjJVrfprPCCVtLSMrSpJ\nfdfTBfNVZffMmdfdhGhcJLJrvnlJvrBJ\nFFWFWTzWSWtFgPHgRPWTzggpJcvvSCGSGJnrvhhrrJhlGlGn\npztFjzRTqWzgHqHWtPtPFgmsNfqbDdwDNVwbmfwbdNsb\nzCHvDWwvCwgpNRCWWHttCwvNPVTqrRrVbbsnbqQPbVsbPrqG\njBBcmZBGmBSSJdmhBVbnnQrVbVqqVPbhbP\nZfJBmLjfJZMcdZmJffGMtHtwNCvWwwwztMzg\nrgFgllfdpFlTHfTnfnNPNtPBBVtpzmVVPmmP\nbLSSbGhGWSWmLzztcQPCQC\njvwhhwRwbwshjGhWwsRRTFflFJFzfddFrHzTrJ\nGSwgSdwfvdfvwgGwBLdJbjjpmFjSTRpqHmRrjptrqt\nNDMVMCsWQVCsQFjFTHjVLqjFrp\nPzWDDCNNlCWfbhZZLfBGwl\nclFLFpFJcVmmWWgWWcWTdwZqCZCZqDqDDSwvwrdHCZ\nGGLfGtbfMNbbLGtzjBNnsMjjZCwDvQZHrHQvDHHHHHHrrNHq\nBthbnfPGMstmJgRPpFRTLL\nVRcdVRPTgVTLVMwmggJBwblJFlmb\nCqDDQsjCCrnnnQQtDcrnGCssJZNvZbvNQmZwmZlbNbwFNwmF\npstjcqrprsHrfDpnrCnHCzRhSMhMMMRVPLMhddfhzT\nQNDQThccDghdcLLgVsrVLVlMGM\nBbbWpppFFpnfnFbBBPRMTJMRsGlJGlJVLf\nwpnbmvbnSwSnmzHHzwwhQqTctNCcqdNqZvhQjj\nlwCLwLjzLhLHCvwjGCZJbQSHdBQdDdbDtdSQ\npnzcTVsszpncgFdJpbtDBDttDSJS\nzTPcRrfzgzCCvGRhLLqW\nBgDbztvdDzLZZwMbDDcFHQQJPLWWPJQJHTHF\njfpNqSrpCqNfNSpjCqSqshNFRQJcJWRRFssgWRHWWFWHQJ\nmrnNNgNfMZwmDtZw\nZrrFPQsQPRLcvPJvhg\nHpmTjnBmDDDwqtVcHNvJhNVRhgvh\ntTpTGfnttqwnqQJzFdzfSdzSzr\nqVVZqfVNdnBZMNzNnPzfMqbzJvFSjSllvjBwrvrvFrlsrjJs\nLhHtDTmWmWmGDhGLWHghHLCwrwRjjsPJrSjFjFrFClvrww\nmDDgtcLcmtTWTTHhpWLDHhDNMqVnqfdqbZdQpqVbzZVPVb\nWGpVMtGZplgHVWMtZpZFHJjndvFdjddJhnjLHF\nDTrRcSSccfzcCPDCTnvJvjbrrhjNvqjqJL\nzczwPDTmfZZtgLwWlp\nsTtTpvMjpBdmTlhlBTdvsvdncmVcVVVVHzFqVnFfqzHzWV\nGGwQRZNCRgDgsDDbSbSgfGFFrHfVHVVWrHWWcVFF\nRZRPgbNSJDsTsplPLsvd\nGrGNGhpnPFFBfCQCMwrVlwTC\nPPWHmmSvSvdTfJvJVVfCfJ\nRjWDmDdDbjcSHmRRLRRHjdDBsFFbBngBzhqbBhpqBPgFFg\nTfdNjJjmShGcWvQNQqcNFN\ngsRZRMqbDpsHHnZnngMZFPlzQRPWvRtPwtRWzlPv\nbrgMMgZCLsDpHGGjfGJVSCqddT\nBdSRjHScGMVjGdcScwLgqQqphNqNDqBBQW\nzzQClvtttrwqrwgCwp\nPQJTzvJJTtJQlvQftmfdmdmGRcSdcMSGdS\nPjPwvwlfGlGCGwppWBsWQVVQnpCQ\nFRSHzMJdrfRnpmpWmp\nTzJTMNNrHLJfTJccvjqwwgGLZLLc\ndCpjsGvcsLvszTrRRlRrDJ\nhPNqqpbhFlrDnPrRnz\nSBbphFNtLvvSfLfc\nzTFnnZzqrjFVnZTrtwMDptbpMwMMBDzb\nJGPCjWGgJjPWGJjNchmbBRpNNDsRsNBbbRBppD\nJWCggWSCmhCmvWHTQrTQfHjdVlQZll\nWWbrmZjbmjpbWSmcWHSbLddwvDggLFDhFrRlFFDw\nqPMVzTPQVfzvVzBQTMtRswwlDdhDghhsfLwdhs\nMMMPQBPJPzCVzvzQVtBJJMzCbmSZNWbSccHHmmZpWSZGZS\nBgmMgjlBMjHPssBnwphtFwhSVVmGGwFS\nrfCqQQrfvCQNRqCnCthFVSGSGtVS\nTrzLNvWfQvrWQZNWsnHsssBBcZBMsDdZ\nCtJCddDHDDPGHCdNVLPBdLDbbGFbwZSsSSZrfFFrGbGlsb\ngvjpnvhRphjmpmSfsTSnPZZrZfrZ\nghRRRhQgWvmPWtHHtLdMtH\nqNgQgNgNQFVbqVQDMRZMDRBHHJHRFh\njnTTPzWZCzrWzGRHMrhmBvJBSBBM\njtCGWnCWPTsTzlctWPtqLbwqZggNwgLbwdVq\nDJgJDgFqCGlhFDGDCWhqCwRfpbcpbsgsVscRpwbwws\nSmLZQmMVvLbsbssNQsQN\ntHZMLZZZMVtmnMHWDHjWlGhFDqCl\nPZRmjlDBWRBWBQMdQQBDPRhfzSSTTnfFnfzFpVFjfSVFgS\nwwHCHLtLwnzNHVTZZV\nrJLtZtLZcCrvJwcbrGLvrcDQDhPldhGdmmPRQhhRBlPM\nzShzVhbqlbpbRNRscBNwCc\nDfmFWnfmnMFDdngdngvJFHgQQNwNsHHCQCwNTTzRcN\nFFFZMWWZZJMmJJMFFpztlPtSllLVphttGZ\ngWhGwmwQGhVwGzBMnDFmdmDDLbCnLn\ntRRHNHHlPHPfPltllNNNRsVPFrTrTbrMMTTDMdLFCrFdPP\nHvRvvHNpNvfqSshwqwVQZWhggwhw\njDDRDVqNsRMMVFjFbtzpBlpllCBlhSLHSStH\nJJWZZTwWcmZCzQShHhzhhm\nwTrgvTwccvdcfvJvWJrvJTNzNMfGNNGjFRsjNDbfVzDs\ncVWPPThWctcFRdQpzLTzBL\nNNCqwNSrsDqNSSgLgffDCNpBdRvvdpmHRQvBdBqvdzBB\nCgbffsDJsSsNgbgJrlcGhGtjnWcJPncjWL\nRwwTGRjGlwWNgjgfQVNmjj\nHJPdLhLzhbtbdLPLbHHJLdcfBffBgrFQVZgzvgBfvrvvmr\ncmchnhtPqLSJJbdPLntlsDGCTWWDsDRwWWWTwS\nDDDBsPGPbwhDcDcj\nrgfNgCmHMvrrttvtfmNLgrLcQQJTJhcnjTQHcZlwlQHnwj\nwgfLmgFgWdBFzSVR\nRwsbssJjnbJwwsGPPdDLfTDLLLWvWNDGDpWD\nzVtHqzHHVcBQTdpSgvQDSpTS\nHHhFCdrrHchmrhcmwsjZwRwMJlshJJJZ\nBdQgtJMLBqshLfNhbccfFhffNc\nDnVWvvpvWvzrpwRWDbTvbTjTFGlfFSfNSFHGmFGNFmnPlmFS\nwDRDZzVWVjrRVjzrQgQdbgCZLCsCQtbd\njQSgWjQmFFvHmjHWVVpZbGlbGlfGpbfGGWpf\nzBPtTqzPBcdwrzPJwqCztTNvCGpbGfDnfhZpNGGZGZbG\nMTzPtcrcwzTttdBJwPvFVHjMLFHQMgsSLHvs\nBHtjmmTtmDtHZjMMdNzCzCWcWZsZdsZs\nJRRJVPLwQJrVMPJLVVwChpRRhcdzcNzhzChNdW\nSrFwfGwGSnnGPlBMDtjbllggBn\nrsMDTrgsBNBgMgDBhfhDghrtcRWJttcmGRWLGQQLJRrqRL\nlnVVjvPbwpndvVwlVCjVwtLcsmLLqWWtttlsmGGcmm\nSsjVSCZbgTBTfNZH\nwlddvlldlBzqSmStdqmmngwgDCgrpMpgCngrMCfn\njVLTHGRHjjvPGcDrfNMbnpngVbpf\nRJRTTRZJLRJQZcGGHLhHvTdmBFWFtWzqdWWQlFzqBFWt\nHDgZHpZSDpBQdRpHHRsDBNNzTvfTQqcqNNTqTqPQvl\nrnMFFMFJwmNgvmfTzcfq\nCWMtjCWMCCLWrWVWJwBbpgtRZSHbddZHBDDb\nhjLWPZJpZptwJghSfgHTMTgRMR\nlCcrblcnlzqDsvbfffTRSMnffjHTgj\nsqscDcblGDDpWPwFGwjLLp\nplQtRqRlGpPPPLZtmtpttRtJjHJvddLTHrLHJJjbdHvrrN\ngcMgTBWBCTczjnvNznnbgH\nFBhSwsWFWDhQZQqTTZllsT\nWsVttWDbvbtRjDVtDbDbJjrjFpCCcBrSCwrrjwww\nnzHqGNMfHqMMwJLLsNBrNcBS\nqgnHflgzHlqlZzhnzssfzbZtmVvmTQvQdTtbbDbTvv\nRtQCBbJDFhJtQtZtCbMnVnPVfGPpJVJWWmwJPG\nNsczRsrdrNrjlcSTGGnwmGmnmVPWwmVT\nSHcljRHzNHHjSHcrgNslcczgZtDgMDBCvQQbhDBCbFBCFCCF\ndRTFRJTRTgJzSSJmzJfN\njLbQllLvvvrQlLQBrvQmFBGzCFtPSMmSGCtPPF\nQjsvDvqlvrQQvnsLjDWghTwZWcccFdRVpc\nbWnDbMJMFbhZSfngpfpd\nHjcrlvjRjrjlLqTqpwQgpfSQgghZgvgf\nTHNTjClLHDCCpWmbtC\nNpBNsnFMLBcPMZccbQGblWRgGZmbRWdW\nCqrJTHCvDfrfwwJHRBvRGvgQmvmlWtlR\nrHzJJJDDwJCqjHTwBLccMpFhshnSSsMz\nbqVqqrDMpLFbLpJJQDMjbpZndwsvwHHswvnvnnZslG\nhgfRghhBWgfzBgZvncvcGvWrnZsG\nfTSSSBtTzhPththRrrCBzVLqMMJQjVJJCLjFjjqjJq\npQlCJQjVvVGGnjNqFbFP\nwTqtmSmTBhstmstTmWRSdLdfPdNGnhGhdPNnNnrN\nmzTZBwWTcSTsTmZRRmzTsVMMVMQMDJgVqqMgcJlVDl\nvVwCqdCDvMrlDJCqrDMrPdTFWwZNbRcbmZWQbRQZWZcQWm\nfGSfhfjHGBlhSpRmpZFZNbQWjj\nntGlgSSGgStVqdMCDCJnqd\nTlTRCrhCpmnCRtMRRWbvMBfwBB\nDVdQcsccdczbtQBWvmQBBW\nPgHPNcmcqqTphlHTCGrT\nGVLCBmdLVtlrmqGCqrTCGnjGSfwzNfPzfNNGzSSw\nrMDcWMJsDWRvRcsZPwpJpSzjwwfFjfjJ\nhcsscQQsDcWbHbmLgTbVgTmlrbgq\nVwJndPThQQjdvbrb\nBHlzFFjlZGBBlZBDFSmllfGggLNbNvggtCgNrLQLbfvbfg\nSHSHHlZSGZSDHGzHssGmnjhjphJPPpPwnsJRwPMM\nQFFGpfGtwgtjwvpwpGGjjBHgqzNNllHRlRllNdBRJl\nSWVDWVrDhnWhnqVnWPrhcSldBJPsRMMdPHsRdJHRMMBP\nSWZZcmnnZnSCLZDZftFFCvbfTTqTCTQF\nTvZBTFZpshPggBNN\nbbctWQDjcnwtwDDDllQzLfgsPzqgrsTPLshWfP\nbwRbQRnmDwtbTjmwRJQRQVZHpCpHVdvpMHZZMHZJvd\nCfqzMCGvGqNrCFFNwcMBbnnbbtBbDStw\nsJjsTjvVjLVlLLbtHSnSSwQsDBSH\ndJlvLVgJTRVPWWjjggCzhNGzzGZdhqrdmqrq\nzGSHWGjzpRsWVfsNwZ\nBmPCLzPlJBBvQmLFQrwwrVtfqtqZtsvwfR\nhLLzlmmBPFFLJMlmgDTMgdSGbDgGHbSp\nppngVjZwNZwwVJjjnnVVJJJpLrsgmsmrbctsLcLmscDggDsL\nvHvRzRPvQPPRqPTlffRTrssbtqmmtbcMcNrDrrtD\nCHWdWzWQzdChNVZjZZwB\nLjVFhwjbFggMdVggTVMNRWRGWqQWtRNWGlQW\nDCzCpJppzJJDScFBzvBGnRWGWrNRQNNWGtNGqB\nvFJSzDSszzzccSZHzDDJmvSJhVwLjfVTPZLfwLgjThLZwhVh\nFMSSNScRlSGzfqWbqqcpWBhpmW\nTrPrnQNnJwtQpmmpmbbL\nvwvCsJZZnnwVnVHsfNjDRgSjRzgRjZjN\nCRhsghlqlvjhPslQdrMndMTWdPnTMJ\ntcDNFDpDSDwDtHrSDwDtFmtpJzWTnzQJpzQGnGWQMWnnTGBd\nZfFSmSfwcwcScZHtVRhRCqLqCfrVrrfg\nvHPsBzvRvhCzbwbnjHLVmVbW\nggdlpZNdZdflWTGpVMjgVbqjVmngnVbr\nlNpfllFGpZTtclDZzSWQFCWWzChPzQhS\nddbJQGsgJcQccCjjTC\nDDflHqNNNjCCPNbT\nLFpLzfzqHzLbLvtndJhs\nmHtbGdwzmtHZrtrHtHGwrmtcFgBFRsBFcdglRsfsdgJBNN\nWvpSVjCCTCSPVDqTVjLqJwWRcWsNlgBcBlfWBRfl\nqDwjvqjVTDDLSPpnjqVnzbhbZbrGMmHzbMHHhh\ncMrrVsdtCdVtwqdHgLjPLFLfcfZZJB\nzGSlGGvpDWWvbSSNTggZPPTTJLZPjPzJ\nbNQDWbjlGSGNnWGblbQDQCmdqhsMtrsVnCrtnwdnCt\nWZSnCCMMdMMMSJMSVZmmFqVjqjVwVjjc\nzvzTlQQQQTNGbmQTjqBjcwwwGgBHGwqB\nmthbtmlPhTrNzNhhPLzlPzWfWPJdRCWspPWSSRsWSnJp\nnmqsjNFqNLcVlPNvRdvPPv\nMrggHrMpbtTpgpDptlQRJJldVRPBsDQvvV\ngrgrTzfTGSFGsGSCLc\nhjdjCfQCLdQcWMfDDQhLsLCjqvVqzzZZprBFbzVdvVnqnBbd\nNTglmJGJRnRZVrVvFr\ngPGgPJSTPJtHncnjWDhDMDhP\nZWWqBqqmgFFDMTTWDMwwcW\nddGPpJQPprRSCrQzJPJGjHDvssMTDZDwcHhMsHsMHS\nzNRGrCRRCjPpdGQJNglqntnbtgNBZtFqtt\npslQSspQrqHfgMRl\ntJBTjsTLGMBGMFMg\ntDwnhZdnLdZDwczzcPvsVvVW\nnZnMSDnGtnzmzWZZcMmgMcHwFFVHhHwbHsVrwQhVhF\ndlTqNqfTjdJflCppCQQRHVwFRJrvJsFVQs\nLNTqBLPprpLLzWtSnMZBMDGm\nPBjlPvvcJlJzwqjnnjLnwm\ndMfdpVfVhGVfVpVTtWWbzwngLMLwMRCmgmLMmsww\nSWppdTrprThhrGVztcrllrQJZNlBZlrNZB\nTTrNcjGNWDdDPDpPjDPNrbmFPfQSFmfSMCmCfSmSSR\nsshhqshzBVnzHgwCMbCwcfmRFmRMbm\ngHVVnBzJcBgVhtZZtgJhpGrGNpvDpddvprNGrlTJ", 3));
+    // other group sizes
+    assert_eq!(Ok(2 + 4), badges_sum("ab\nbc\ncd\nde", 2));
+    assert_eq!(Ok(1), badges_sum("abc\nade\nafg\nahi", 4));
+    assert_eq!(
+        Err(String::from("5 rucksacks can't be split in groups of 2")),
+        badges_sum("a\na\na\na\na", 2)
+    );
+    // ungrouped rucksacks
+    assert_eq!(
+        Ok(vec![vec![0, 2, 4], vec![1, 3, 5]]),
+        groups_detect(
+            "vJrwpWtwJgWrhcsFMMfFFhFp\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nttgJtRGJQctTZtZT\nPmmdzqPrVvPwwTWBwg\nCrZsJsPPZsGzwwsLwLmpwMDw",
+            3
+        )
+    );
+    assert_eq!(Ok(70), badges_sum_ungrouped("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw", 3));
+    assert_eq!(
+        Ok(vec![vec![0, 3], vec![1, 2]]),
+        groups_detect("ab\ncd\nce\naf", 2)
+    );
+    assert_eq!(
+        Err(String::from(
+            "no partition in groups of 2 sharing exactly one item"
+        )),
+        groups_detect("ab\nab\ncd\ncd", 2)
+    );
+    // invalid inputs
+    assert_eq!(
+        Err(String::from("invalid item '-'")),
+        badge_find(&["a-", "ab", "ac"])
+    );
+    assert_eq!(
+        Err(String::from("line 1: group shares 2 items instead of 1")),
+        badges_sum("ab\nab\nab", 3)
+    );
 }
 
 /*
@@ -1,55 +1,155 @@
 // https://adventofcode.com/2022/day/5
-// (part 1)
+// (part 1 and 2)
 
-const CRATE_STR_LEN: usize = 4; // include separator
-const CRATE_STR_CRATE_INDEX: usize = 1; // index of the crate in the str
 const COMMAND_MOVE_PREFIX: &str = "move ";
 const COMMAND_FROM_PREFIX: &str = " from ";
 const COMMAND_TO_PREFIX: &str = " to ";
+const PLAN_STATES_MAX: usize = 200_000;
 
-fn stacks_build(lines: &mut Vec<&str>) -> Vec<Vec<char>> {
-    let mut stacks: Vec<Vec<char>> = Vec::new();
+// crate label, usually a single letter but `[AB]` style labels are accepted too
+type Crate = String;
 
-    let lines_count: usize = lines.len();
-    if lines_count > 0 {
-        let crates_line_len: usize = lines[0].len();
-        // create stacks
-        let stacks_count: usize = (crates_line_len / CRATE_STR_LEN) + 1; // +1 cause the last crate doesn't have a separator
-        for __ in 0..stacks_count {
-            stacks.push(Vec::new());
-        }
-        // populate stacks
-        let mut lines_i: usize = 0;
-        while lines_i < lines_count {
-            let crate_line = lines[lines_i];
-            if crate_line.len() == 0 {
-                // end of crates
-                break;
-            }
-            lines_i += 1;
-            for i in 0..stacks_count {
-                let c: char = crate_line
-                    .chars()
-                    .nth(i * CRATE_STR_LEN + CRATE_STR_CRATE_INDEX)
-                    .unwrap();
-                if c != ' ' {
-                    stacks[i].insert(0, c);
-                }
-            }
+// column span (both ends included) of each stack index in the index row, in stack order
+fn stack_columns_get(index_line: &str, line_number: usize) -> Result<Vec<[usize; 2]>, String> {
+    let mut columns: Vec<[usize; 2]> = Vec::new();
+
+    let chars: Vec<char> = index_line.chars().collect();
+    let mut i: usize = 0;
+    while i < chars.len() {
+        if chars[i] == ' ' {
+            i += 1;
+            continue;
+        }
+        let start: usize = i;
+        while i < chars.len() && chars[i] != ' ' {
+            i += 1;
+        }
+        let index: String = chars[start..i].iter().collect();
+        if index.parse::<usize>() != Ok(columns.len() + 1) {
+            return Err(format!(
+                "line {}, column {}: expected stack index {}, found '{}'",
+                line_number,
+                start + 1,
+                columns.len() + 1,
+                index
+            ));
+        }
+        columns.push([start, i - 1]);
+    }
+    if columns.is_empty() {
+        return Err(format!("line {}, column 1: no stack index", line_number));
+    }
+
+    return Ok(columns);
+}
+
+// crates of a drawing line with the stack they are above and their column, `[` and `]` of each
+// crate must overlap the stack index column
+fn crates_line_parse(
+    line: &str,
+    line_number: usize,
+    columns: &Vec<[usize; 2]>,
+) -> Result<Vec<(usize, usize, Crate)>, String> {
+    let mut crates: Vec<(usize, usize, Crate)> = Vec::new();
+
+    let chars: Vec<char> = line.chars().collect();
+    let mut i: usize = 0;
+    while i < chars.len() {
+        if chars[i] == ' ' {
+            i += 1;
+            continue;
+        }
+        if chars[i] != '[' {
+            return Err(format!(
+                "line {}, column {}: expected '[', found '{}'",
+                line_number,
+                i + 1,
+                chars[i]
+            ));
+        }
+        let start: usize = i;
+        let end: Option<usize> = chars[start..].iter().position(|c| *c == ']');
+        if end.is_none() {
+            return Err(format!(
+                "line {}, column {}: crate is not closed",
+                line_number,
+                start + 1
+            ));
+        }
+        let end: usize = start + end.unwrap();
+        let label: Crate = chars[start + 1..end].iter().collect();
+        if label.is_empty() || label.contains(|c: char| c == ' ' || c == '[') {
+            return Err(format!(
+                "line {}, column {}: invalid crate label '{}'",
+                line_number,
+                start + 1,
+                label
+            ));
+        }
+        let stack: Option<usize> = columns
+            .iter()
+            .position(|column| column[0] <= end && start <= column[1]);
+        if stack.is_none() {
+            return Err(format!(
+                "line {}, column {}: crate [{}] is not above a stack index",
+                line_number,
+                start + 1,
+                label
+            ));
+        }
+        let stack: usize = stack.unwrap();
+        if crates.last().map(|(s, _, _)| *s) == Some(stack) {
+            return Err(format!(
+                "line {}, column {}: two crates above stack {}",
+                line_number,
+                start + 1,
+                stack + 1
+            ));
         }
-        // strip crate lines from input
-        lines.drain(0..lines_i + 1);
+        crates.push((stack, start, label));
+        i = end + 1;
+    }
+
+    return Ok(crates);
+}
+
+// reads the drawing lines (up to the first empty line, which is removed too) from `lines`, the
+// stacks being located from the index row rather than from fixed width columns
+fn stacks_build(lines: &mut Vec<&str>) -> Result<Vec<Vec<Crate>>, String> {
+    let drawing_len: usize = lines
+        .iter()
+        .position(|line| line.len() == 0)
+        .unwrap_or(lines.len());
+    if drawing_len == 0 {
+        return Err(String::from("line 1, column 1: no stacks drawing"));
+    }
 
-        // remove last crate of each stacks, it's the stacks index
-        for stack in &mut stacks {
-            stack.remove(0);
+    let columns: Vec<[usize; 2]> = stack_columns_get(lines[drawing_len - 1], drawing_len)?;
+    let mut stacks: Vec<Vec<Crate>> = vec![Vec::new(); columns.len()];
+    // bottom up, so a crate missing under another one can be detected
+    for line_i in (0..drawing_len - 1).rev() {
+        let level: usize = drawing_len - 2 - line_i;
+        for (stack, column, label) in crates_line_parse(lines[line_i], line_i + 1, &columns)? {
+            if stacks[stack].len() != level {
+                return Err(format!(
+                    "line {}, column {}: crate [{}] of stack {} is floating",
+                    line_i + 1,
+                    column + 1,
+                    label,
+                    stack + 1
+                ));
+            }
+            stacks[stack].push(label);
         }
     }
 
-    return stacks;
+    // strip crate lines from input
+    lines.drain(0..(drawing_len + 1).min(lines.len()));
+
+    return Ok(stacks);
 }
 
-fn number_parse(number: &str) -> (usize, usize) {
+fn number_parse(number: &str) -> Result<(usize, usize), String> {
     let mut digit_count: usize = 0;
     for c in number.chars() {
         if c < '0' || c > '9' {
@@ -57,89 +157,1081 @@ fn number_parse(number: &str) -> (usize, usize) {
         }
         digit_count += 1;
     }
-    let n = number
-        .get(0..digit_count)
-        .unwrap()
-        .parse::<usize>()
-        .unwrap();
-    return (n, digit_count);
+    let n = number.get(0..digit_count).unwrap().parse::<usize>();
+    if n.is_err() {
+        return Err(format!("expected a number, found '{}'", number));
+    }
+    return Ok((n.unwrap(), digit_count));
 }
 
-fn command_parse(command: &str) -> (usize, usize, usize) {
+fn prefix_check(command: &str, index: usize, prefix: &str) -> Result<(), String> {
+    if command.get(index..index + prefix.len()) != Some(prefix) {
+        return Err(format!(
+            "expected '{}' at column {}",
+            prefix.trim(),
+            index + 1
+        ));
+    }
+    return Ok(());
+}
+
+fn command_parse(command: &str) -> Result<(usize, usize, usize), String> {
     // move count
-    assert_eq!(
-        Some(COMMAND_MOVE_PREFIX),
-        command.get(0..COMMAND_MOVE_PREFIX.len())
-    );
+    prefix_check(command, 0, COMMAND_MOVE_PREFIX)?;
     let (cmd_move_count, move_char_read_count) =
-        number_parse(command.get(COMMAND_MOVE_PREFIX.len()..).unwrap());
+        number_parse(command.get(COMMAND_MOVE_PREFIX.len()..).unwrap())?;
     // from index
     let from_str_index = COMMAND_MOVE_PREFIX.len() + move_char_read_count;
-    assert_eq!(
-        Some(COMMAND_FROM_PREFIX),
-        command.get(from_str_index..from_str_index + COMMAND_FROM_PREFIX.len())
-    );
+    prefix_check(command, from_str_index, COMMAND_FROM_PREFIX)?;
     let (cmd_from_index, from_char_read_count) = number_parse(
         command
             .get(from_str_index + COMMAND_FROM_PREFIX.len()..)
             .unwrap(),
-    );
+    )?;
     // to index
     let to_str_index = from_str_index + COMMAND_FROM_PREFIX.len() + from_char_read_count;
-    assert_eq!(
-        Some(COMMAND_TO_PREFIX),
-        command.get(to_str_index..to_str_index + COMMAND_TO_PREFIX.len())
-    );
+    prefix_check(command, to_str_index, COMMAND_TO_PREFIX)?;
     let (cmd_to_index, to_char_read_count) = number_parse(
         command
             .get(to_str_index + COMMAND_TO_PREFIX.len()..)
             .unwrap(),
-    );
-    assert!(
-        command.len()
-            == COMMAND_MOVE_PREFIX.len()
-                + move_char_read_count
-                + COMMAND_FROM_PREFIX.len()
-                + from_char_read_count
-                + COMMAND_TO_PREFIX.len()
-                + to_char_read_count
-    );
+    )?;
+    let command_len: usize = to_str_index + COMMAND_TO_PREFIX.len() + to_char_read_count;
+    if command.len() != command_len {
+        return Err(format!(
+            "unexpected '{}' at column {}",
+            &command[command_len..],
+            command_len + 1
+        ));
+    }
+
+    return Ok((cmd_move_count, cmd_from_index, cmd_to_index));
+}
+
+// checks a command (stack indices starting at 1) can be run on the current stacks
+fn command_check(
+    stacks: &Vec<Vec<Crate>>,
+    count: usize,
+    from: usize,
+    to: usize,
+) -> Result<(), String> {
+    for index in [from, to] {
+        if index == 0 || index > stacks.len() {
+            return Err(format!(
+                "stack {} doesn't exist, stacks are 1 to {}",
+                index,
+                stacks.len()
+            ));
+        }
+    }
+    if stacks[from - 1].len() < count {
+        return Err(format!(
+            "stack {} only has {} crates",
+            from,
+            stacks[from - 1].len()
+        ));
+    }
+    return Ok(());
+}
 
-    return (cmd_move_count, cmd_from_index, cmd_to_index);
+// a crane model, moving crates between stacks (indices starting at 0)
+trait Crane {
+    fn name(&self) -> String;
+    fn crates_move(&self, stacks: &mut Vec<Vec<Crate>>, count: usize, from: usize, to: usize);
 }
 
-fn crates_move(stacks: &mut Vec<Vec<char>>, lines: &Vec<&str>) {
-    for line in lines {
-        let (count, from, to) = command_parse(line);
-        // indices from the command starts at 1
-        assert!(from > 0);
-        assert!(to > 0);
+// part 1, moves crates one at a time
+struct CrateMover9000 {}
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        return String::from("CrateMover 9000");
+    }
+
+    fn crates_move(&self, stacks: &mut Vec<Vec<Crate>>, count: usize, from: usize, to: usize) {
         for _ in 0..count {
-            let c = stacks[from - 1].pop().unwrap();
-            stacks[to - 1].push(c);
+            let c = stacks[from].pop().unwrap();
+            stacks[to].push(c);
         }
     }
 }
 
-fn crates_rearrange(list: &str) -> String {
-    let mut lines: std::vec::Vec<&str> = list.split('\n').collect();
+// part 2, moves all the crates at once, keeping their order
+struct CrateMover9001 {}
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        return String::from("CrateMover 9001");
+    }
+
+    fn crates_move(&self, stacks: &mut Vec<Vec<Crate>>, count: usize, from: usize, to: usize) {
+        let l = stacks[from].len();
+        let stacks_to_move = stacks[from].split_off(l - count);
+        stacks[to].extend_from_slice(&stacks_to_move);
+    }
+}
+
+// moves blocks of at most `capacity` crates, so bigger moves are split in several lifts
+struct CrateMoverCapacity {
+    capacity: usize,
+}
+
+impl Crane for CrateMoverCapacity {
+    fn name(&self) -> String {
+        return format!("CrateMover with a {} crates capacity", self.capacity);
+    }
+
+    fn crates_move(&self, stacks: &mut Vec<Vec<Crate>>, count: usize, from: usize, to: usize) {
+        let mut remaining: usize = count;
+        while remaining > 0 {
+            let lift: usize = remaining.min(self.capacity);
+            CrateMover9001 {}.crates_move(stacks, lift, from, to);
+            remaining -= lift;
+        }
+    }
+}
+
+// `9000`, `9001` or `capacity:N`
+fn crane_get(model: &str) -> Option<Box<dyn Crane>> {
+    if model == "9000" {
+        return Some(Box::new(CrateMover9000 {}));
+    } else if model == "9001" {
+        return Some(Box::new(CrateMover9001 {}));
+    } else if let Some(capacity) = model.strip_prefix("capacity:") {
+        return match capacity.parse::<usize>() {
+            Ok(capacity) if capacity > 0 => {
+                Some(Box::new(CrateMoverCapacity { capacity: capacity }))
+            }
+            _ => None,
+        };
+    } else {
+        return None;
+    }
+}
+
+// first invalid command of a procedure, with the stacks as they were before it
+#[derive(Debug, PartialEq)]
+struct MoveFailure {
+    line_number: usize,
+    command: String,
+    reason: String,
+    stacks: Vec<Vec<Crate>>,
+}
+
+impl MoveFailure {
+    fn report(&self) -> String {
+        return format!(
+            "line {}: '{}': {}\n{}",
+            self.line_number,
+            self.command,
+            self.reason,
+            stacks_render(&self.stacks)
+        );
+    }
+}
+
+// runs a single command, leaving the stacks untouched when it is invalid
+fn command_run(
+    stacks: &mut Vec<Vec<Crate>>,
+    line: &str,
+    line_number: usize,
+    crane: &dyn Crane,
+) -> Result<(), MoveFailure> {
+    let checked: Result<(usize, usize, usize), String> =
+        command_parse(line).and_then(|(count, from, to)| {
+            command_check(stacks, count, from, to).map(|_| (count, from, to))
+        });
+    if let Err(reason) = checked {
+        return Err(MoveFailure {
+            line_number: line_number,
+            command: String::from(line),
+            reason: reason,
+            stacks: stacks.clone(),
+        });
+    }
+    let (count, from, to) = checked.unwrap();
+    // indices from the command starts at 1
+    crane.crates_move(stacks, count, from - 1, to - 1);
+    return Ok(());
+}
+
+// calls `on_move` after every command with the command line and the resulting stacks, stops at
+// the first invalid command, `first_line_number` being the line number of the first command
+fn crates_move_observed(
+    stacks: &mut Vec<Vec<Crate>>,
+    lines: &Vec<&str>,
+    first_line_number: usize,
+    crane: &dyn Crane,
+    on_move: &mut dyn FnMut(&str, &Vec<Vec<Crate>>),
+) -> Result<(), MoveFailure> {
+    for (i, line) in lines.iter().enumerate() {
+        command_run(stacks, line, first_line_number + i, crane)?;
+        on_move(line, stacks);
+    }
+    return Ok(());
+}
+
+fn crates_move(
+    stacks: &mut Vec<Vec<Crate>>,
+    lines: &Vec<&str>,
+    first_line_number: usize,
+    crane: &dyn Crane,
+) -> Result<(), MoveFailure> {
+    return crates_move_observed(stacks, lines, first_line_number, crane, &mut |_, _| {});
+}
 
-    let mut stacks: Vec<Vec<char>> = stacks_build(&mut lines);
-    crates_move(&mut stacks, &lines);
+// every invalid command of a procedure, invalid commands being skipped so the following ones are
+// checked against the state the valid ones lead to
+fn procedure_validate(
+    stacks: &Vec<Vec<Crate>>,
+    lines: &Vec<&str>,
+    first_line_number: usize,
+    crane: &dyn Crane,
+) -> Vec<MoveFailure> {
+    let mut failures: Vec<MoveFailure> = Vec::new();
 
+    // the stacks are only copied for the reports of the invalid commands
+    let mut stacks: Vec<Vec<Crate>> = stacks.clone();
+    for (i, line) in lines.iter().enumerate() {
+        if let Err(failure) = command_run(&mut stacks, line, first_line_number + i, crane) {
+            failures.push(failure);
+        }
+    }
+
+    return failures;
+}
+
+// stacks, procedure lines and line number of the first procedure line
+fn input_parse(list: &str) -> Result<(Vec<Vec<Crate>>, Vec<&str>, usize), String> {
+    let mut lines: std::vec::Vec<&str> = list.split('\n').collect();
+    let lines_count: usize = lines.len();
+    let stacks: Vec<Vec<Crate>> = stacks_build(&mut lines)?;
+    let first_line_number: usize = lines_count - lines.len() + 1;
+    return Ok((stacks, lines, first_line_number));
+}
+
+// crates on top of each stack, a space for empty stacks
+fn stacks_top(stacks: &Vec<Vec<Crate>>) -> String {
     let mut s: String = String::with_capacity(stacks.len());
     for stack in stacks {
-        s.push(stack[stack.len() - 1]);
+        match stack.last() {
+            Some(c) => s.push_str(c),
+            None => s.push(' '),
+        }
     }
-
     return s;
 }
 
+// stacks drawn as in the puzzle input, index row included, so `stacks_build` can read it back,
+// columns get wider for labels or indices longer than a single character
+fn stacks_render(stacks: &Vec<Vec<Crate>>) -> String {
+    let height: usize = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let label_len: usize = stacks
+        .iter()
+        .flatten()
+        .map(|c| c.chars().count())
+        .max()
+        .unwrap_or(1);
+    let width: usize = (label_len + 2).max(stacks.len().to_string().len());
+
+    let mut lines: Vec<String> = Vec::with_capacity(height + 1);
+    for level in (0..height).rev() {
+        let crates: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("{:^width$}", format!("[{}]", c), width = width),
+                None => " ".repeat(width),
+            })
+            .collect();
+        lines.push(crates.join(" "));
+    }
+    let indices: Vec<String> = (1..=stacks.len())
+        .map(|i| format!("{:^width$}", i, width = width))
+        .collect();
+    lines.push(indices.join(" "));
+
+    return lines.join("\n");
+}
+
+fn crates_rearrange(list: &str, crane: &dyn Crane) -> Result<String, String> {
+    let (mut stacks, lines, first_line_number) = input_parse(list)?;
+    crates_move(&mut stacks, &lines, first_line_number, crane).map_err(|f| f.report())?;
+
+    return Ok(stacks_top(&stacks));
+}
+
+// same as `crates_rearrange`, also returning the drawing of the final stacks
+fn crates_rearrange_drawing(list: &str, crane: &dyn Crane) -> Result<(String, String), String> {
+    let (mut stacks, lines, first_line_number) = input_parse(list)?;
+    crates_move(&mut stacks, &lines, first_line_number, crane).map_err(|f| f.report())?;
+
+    return Ok((stacks_top(&stacks), stacks_render(&stacks)));
+}
+
+// a move as it changed the stacks, so it can be undone and redone without the crane
+#[derive(Clone, Debug, PartialEq)]
+struct MoveRecord {
+    line_number: usize,
+    command: String,
+    from: usize,
+    to: usize,
+    // crates taken from the top of `from`, bottom first
+    taken: Vec<Crate>,
+    // crates put on `to` as indices in `taken`, bottom first
+    order: Vec<usize>,
+}
+
+fn move_redo(stacks: &mut Vec<Vec<Crate>>, record: &MoveRecord) {
+    let l: usize = stacks[record.from].len();
+    stacks[record.from].truncate(l - record.taken.len());
+    for i in &record.order {
+        stacks[record.to].push(record.taken[*i].clone());
+    }
+}
+
+fn move_undo(stacks: &mut Vec<Vec<Crate>>, record: &MoveRecord) {
+    let l: usize = stacks[record.to].len();
+    stacks[record.to].truncate(l - record.taken.len());
+    stacks[record.from].extend_from_slice(&record.taken);
+}
+
+// crate whose position differs between two points of the history, levels starting at 0 from
+// the bottom of the stacks and stacks at 1
+#[derive(Debug, PartialEq)]
+struct CrateMoved {
+    label: Crate,
+    from: (usize, usize),
+    to: (usize, usize),
+}
+
+// stacks with the history of the moves applied to them, `position` moves being currently done
+struct Simulation {
+    stacks: Vec<Vec<Crate>>,
+    history: Vec<MoveRecord>,
+    position: usize,
+}
+
+impl Simulation {
+    fn new(stacks: Vec<Vec<Crate>>) -> Simulation {
+        return Simulation {
+            stacks: stacks,
+            history: Vec::new(),
+            position: 0,
+        };
+    }
+
+    // runs a command, dropping the moves that were undone
+    fn apply(
+        &mut self,
+        line: &str,
+        line_number: usize,
+        crane: &dyn Crane,
+    ) -> Result<(), MoveFailure> {
+        let checked: Result<(usize, usize, usize), String> =
+            command_parse(line).and_then(|(count, from, to)| {
+                command_check(&self.stacks, count, from, to).map(|_| (count, from, to))
+            });
+        if let Err(reason) = checked {
+            return Err(MoveFailure {
+                line_number: line_number,
+                command: String::from(line),
+                reason: reason,
+                stacks: self.stacks.clone(),
+            });
+        }
+        let (count, from, to) = checked.unwrap();
+
+        // the crane moves indices on empty stacks to know where each crate ends up
+        let mut indices: Vec<Vec<Crate>> = vec![Vec::new(); self.stacks.len()];
+        indices[from - 1] = (0..count).map(|i| i.to_string()).collect();
+        crane.crates_move(&mut indices, count, from - 1, to - 1);
+        let l: usize = self.stacks[from - 1].len();
+        let record: MoveRecord = MoveRecord {
+            line_number: line_number,
+            command: String::from(line),
+            from: from - 1,
+            to: to - 1,
+            taken: self.stacks[from - 1][l - count..].to_vec(),
+            order: indices[to - 1]
+                .iter()
+                .map(|i| i.parse::<usize>().unwrap())
+                .collect(),
+        };
+
+        move_redo(&mut self.stacks, &record);
+        self.history.truncate(self.position);
+        self.history.push(record);
+        self.position += 1;
+        return Ok(());
+    }
+
+    fn run(
+        &mut self,
+        lines: &Vec<&str>,
+        first_line_number: usize,
+        crane: &dyn Crane,
+    ) -> Result<(), MoveFailure> {
+        for (i, line) in lines.iter().enumerate() {
+            self.apply(line, first_line_number + i, crane)?;
+        }
+        return Ok(());
+    }
+
+    fn undo(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        self.position -= 1;
+        move_undo(&mut self.stacks, &self.history[self.position]);
+        return true;
+    }
+
+    fn redo(&mut self) -> bool {
+        if self.position == self.history.len() {
+            return false;
+        }
+        move_redo(&mut self.stacks, &self.history[self.position]);
+        self.position += 1;
+        return true;
+    }
+
+    // undoes or redoes moves until `position` moves are done
+    fn goto(&mut self, position: usize) -> bool {
+        if position > self.history.len() {
+            return false;
+        }
+        while self.position > position {
+            self.undo();
+        }
+        while self.position < position {
+            self.redo();
+        }
+        return true;
+    }
+
+    // stacks once the first `position` moves are done
+    fn state_at(&self, position: usize) -> Option<Vec<Vec<Crate>>> {
+        if position > self.history.len() {
+            return None;
+        }
+        let mut stacks: Vec<Vec<Crate>> = self.stacks.clone();
+        if position < self.position {
+            for record in self.history[position..self.position].iter().rev() {
+                move_undo(&mut stacks, record);
+            }
+        } else {
+            for record in &self.history[self.position..position] {
+                move_redo(&mut stacks, record);
+            }
+        }
+        return Some(stacks);
+    }
+
+    // crates that are somewhere else after `end` moves than after `start` moves
+    fn diff(&self, start: usize, end: usize) -> Option<Vec<CrateMoved>> {
+        let stacks: Vec<Vec<Crate>> = self.state_at(start)?;
+        self.state_at(end)?;
+
+        // follow every crate of the start state by its position in that state
+        let mut ids: Vec<Vec<(usize, usize)>> = stacks
+            .iter()
+            .enumerate()
+            .map(|(s, stack)| (0..stack.len()).map(|level| (s, level)).collect())
+            .collect();
+        if start <= end {
+            for record in &self.history[start..end] {
+                let l: usize = ids[record.from].len();
+                let taken: Vec<(usize, usize)> = ids[record.from].split_off(l - record.taken.len());
+                for i in &record.order {
+                    ids[record.to].push(taken[*i]);
+                }
+            }
+        } else {
+            for record in self.history[end..start].iter().rev() {
+                let l: usize = ids[record.to].len();
+                let given: Vec<(usize, usize)> = ids[record.to].split_off(l - record.taken.len());
+                let mut taken: Vec<(usize, usize)> = given.clone();
+                for (i, index) in record.order.iter().enumerate() {
+                    taken[*index] = given[i];
+                }
+                ids[record.from].extend(taken);
+            }
+        }
+
+        let mut moved: Vec<CrateMoved> = Vec::new();
+        for (s, stack) in ids.iter().enumerate() {
+            for (level, id) in stack.iter().enumerate() {
+                if *id != (s, level) {
+                    moved.push(CrateMoved {
+                        label: stacks[id.0][id.1].clone(),
+                        from: (id.0 + 1, id.1),
+                        to: (s + 1, level),
+                    });
+                }
+            }
+        }
+        moved.sort_by_key(|m| (m.from.0, m.from.1));
+        return Some(moved);
+    }
+}
+
+enum PlanTarget {
+    // crates expected on top of each stack, as returned by `stacks_top`
+    Top(String),
+    Stacks(Vec<Vec<Crate>>),
+}
+
+impl PlanTarget {
+    // lower bound of the moves left, a move changing at most two stacks
+    fn moves_min(&self, stacks: &Vec<Vec<Crate>>) -> usize {
+        let wrong: usize = match self {
+            PlanTarget::Top(top) => {
+                if stacks_top(stacks) == *top {
+                    0
+                } else {
+                    stacks
+                        .iter()
+                        .zip(top.chars())
+                        .filter(|(stack, c)| match stack.last() {
+                            Some(label) => *label != c.to_string(),
+                            None => *c != ' ',
+                        })
+                        .count()
+                        .max(1)
+                }
+            }
+            PlanTarget::Stacks(target) => stacks
+                .iter()
+                .zip(target.iter())
+                .filter(|(stack, target)| stack != target)
+                .count(),
+        };
+        return (wrong + 1) / 2;
+    }
+}
+
+// shortest procedure (A* over the stacks states) leading to `target` with the given crane, gives
+// up after visiting `states_max` states
+fn plan_find(
+    stacks: &Vec<Vec<Crate>>,
+    target: &PlanTarget,
+    crane: &dyn Crane,
+    states_max: usize,
+) -> Result<Vec<String>, String> {
+    match target {
+        PlanTarget::Top(top) if top.chars().count() != stacks.len() => {
+            return Err(format!(
+                "target '{}' doesn't have one crate per stack ({})",
+                top,
+                stacks.len()
+            ));
+        }
+        PlanTarget::Stacks(target) if target.len() != stacks.len() => {
+            return Err(format!(
+                "target has {} stacks instead of {}",
+                target.len(),
+                stacks.len()
+            ));
+        }
+        _ => {}
+    }
+
+    // every visited state with the state it comes from and the command leading to it, states
+    // being shared with `visited` which keeps the least moves each one was reached with
+    let start: std::rc::Rc<Vec<Vec<Crate>>> = std::rc::Rc::new(stacks.clone());
+    let mut states: Vec<(std::rc::Rc<Vec<Vec<Crate>>>, usize, String)> =
+        vec![(start.clone(), 0, String::new())];
+    let mut visited: std::collections::HashMap<std::rc::Rc<Vec<Vec<Crate>>>, usize> =
+        std::collections::HashMap::new();
+    visited.insert(start, 0);
+    // (moves done + lower bound of the moves left, moves done, state)
+    let mut pending: std::collections::BinaryHeap<std::cmp::Reverse<(usize, usize, usize)>> =
+        std::collections::BinaryHeap::new();
+    pending.push(std::cmp::Reverse((target.moves_min(stacks), 0, 0)));
+
+    while let Some(std::cmp::Reverse((_, moves, state))) = pending.pop() {
+        let current: std::rc::Rc<Vec<Vec<Crate>>> = states[state].0.clone();
+        if target.moves_min(&current) == 0 {
+            let mut plan: Vec<String> = Vec::with_capacity(moves);
+            let mut i: usize = state;
+            while i != 0 {
+                plan.push(states[i].2.clone());
+                i = states[i].1;
+            }
+            plan.reverse();
+            return Ok(plan);
+        }
+        if visited[&current] < moves {
+            // already reached with less moves
+            continue;
+        }
+
+        for from in 0..stacks.len() {
+            for to in 0..stacks.len() {
+                if from == to {
+                    continue;
+                }
+                for count in 1..=current[from].len() {
+                    let mut next: Vec<Vec<Crate>> = (*current).clone();
+                    crane.crates_move(&mut next, count, from, to);
+                    if visited.get(&next).map_or(false, |m| *m <= moves + 1) {
+                        continue;
+                    }
+                    if states.len() >= states_max {
+                        return Err(format!("no plan found within {} states", states_max));
+                    }
+                    let estimate: usize = moves + 1 + target.moves_min(&next);
+                    let next: std::rc::Rc<Vec<Vec<Crate>>> = std::rc::Rc::new(next);
+                    visited.insert(next.clone(), moves + 1);
+                    states.push((
+                        next,
+                        state,
+                        format!("move {} from {} to {}", count, from + 1, to + 1),
+                    ));
+                    pending.push(std::cmp::Reverse((estimate, moves + 1, states.len() - 1)));
+                }
+            }
+        }
+    }
+
+    return Err(String::from("target can't be reached"));
+}
+
 fn main() {
+    if let Some(model) = std::env::args().nth(1) {
+        // drawing and procedure read from stdin, rearranged with the given crane model, with as
+        // second argument:
+        // - `trace` to draw the stacks after every move
+        // - `validate` to list every invalid move instead of stopping at the first one
+        // - `plan TOP` to search a procedure giving the TOP crates instead, `plan` alone
+        //   searching one giving the drawing that replaces the procedure in stdin
+        // - `state K` to draw the stacks after the first K moves
+        // - `diff A B` to list the crates moved between the first A and the first B moves
+        let crane: Option<Box<dyn Crane>> = crane_get(&model);
+        if crane.is_none() {
+            eprintln!(
+                "unknown crane model '{}', expected 9000, 9001 or capacity:N",
+                model
+            );
+            std::process::exit(1);
+        }
+        let crane: Box<dyn Crane> = crane.unwrap();
+        let mode: Option<String> = std::env::args().nth(2);
+        let mut list: String = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut list).unwrap();
+        let input = input_parse(list.trim_end_matches('\n'));
+        if let Err(e) = input {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        let (mut stacks, lines, first_line_number) = input.unwrap();
+        if mode.as_deref() == Some("plan") {
+            let target: PlanTarget = match std::env::args().nth(3) {
+                Some(top) => PlanTarget::Top(top),
+                None => match stacks_build(&mut lines.clone()) {
+                    Ok(target) => PlanTarget::Stacks(target),
+                    Err(e) => {
+                        eprintln!("target {}", e);
+                        std::process::exit(1);
+                    }
+                },
+            };
+            match plan_find(&stacks, &target, crane.as_ref(), PLAN_STATES_MAX) {
+                Ok(plan) => println!("{}", plan.join("\n")),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        if mode.as_deref() == Some("state") || mode.as_deref() == Some("diff") {
+            let mut simulation: Simulation = Simulation::new(stacks);
+            if let Err(failure) = simulation.run(&lines, first_line_number, crane.as_ref()) {
+                println!("{}", failure.report());
+                std::process::exit(1);
+            }
+            let positions: Vec<usize> = std::env::args()
+                .skip(3)
+                .map(|p| p.parse::<usize>().unwrap_or(usize::MAX))
+                .collect();
+            if mode.as_deref() == Some("state") && positions.len() == 1 {
+                if let Some(stacks) = simulation.state_at(positions[0]) {
+                    println!("{}", stacks_render(&stacks));
+                    return;
+                }
+            } else if mode.as_deref() == Some("diff") && positions.len() == 2 {
+                if let Some(moved) = simulation.diff(positions[0], positions[1]) {
+                    for m in moved {
+                        println!(
+                            "[{}] stack {} level {} -> stack {} level {}",
+                            m.label, m.from.0, m.from.1, m.to.0, m.to.1
+                        );
+                    }
+                    return;
+                }
+            }
+            eprintln!(
+                "expected move numbers from 0 to {}",
+                simulation.history.len()
+            );
+            std::process::exit(1);
+        }
+        if mode.as_deref() == Some("validate") {
+            let failures: Vec<MoveFailure> =
+                procedure_validate(&stacks, &lines, first_line_number, crane.as_ref());
+            for failure in &failures {
+                println!("{}\n", failure.report());
+            }
+            println!("{} invalid moves", failures.len());
+            std::process::exit(if failures.is_empty() { 0 } else { 1 });
+        }
+        let trace: bool = mode.as_deref() == Some("trace");
+        let result: Result<(), MoveFailure> = crates_move_observed(
+            &mut stacks,
+            &lines,
+            first_line_number,
+            crane.as_ref(),
+            &mut |line, stacks| {
+                if trace {
+                    println!("{}\n{}\n", line, stacks_render(stacks));
+                }
+            },
+        );
+        if let Err(failure) = result {
+            println!("{}", failure.report());
+            std::process::exit(1);
+        }
+        println!(
+            "{}\n\n{}: {}",
+            stacks_render(&stacks),
+            crane.name(),
+            stacks_top(&stacks)
+        );
+        return;
+    }
+
+    let crate_mover_9000: Box<dyn Crane> = crane_get("9000").unwrap();
+    let crate_mover_9001: Box<dyn Crane> = crane_get("9001").unwrap();
     // example
-    assert_eq!("CMZ", crates_rearrange("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2"));
+    let example: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+    assert_eq!(
+        Ok(String::from("CMZ")),
+        crates_rearrange(example, crate_mover_9000.as_ref())
+    );
+    assert_eq!(
+        Ok(String::from("MCD")),
+        crates_rearrange(example, crate_mover_9001.as_ref())
+    );
+    assert_eq!(
+        Ok(String::from("MCD")),
+        crates_rearrange(example, crane_get("capacity:3").unwrap().as_ref())
+    );
+    assert_eq!(
+        Ok(String::from("MCZ")),
+        crates_rearrange(example, crane_get("capacity:2").unwrap().as_ref())
+    );
+    assert_eq!(
+        Ok((
+            String::from("CMZ"),
+            String::from("        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 ")
+        )),
+        crates_rearrange_drawing(example, crate_mover_9000.as_ref())
+    );
+    // drawing round trip
+    let mut example_lines: Vec<&str> = example.split('\n').collect();
+    let example_stacks: Vec<Vec<Crate>> = stacks_build(&mut example_lines).unwrap();
+    let example_drawing: String = stacks_render(&example_stacks);
+    assert!(example.starts_with(&(example_drawing.clone() + "\n\n")));
+    let mut drawing_lines: Vec<&str> = example_drawing.split('\n').collect();
+    drawing_lines.push("");
+    assert_eq!(Ok(example_stacks.clone()), stacks_build(&mut drawing_lines));
+    let mut trace: Vec<String> = Vec::new();
+    assert_eq!(
+        Ok(()),
+        crates_move_observed(
+            &mut example_stacks.clone(),
+            &example_lines,
+            6,
+            crate_mover_9001.as_ref(),
+            &mut |line, stacks| trace.push(format!("{}\n{}", line, stacks_render(stacks))),
+        )
+    );
+    assert_eq!(
+        "move 3 from 1 to 3\n        [D]\n        [N]\n    [C] [Z]\n    [M] [P]\n 1   2   3 ",
+        trace[1]
+    );
+    assert_eq!(" 1   2 ", stacks_render(&vec![Vec::new(), Vec::new()]));
+    // wide, trimmed and malformed drawings
+    let mut lines: Vec<&str> = vec!["    [D]", "[N] [C]", "[Z] [M] [P]", " 1   2   3", ""];
+    assert_eq!(Ok(example_stacks.clone()), stacks_build(&mut lines));
+    let wide_stacks: Vec<Vec<Crate>> = vec![
+        vec![String::from("AB")],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        vec![String::from("C"), String::from("DEF")],
+    ];
+    let wide_drawing: String = stacks_render(&wide_stacks);
+    assert_eq!(
+        vec![
+            format!("{}[DEF]", " ".repeat(54)),
+            format!("[AB] {}[C] ", " ".repeat(50)),
+            String::from("  1     2     3     4     5     6     7     8     9    10  "),
+        ],
+        wide_drawing.split('\n').collect::<Vec<&str>>()
+    );
+    let mut lines: Vec<&str> = wide_drawing.split('\n').map(|l| l.trim_end()).collect();
+    assert_eq!(Ok(wide_stacks.clone()), stacks_build(&mut lines));
+    assert_eq!("AB        DEF", stacks_top(&wide_stacks));
+    assert_eq!(
+        Err(String::from(
+            "line 3, column 10: expected stack index 3, found '4'"
+        )),
+        stacks_build(&mut vec!["[A]", "[B] [C]", " 1   2   4", ""])
+    );
+    assert_eq!(
+        Err(String::from("line 1, column 5: expected '[', found 'C'")),
+        stacks_build(&mut vec!["[A] C", " 1   2", ""])
+    );
+    assert_eq!(
+        Err(String::from("line 1, column 1: crate is not closed")),
+        stacks_build(&mut vec!["[A", " 1", ""])
+    );
+    assert_eq!(
+        Err(String::from(
+            "line 1, column 6: crate [B] is not above a stack index"
+        )),
+        stacks_build(&mut vec!["[A]  [B]", " 1 ", ""])
+    );
+    assert_eq!(
+        Err(String::from(
+            "line 1, column 5: crate [B] of stack 2 is floating"
+        )),
+        stacks_build(&mut vec!["[A] [B]", "[C]", " 1   2", ""])
+    );
+    // invalid procedures
+    assert_eq!(Ok((2, 10, 3)), command_parse("move 2 from 10 to 3"));
+    assert_eq!(
+        Err(String::from("expected 'from' at column 7")),
+        command_parse("move 2 form 1 to 3")
+    );
+    assert_eq!(
+        Err(String::from("expected a number, found 'x to 3'")),
+        command_parse("move 2 from x to 3")
+    );
+    assert_eq!(
+        Err(String::from("unexpected ' now' at column 19")),
+        command_parse("move 2 from 1 to 3 now")
+    );
+    let invalid: &str = "[A]\n[B] [C]\n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 1 to 2\nmove 1 from 3 to 1\nmove 1 from 2 to 1";
+    let (invalid_stacks, invalid_lines, invalid_first_line) = input_parse(invalid).unwrap();
+    assert_eq!(5, invalid_first_line);
+    let mut stacks: Vec<Vec<Crate>> = invalid_stacks.clone();
+    assert_eq!(
+        Err(MoveFailure {
+            line_number: 6,
+            command: String::from("move 2 from 1 to 2"),
+            reason: String::from("stack 1 only has 1 crates"),
+            stacks: vec![
+                vec![String::from("B")],
+                vec![String::from("C"), String::from("A")]
+            ],
+        }),
+        crates_move(
+            &mut stacks,
+            &invalid_lines,
+            invalid_first_line,
+            crate_mover_9000.as_ref()
+        )
+    );
+    assert_eq!(
+        Err(String::from(
+            "line 6: 'move 2 from 1 to 2': stack 1 only has 1 crates\n    [A]\n[B] [C]\n 1   2 "
+        )),
+        crates_rearrange(invalid, crate_mover_9000.as_ref())
+    );
+    let failures: Vec<MoveFailure> = procedure_validate(
+        &invalid_stacks,
+        &invalid_lines,
+        invalid_first_line,
+        crate_mover_9000.as_ref(),
+    );
+    assert_eq!(
+        vec![
+            (6, String::from("stack 1 only has 1 crates")),
+            (7, String::from("stack 3 doesn't exist, stacks are 1 to 2")),
+        ],
+        failures
+            .iter()
+            .map(|f| (f.line_number, f.reason.clone()))
+            .collect::<Vec<(usize, String)>>()
+    );
+    // history
+    let mut simulation: Simulation = Simulation::new(example_stacks.clone());
+    assert_eq!(
+        Ok(()),
+        simulation.run(&example_lines, 6, crate_mover_9000.as_ref())
+    );
+    assert_eq!(4, simulation.position);
+    assert_eq!("CMZ", stacks_top(&simulation.stacks));
+    assert!(simulation.undo());
+    assert!(simulation.undo());
+    assert_eq!(
+        "        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3 ",
+        stacks_render(&simulation.stacks)
+    );
+    assert_eq!(Some(example_stacks.clone()), simulation.state_at(0));
+    assert_eq!(
+        Some(String::from("CMZ")),
+        simulation.state_at(4).map(|s| stacks_top(&s))
+    );
+    assert!(simulation.redo());
+    assert_eq!("M Z", stacks_top(&simulation.stacks));
+    assert!(simulation.goto(0));
+    assert!(!simulation.undo());
+    assert_eq!(example_stacks, simulation.stacks);
+    assert!(simulation.goto(4));
+    assert!(!simulation.redo());
+    assert!(!simulation.goto(5));
+    assert_eq!(None, simulation.state_at(5));
+    assert_eq!(
+        Some(vec![
+            CrateMoved {
+                label: String::from("Z"),
+                from: (1, 0),
+                to: (3, 3),
+            },
+            CrateMoved {
+                label: String::from("N"),
+                from: (1, 1),
+                to: (3, 2),
+            },
+            CrateMoved {
+                label: String::from("D"),
+                from: (1, 2),
+                to: (3, 1),
+            },
+        ]),
+        simulation.diff(1, 2)
+    );
+    let forward: Vec<CrateMoved> = simulation.diff(0, 4).unwrap();
+    let backward: Vec<CrateMoved> = simulation.diff(4, 0).unwrap();
+    assert_eq!(forward.len(), backward.len());
+    for m in &backward {
+        assert!(forward
+            .iter()
+            .any(|f| f.label == m.label && f.from == m.to && f.to == m.from));
+    }
+    // undoing then running another command drops the undone ones
+    assert!(simulation.goto(1));
+    assert_eq!(
+        Ok(()),
+        simulation.apply("move 2 from 1 to 2", 7, crate_mover_9001.as_ref())
+    );
+    assert_eq!(2, simulation.history.len());
+    assert_eq!(
+        "    [D]    \n    [N]    \n    [C]    \n[Z] [M] [P]\n 1   2   3 ",
+        stacks_render(&simulation.stacks)
+    );
+    assert!(simulation.undo());
+    assert_eq!(
+        "[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ",
+        stacks_render(&simulation.stacks)
+    );
+    assert_eq!(
+        Err(String::from("stack 1 only has 3 crates")),
+        simulation
+            .apply("move 4 from 1 to 2", 7, crate_mover_9001.as_ref())
+            .map_err(|f| f.reason)
+    );
+    // planning
+    let plan: Vec<String> = plan_find(
+        &example_stacks,
+        &PlanTarget::Top(String::from("CMZ")),
+        crate_mover_9000.as_ref(),
+        PLAN_STATES_MAX,
+    )
+    .unwrap();
+    assert_eq!(2, plan.len());
+    let mut stacks: Vec<Vec<Crate>> = example_stacks.clone();
+    let plan_lines: Vec<&str> = plan.iter().map(|l| l.as_str()).collect();
+    assert_eq!(
+        Ok(()),
+        crates_move(&mut stacks, &plan_lines, 1, crate_mover_9000.as_ref())
+    );
+    assert_eq!("CMZ", stacks_top(&stacks));
+    let target_stacks: Vec<Vec<Crate>> = stacks_build(&mut vec![
+        "        [Z]",
+        "        [N]",
+        "        [D]",
+        "[C] [M] [P]",
+        " 1   2   3",
+        "",
+    ])
+    .unwrap();
+    for crane in [crate_mover_9000.as_ref(), crate_mover_9001.as_ref()] {
+        let plan: Vec<String> = plan_find(
+            &example_stacks,
+            &PlanTarget::Stacks(target_stacks.clone()),
+            crane,
+            PLAN_STATES_MAX,
+        )
+        .unwrap();
+        assert!(plan.len() <= 4);
+        let mut stacks: Vec<Vec<Crate>> = example_stacks.clone();
+        let plan_lines: Vec<&str> = plan.iter().map(|l| l.as_str()).collect();
+        assert_eq!(Ok(()), crates_move(&mut stacks, &plan_lines, 1, crane));
+        assert_eq!(target_stacks, stacks);
+    }
+    assert_eq!(
+        Ok(Vec::<String>::new()),
+        plan_find(
+            &example_stacks,
+            &PlanTarget::Top(String::from("NDP")),
+            crate_mover_9000.as_ref(),
+            PLAN_STATES_MAX
+        )
+    );
+    assert_eq!(
+        Err(String::from("target can't be reached")),
+        plan_find(
+            &example_stacks,
+            &PlanTarget::Top(String::from("XDP")),
+            crate_mover_9000.as_ref(),
+            PLAN_STATES_MAX
+        )
+    );
+    assert_eq!(
+        Err(String::from("no plan found within 10 states")),
+        plan_find(
+            &example_stacks,
+            &PlanTarget::Top(String::from("MDZ")),
+            crate_mover_9000.as_ref(),
+            10
+        )
+    );
     //// user puzzle input
-    assert_eq!("FRDSQRRCD", crates_rearrange("        [M]     [B]             [N]\n[T]     [H]     [V] [Q]         [H]\n[Q]     [N]     [H] [W] [T]     [Q]\n[V]     [P] [F] [Q] [P] [C]     [R]\n[C]     [D] [T] [N] [N] [L] [S] [J]\n[D] [V] [W] [R] [M] [G] [R] [N] [D]\n[S] [F] [Q] [Q] [F] [F] [F] [Z] [S]\n[N] [M] [F] [D] [R] [C] [W] [T] [M]\n 1   2   3   4   5   6   7   8   9 \n\nmove 1 from 8 to 7\nmove 1 from 2 to 7\nmove 6 from 9 to 8\nmove 1 from 9 to 1\nmove 1 from 9 to 1\nmove 3 from 3 to 6\nmove 3 from 3 to 9\nmove 1 from 9 to 2\nmove 5 from 7 to 9\nmove 9 from 1 to 6\nmove 3 from 4 to 9\nmove 2 from 9 to 2\nmove 1 from 4 to 2\nmove 1 from 3 to 9\nmove 8 from 9 to 4\nmove 14 from 6 to 7\nmove 1 from 3 to 2\nmove 5 from 4 to 2\nmove 5 from 5 to 7\nmove 4 from 2 to 1\nmove 2 from 4 to 9\nmove 1 from 4 to 3\nmove 3 from 5 to 7\nmove 1 from 8 to 6\nmove 2 from 8 to 7\nmove 2 from 1 to 2\nmove 1 from 9 to 7\nmove 2 from 1 to 3\nmove 5 from 6 to 5\nmove 4 from 5 to 7\nmove 3 from 8 to 4\nmove 20 from 7 to 1\nmove 11 from 7 to 5\nmove 1 from 6 to 9\nmove 3 from 9 to 2\nmove 12 from 1 to 9\nmove 2 from 8 to 3\nmove 4 from 2 to 8\nmove 8 from 2 to 1\nmove 4 from 8 to 9\nmove 1 from 2 to 5\nmove 12 from 9 to 7\nmove 4 from 4 to 9\nmove 4 from 9 to 5\nmove 13 from 5 to 4\nmove 4 from 4 to 7\nmove 1 from 7 to 9\nmove 2 from 9 to 5\nmove 9 from 1 to 2\nmove 1 from 8 to 3\nmove 5 from 4 to 2\nmove 1 from 3 to 6\nmove 7 from 2 to 8\nmove 6 from 1 to 6\nmove 6 from 8 to 7\nmove 6 from 2 to 1\nmove 3 from 9 to 3\nmove 7 from 3 to 7\nmove 4 from 4 to 9\nmove 1 from 8 to 9\nmove 1 from 3 to 9\nmove 1 from 2 to 4\nmove 1 from 9 to 6\nmove 5 from 1 to 9\nmove 1 from 4 to 9\nmove 2 from 9 to 1\nmove 8 from 6 to 7\nmove 4 from 9 to 7\nmove 2 from 5 to 2\nmove 2 from 1 to 9\nmove 14 from 7 to 4\nmove 22 from 7 to 2\nmove 2 from 7 to 4\nmove 3 from 7 to 5\nmove 9 from 4 to 7\nmove 6 from 2 to 4\nmove 8 from 4 to 3\nmove 14 from 2 to 9\nmove 2 from 3 to 9\nmove 3 from 2 to 9\nmove 4 from 4 to 2\nmove 1 from 4 to 5\nmove 1 from 1 to 4\nmove 5 from 7 to 8\nmove 1 from 1 to 3\nmove 4 from 5 to 2\nmove 6 from 3 to 9\nmove 1 from 3 to 4\nmove 4 from 8 to 9\nmove 2 from 4 to 6\nmove 4 from 5 to 3\nmove 1 from 7 to 6\nmove 1 from 8 to 5\nmove 3 from 3 to 1\nmove 33 from 9 to 5\nmove 5 from 2 to 1\nmove 1 from 3 to 5\nmove 1 from 7 to 6\nmove 18 from 5 to 1\nmove 1 from 2 to 8\nmove 6 from 5 to 4\nmove 1 from 8 to 7\nmove 2 from 4 to 1\nmove 4 from 1 to 2\nmove 19 from 1 to 2\nmove 4 from 6 to 8\nmove 4 from 1 to 8\nmove 14 from 2 to 9\nmove 5 from 2 to 4\nmove 1 from 8 to 2\nmove 8 from 2 to 5\nmove 5 from 8 to 4\nmove 4 from 9 to 7\nmove 1 from 8 to 1\nmove 16 from 5 to 4\nmove 15 from 4 to 5\nmove 1 from 9 to 5\nmove 5 from 7 to 6\nmove 2 from 7 to 6\nmove 1 from 1 to 9\nmove 7 from 6 to 7\nmove 1 from 8 to 5\nmove 1 from 1 to 9\nmove 12 from 5 to 7\nmove 7 from 5 to 9\nmove 12 from 7 to 2\nmove 1 from 7 to 4\nmove 7 from 4 to 7\nmove 2 from 9 to 4\nmove 5 from 4 to 9\nmove 8 from 2 to 3\nmove 4 from 2 to 4\nmove 9 from 4 to 8\nmove 6 from 3 to 5\nmove 8 from 7 to 3\nmove 1 from 4 to 3\nmove 7 from 8 to 9\nmove 4 from 5 to 4\nmove 6 from 3 to 1\nmove 4 from 3 to 4\nmove 1 from 3 to 6\nmove 6 from 4 to 9\nmove 1 from 6 to 5\nmove 17 from 9 to 4\nmove 3 from 7 to 3\nmove 1 from 7 to 9\nmove 2 from 5 to 3\nmove 2 from 1 to 3\nmove 2 from 8 to 9\nmove 1 from 5 to 1\nmove 14 from 4 to 5\nmove 2 from 3 to 2\nmove 1 from 7 to 6\nmove 10 from 9 to 4\nmove 12 from 9 to 4\nmove 9 from 4 to 5\nmove 1 from 2 to 9\nmove 13 from 5 to 9\nmove 2 from 5 to 1\nmove 1 from 2 to 9\nmove 3 from 4 to 2\nmove 12 from 4 to 7\nmove 8 from 5 to 7\nmove 1 from 1 to 9\nmove 1 from 6 to 4\nmove 1 from 5 to 4\nmove 1 from 4 to 8\nmove 5 from 3 to 4\nmove 10 from 9 to 6\nmove 3 from 6 to 2\nmove 7 from 6 to 5\nmove 6 from 5 to 4\nmove 1 from 8 to 5\nmove 1 from 1 to 4\nmove 2 from 7 to 2\nmove 5 from 4 to 9\nmove 2 from 5 to 8\nmove 1 from 1 to 3\nmove 2 from 1 to 7\nmove 6 from 7 to 9\nmove 9 from 9 to 8\nmove 1 from 1 to 3\nmove 4 from 2 to 7\nmove 11 from 7 to 3\nmove 11 from 8 to 6\nmove 7 from 3 to 1\nmove 4 from 7 to 2\nmove 3 from 2 to 9\nmove 8 from 1 to 5\nmove 2 from 7 to 5\nmove 2 from 2 to 9\nmove 2 from 3 to 9\nmove 11 from 4 to 7\nmove 7 from 9 to 5\nmove 6 from 6 to 5\nmove 2 from 2 to 9\nmove 1 from 2 to 3\nmove 6 from 9 to 4\nmove 3 from 9 to 1\nmove 4 from 3 to 5\nmove 6 from 7 to 1\nmove 2 from 6 to 3\nmove 2 from 9 to 2\nmove 3 from 3 to 2\nmove 3 from 6 to 8\nmove 2 from 7 to 5\nmove 20 from 5 to 6\nmove 8 from 5 to 1\nmove 1 from 5 to 9\nmove 2 from 8 to 4\nmove 1 from 8 to 7\nmove 16 from 1 to 8\nmove 8 from 8 to 9\nmove 4 from 2 to 4\nmove 1 from 1 to 5\nmove 1 from 5 to 4\nmove 3 from 8 to 4\nmove 14 from 4 to 6\nmove 5 from 8 to 7\nmove 6 from 7 to 8\nmove 29 from 6 to 2\nmove 3 from 9 to 8\nmove 21 from 2 to 3\nmove 1 from 8 to 3\nmove 6 from 9 to 4\nmove 8 from 3 to 5\nmove 7 from 8 to 4\nmove 7 from 3 to 9\nmove 3 from 7 to 2\nmove 12 from 4 to 8\nmove 2 from 3 to 1\nmove 2 from 9 to 1\nmove 1 from 6 to 7\nmove 1 from 7 to 6\nmove 1 from 6 to 3\nmove 3 from 1 to 8\nmove 2 from 4 to 1\nmove 4 from 6 to 1\nmove 5 from 2 to 7\nmove 1 from 1 to 2\nmove 5 from 1 to 2\nmove 2 from 8 to 1\nmove 1 from 4 to 5\nmove 9 from 8 to 4\nmove 3 from 7 to 9\nmove 7 from 5 to 7\nmove 2 from 5 to 9\nmove 4 from 9 to 2\nmove 3 from 3 to 2\nmove 5 from 2 to 7\nmove 2 from 8 to 2\nmove 2 from 7 to 3\nmove 1 from 8 to 6\nmove 2 from 1 to 2\nmove 1 from 6 to 7\nmove 1 from 8 to 1\nmove 12 from 7 to 1\nmove 5 from 2 to 7\nmove 7 from 4 to 2\nmove 2 from 4 to 1\nmove 5 from 3 to 8\nmove 7 from 1 to 9\nmove 4 from 7 to 1\nmove 7 from 1 to 5\nmove 12 from 9 to 2\nmove 27 from 2 to 4\nmove 3 from 8 to 9\nmove 6 from 2 to 5\nmove 6 from 1 to 8\nmove 1 from 7 to 6\nmove 9 from 5 to 2\nmove 3 from 9 to 2\nmove 13 from 4 to 5\nmove 10 from 2 to 7\nmove 1 from 9 to 8\nmove 11 from 5 to 7\nmove 1 from 8 to 7\nmove 1 from 2 to 6\nmove 13 from 4 to 3\nmove 23 from 7 to 4\nmove 1 from 6 to 9\nmove 1 from 2 to 4\nmove 7 from 3 to 5\nmove 1 from 9 to 8\nmove 19 from 4 to 1\nmove 2 from 4 to 1\nmove 1 from 7 to 6\nmove 1 from 4 to 5\nmove 1 from 5 to 7\nmove 11 from 5 to 1\nmove 2 from 5 to 4\nmove 2 from 6 to 9\nmove 3 from 8 to 2\nmove 2 from 8 to 1\nmove 3 from 2 to 1\nmove 1 from 9 to 5\nmove 6 from 1 to 3\nmove 1 from 9 to 7\nmove 2 from 7 to 5\nmove 2 from 8 to 6\nmove 1 from 3 to 2\nmove 2 from 8 to 5\nmove 1 from 2 to 1\nmove 3 from 4 to 1\nmove 3 from 5 to 1\nmove 2 from 5 to 1\nmove 2 from 6 to 9\nmove 1 from 9 to 6\nmove 1 from 4 to 5\nmove 1 from 9 to 8\nmove 1 from 8 to 6\nmove 8 from 1 to 6\nmove 7 from 1 to 8\nmove 9 from 1 to 6\nmove 1 from 5 to 3\nmove 3 from 8 to 4\nmove 11 from 3 to 4\nmove 1 from 3 to 6\nmove 10 from 6 to 8\nmove 13 from 1 to 6\nmove 3 from 4 to 5\nmove 7 from 8 to 6\nmove 3 from 8 to 5\nmove 6 from 5 to 3\nmove 22 from 6 to 9\nmove 4 from 3 to 6\nmove 4 from 9 to 5\nmove 1 from 1 to 5\nmove 2 from 3 to 4\nmove 2 from 1 to 5\nmove 1 from 9 to 2\nmove 5 from 8 to 3\nmove 2 from 9 to 2\nmove 11 from 6 to 9\nmove 3 from 2 to 7\nmove 1 from 6 to 7\nmove 12 from 9 to 8\nmove 4 from 7 to 1\nmove 12 from 4 to 8\nmove 2 from 4 to 7\nmove 1 from 1 to 8\nmove 1 from 5 to 1\nmove 19 from 8 to 4\nmove 4 from 5 to 1\nmove 1 from 7 to 4\nmove 1 from 7 to 1\nmove 3 from 3 to 4\nmove 2 from 8 to 4\nmove 1 from 5 to 7\nmove 1 from 7 to 9\nmove 8 from 1 to 8\nmove 1 from 1 to 4\nmove 1 from 3 to 9\nmove 1 from 3 to 5\nmove 1 from 5 to 2\nmove 7 from 8 to 7\nmove 16 from 4 to 7\nmove 1 from 7 to 4\nmove 3 from 8 to 2\nmove 14 from 7 to 4\nmove 1 from 5 to 8\nmove 5 from 7 to 5\nmove 16 from 4 to 5\nmove 3 from 5 to 4\nmove 3 from 2 to 1\nmove 1 from 7 to 9\nmove 11 from 4 to 2\nmove 3 from 8 to 6\nmove 2 from 1 to 8\nmove 1 from 4 to 9\nmove 18 from 5 to 1\nmove 1 from 8 to 7\nmove 3 from 7 to 9\nmove 18 from 9 to 3\nmove 3 from 6 to 9\nmove 7 from 1 to 6\nmove 1 from 8 to 4\nmove 1 from 4 to 9\nmove 3 from 6 to 4\nmove 5 from 9 to 2\nmove 2 from 4 to 7\nmove 7 from 2 to 8\nmove 1 from 7 to 3\nmove 2 from 6 to 8\nmove 1 from 9 to 5\nmove 1 from 6 to 8\nmove 1 from 4 to 8\nmove 1 from 5 to 3\nmove 1 from 7 to 5\nmove 8 from 8 to 7\nmove 10 from 2 to 6\nmove 1 from 9 to 3\nmove 6 from 6 to 2\nmove 5 from 6 to 2\nmove 7 from 2 to 7\nmove 12 from 1 to 6\nmove 2 from 2 to 1\nmove 1 from 2 to 5\nmove 4 from 7 to 6\nmove 12 from 3 to 1\nmove 2 from 7 to 2\nmove 9 from 3 to 8\nmove 1 from 2 to 6\nmove 1 from 5 to 4\nmove 9 from 6 to 5\nmove 1 from 7 to 6\nmove 1 from 4 to 9\nmove 9 from 6 to 7\nmove 7 from 8 to 3\nmove 6 from 3 to 1\nmove 4 from 8 to 3\nmove 5 from 3 to 1\nmove 1 from 9 to 8\nmove 2 from 8 to 9\nmove 5 from 5 to 7\nmove 14 from 7 to 8\nmove 1 from 9 to 4\nmove 2 from 2 to 1\nmove 3 from 5 to 3\nmove 2 from 3 to 1\nmove 1 from 4 to 6\nmove 6 from 8 to 6\nmove 6 from 8 to 3\nmove 3 from 6 to 1\nmove 2 from 8 to 9\nmove 19 from 1 to 6\nmove 3 from 9 to 3\nmove 6 from 3 to 4\nmove 6 from 6 to 2\nmove 4 from 3 to 9\nmove 1 from 7 to 9\nmove 2 from 5 to 7\nmove 5 from 9 to 6\nmove 6 from 7 to 2\nmove 11 from 2 to 5\nmove 2 from 7 to 4\nmove 4 from 4 to 3\nmove 2 from 4 to 8\nmove 12 from 1 to 2\nmove 1 from 8 to 2\nmove 8 from 5 to 7\nmove 2 from 4 to 9\nmove 2 from 7 to 1\nmove 4 from 2 to 3\nmove 1 from 8 to 6\nmove 1 from 1 to 5\nmove 2 from 9 to 1\nmove 2 from 7 to 3\nmove 2 from 5 to 2\nmove 1 from 5 to 7\nmove 2 from 7 to 8\nmove 1 from 5 to 7\nmove 5 from 3 to 4\nmove 3 from 1 to 7\nmove 1 from 2 to 4\nmove 15 from 6 to 1\nmove 4 from 4 to 1\nmove 4 from 2 to 3\nmove 8 from 3 to 2\nmove 5 from 2 to 4\nmove 1 from 8 to 6\nmove 1 from 8 to 9\nmove 1 from 3 to 1\nmove 3 from 7 to 3\nmove 5 from 7 to 6\nmove 4 from 2 to 9\nmove 6 from 2 to 6\nmove 4 from 9 to 6\nmove 12 from 1 to 5\nmove 6 from 4 to 1\nmove 1 from 3 to 6\nmove 4 from 5 to 8\nmove 7 from 5 to 3\nmove 3 from 8 to 2\nmove 1 from 2 to 3\nmove 1 from 9 to 5\nmove 1 from 4 to 5\nmove 1 from 8 to 5\nmove 8 from 6 to 9\nmove 10 from 1 to 4\nmove 3 from 6 to 1\nmove 9 from 3 to 6\nmove 1 from 3 to 8\nmove 1 from 2 to 4\nmove 6 from 9 to 1\nmove 1 from 1 to 4\nmove 10 from 1 to 6\nmove 1 from 8 to 6\nmove 13 from 6 to 7\nmove 1 from 2 to 1\nmove 1 from 9 to 6\nmove 9 from 7 to 5\nmove 1 from 9 to 4\nmove 3 from 7 to 1\nmove 3 from 5 to 6\nmove 10 from 4 to 7\nmove 5 from 6 to 5\nmove 3 from 4 to 5\nmove 13 from 6 to 9\nmove 7 from 5 to 3\nmove 6 from 3 to 2\nmove 5 from 6 to 4\nmove 4 from 2 to 8"));
+    let puzzle_input: &str = "        [M]     [B]             [N]\n[T]     [H]     [V] [Q]         [H]\n[Q]     [N]     [H] [W] [T]     [Q]\n[V]     [P] [F] [Q] [P] [C]     [R]\n[C]     [D] [T] [N] [N] [L] [S] [J]\n[D] [V] [W] [R] [M] [G] [R] [N] [D]\n[S] [F] [Q] [Q] [F] [F] [F] [Z] [S]\n[N] [M] [F] [D] [R] [C] [W] [T] [M]\n 1   2   3   4   5   6   7   8   9 \n\nmove 1 from 8 to 7\nmove 1 from 2 to 7\nmove 6 from 9 to 8\nmove 1 from 9 to 1\nmove 1 from 9 to 1\nmove 3 from 3 to 6\nmove 3 from 3 to 9\nmove 1 from 9 to 2\nmove 5 from 7 to 9\nmove 9 from 1 to 6\nmove 3 from 4 to 9\nmove 2 from 9 to 2\nmove 1 from 4 to 2\nmove 1 from 3 to 9\nmove 8 from 9 to 4\nmove 14 from 6 to 7\nmove 1 from 3 to 2\nmove 5 from 4 to 2\nmove 5 from 5 to 7\nmove 4 from 2 to 1\nmove 2 from 4 to 9\nmove 1 from 4 to 3\nmove 3 from 5 to 7\nmove 1 from 8 to 6\nmove 2 from 8 to 7\nmove 2 from 1 to 2\nmove 1 from 9 to 7\nmove 2 from 1 to 3\nmove 5 from 6 to 5\nmove 4 from 5 to 7\nmove 3 from 8 to 4\nmove 20 from 7 to 1\nmove 11 from 7 to 5\nmove 1 from 6 to 9\nmove 3 from 9 to 2\nmove 12 from 1 to 9\nmove 2 from 8 to 3\nmove 4 from 2 to 8\nmove 8 from 2 to 1\nmove 4 from 8 to 9\nmove 1 from 2 to 5\nmove 12 from 9 to 7\nmove 4 from 4 to 9\nmove 4 from 9 to 5\nmove 13 from 5 to 4\nmove 4 from 4 to 7\nmove 1 from 7 to 9\nmove 2 from 9 to 5\nmove 9 from 1 to 2\nmove 1 from 8 to 3\nmove 5 from 4 to 2\nmove 1 from 3 to 6\nmove 7 from 2 to 8\nmove 6 from 1 to 6\nmove 6 from 8 to 7\nmove 6 from 2 to 1\nmove 3 from 9 to 3\nmove 7 from 3 to 7\nmove 4 from 4 to 9\nmove 1 from 8 to 9\nmove 1 from 3 to 9\nmove 1 from 2 to 4\nmove 1 from 9 to 6\nmove 5 from 1 to 9\nmove 1 from 4 to 9\nmove 2 from 9 to 1\nmove 8 from 6 to 7\nmove 4 from 9 to 7\nmove 2 from 5 to 2\nmove 2 from 1 to 9\nmove 14 from 7 to 4\nmove 22 from 7 to 2\nmove 2 from 7 to 4\nmove 3 from 7 to 5\nmove 9 from 4 to 7\nmove 6 from 2 to 4\nmove 8 from 4 to 3\nmove 14 from 2 to 9\nmove 2 from 3 to 9\nmove 3 from 2 to 9\nmove 4 from 4 to 2\nmove 1 from 4 to 5\nmove 1 from 1 to 4\nmove 5 from 7 to 8\nmove 1 from 1 to 3\nmove 4 from 5 to 2\nmove 6 from 3 to 9\nmove 1 from 3 to 4\nmove 4 from 8 to 9\nmove 2 from 4 to 6\nmove 4 from 5 to 3\nmove 1 from 7 to 6\nmove 1 from 8 to 5\nmove 3 from 3 to 1\nmove 33 from 9 to 5\nmove 5 from 2 to 1\nmove 1 from 3 to 5\nmove 1 from 7 to 6\nmove 18 from 5 to 1\nmove 1 from 2 to 8\nmove 6 from 5 to 4\nmove 1 from 8 to 7\nmove 2 from 4 to 1\nmove 4 from 1 to 2\nmove 19 from 1 to 2\nmove 4 from 6 to 8\nmove 4 from 1 to 8\nmove 14 from 2 to 9\nmove 5 from 2 to 4\nmove 1 from 8 to 2\nmove 8 from 2 to 5\nmove 5 from 8 to 4\nmove 4 from 9 to 7\nmove 1 from 8 to 1\nmove 16 from 5 to 4\nmove 15 from 4 to 5\nmove 1 from 9 to 5\nmove 5 from 7 to 6\nmove 2 from 7 to 6\nmove 1 from 1 to 9\nmove 7 from 6 to 7\nmove 1 from 8 to 5\nmove 1 from 1 to 9\nmove 12 from 5 to 7\nmove 7 from 5 to 9\nmove 12 from 7 to 2\nmove 1 from 7 to 4\nmove 7 from 4 to 7\nmove 2 from 9 to 4\nmove 5 from 4 to 9\nmove 8 from 2 to 3\nmove 4 from 2 to 4\nmove 9 from 4 to 8\nmove 6 from 3 to 5\nmove 8 from 7 to 3\nmove 1 from 4 to 3\nmove 7 from 8 to 9\nmove 4 from 5 to 4\nmove 6 from 3 to 1\nmove 4 from 3 to 4\nmove 1 from 3 to 6\nmove 6 from 4 to 9\nmove 1 from 6 to 5\nmove 17 from 9 to 4\nmove 3 from 7 to 3\nmove 1 from 7 to 9\nmove 2 from 5 to 3\nmove 2 from 1 to 3\nmove 2 from 8 to 9\nmove 1 from 5 to 1\nmove 14 from 4 to 5\nmove 2 from 3 to 2\nmove 1 from 7 to 6\nmove 10 from 9 to 4\nmove 12 from 9 to 4\nmove 9 from 4 to 5\nmove 1 from 2 to 9\nmove 13 from 5 to 9\nmove 2 from 5 to 1\nmove 1 from 2 to 9\nmove 3 from 4 to 2\nmove 12 from 4 to 7\nmove 8 from 5 to 7\nmove 1 from 1 to 9\nmove 1 from 6 to 4\nmove 1 from 5 to 4\nmove 1 from 4 to 8\nmove 5 from 3 to 4\nmove 10 from 9 to 6\nmove 3 from 6 to 2\nmove 7 from 6 to 5\nmove 6 from 5 to 4\nmove 1 from 8 to 5\nmove 1 from 1 to 4\nmove 2 from 7 to 2\nmove 5 from 4 to 9\nmove 2 from 5 to 8\nmove 1 from 1 to 3\nmove 2 from 1 to 7\nmove 6 from 7 to 9\nmove 9 from 9 to 8\nmove 1 from 1 to 3\nmove 4 from 2 to 7\nmove 11 from 7 to 3\nmove 11 from 8 to 6\nmove 7 from 3 to 1\nmove 4 from 7 to 2\nmove 3 from 2 to 9\nmove 8 from 1 to 5\nmove 2 from 7 to 5\nmove 2 from 2 to 9\nmove 2 from 3 to 9\nmove 11 from 4 to 7\nmove 7 from 9 to 5\nmove 6 from 6 to 5\nmove 2 from 2 to 9\nmove 1 from 2 to 3\nmove 6 from 9 to 4\nmove 3 from 9 to 1\nmove 4 from 3 to 5\nmove 6 from 7 to 1\nmove 2 from 6 to 3\nmove 2 from 9 to 2\nmove 3 from 3 to 2\nmove 3 from 6 to 8\nmove 2 from 7 to 5\nmove 20 from 5 to 6\nmove 8 from 5 to 1\nmove 1 from 5 to 9\nmove 2 from 8 to 4\nmove 1 from 8 to 7\nmove 16 from 1 to 8\nmove 8 from 8 to 9\nmove 4 from 2 to 4\nmove 1 from 1 to 5\nmove 1 from 5 to 4\nmove 3 from 8 to 4\nmove 14 from 4 to 6\nmove 5 from 8 to 7\nmove 6 from 7 to 8\nmove 29 from 6 to 2\nmove 3 from 9 to 8\nmove 21 from 2 to 3\nmove 1 from 8 to 3\nmove 6 from 9 to 4\nmove 8 from 3 to 5\nmove 7 from 8 to 4\nmove 7 from 3 to 9\nmove 3 from 7 to 2\nmove 12 from 4 to 8\nmove 2 from 3 to 1\nmove 2 from 9 to 1\nmove 1 from 6 to 7\nmove 1 from 7 to 6\nmove 1 from 6 to 3\nmove 3 from 1 to 8\nmove 2 from 4 to 1\nmove 4 from 6 to 1\nmove 5 from 2 to 7\nmove 1 from 1 to 2\nmove 5 from 1 to 2\nmove 2 from 8 to 1\nmove 1 from 4 to 5\nmove 9 from 8 to 4\nmove 3 from 7 to 9\nmove 7 from 5 to 7\nmove 2 from 5 to 9\nmove 4 from 9 to 2\nmove 3 from 3 to 2\nmove 5 from 2 to 7\nmove 2 from 8 to 2\nmove 2 from 7 to 3\nmove 1 from 8 to 6\nmove 2 from 1 to 2\nmove 1 from 6 to 7\nmove 1 from 8 to 1\nmove 12 from 7 to 1\nmove 5 from 2 to 7\nmove 7 from 4 to 2\nmove 2 from 4 to 1\nmove 5 from 3 to 8\nmove 7 from 1 to 9\nmove 4 from 7 to 1\nmove 7 from 1 to 5\nmove 12 from 9 to 2\nmove 27 from 2 to 4\nmove 3 from 8 to 9\nmove 6 from 2 to 5\nmove 6 from 1 to 8\nmove 1 from 7 to 6\nmove 9 from 5 to 2\nmove 3 from 9 to 2\nmove 13 from 4 to 5\nmove 10 from 2 to 7\nmove 1 from 9 to 8\nmove 11 from 5 to 7\nmove 1 from 8 to 7\nmove 1 from 2 to 6\nmove 13 from 4 to 3\nmove 23 from 7 to 4\nmove 1 from 6 to 9\nmove 1 from 2 to 4\nmove 7 from 3 to 5\nmove 1 from 9 to 8\nmove 19 from 4 to 1\nmove 2 from 4 to 1\nmove 1 from 7 to 6\nmove 1 from 4 to 5\nmove 1 from 5 to 7\nmove 11 from 5 to 1\nmove 2 from 5 to 4\nmove 2 from 6 to 9\nmove 3 from 8 to 2\nmove 2 from 8 to 1\nmove 3 from 2 to 1\nmove 1 from 9 to 5\nmove 6 from 1 to 3\nmove 1 from 9 to 7\nmove 2 from 7 to 5\nmove 2 from 8 to 6\nmove 1 from 3 to 2\nmove 2 from 8 to 5\nmove 1 from 2 to 1\nmove 3 from 4 to 1\nmove 3 from 5 to 1\nmove 2 from 5 to 1\nmove 2 from 6 to 9\nmove 1 from 9 to 6\nmove 1 from 4 to 5\nmove 1 from 9 to 8\nmove 1 from 8 to 6\nmove 8 from 1 to 6\nmove 7 from 1 to 8\nmove 9 from 1 to 6\nmove 1 from 5 to 3\nmove 3 from 8 to 4\nmove 11 from 3 to 4\nmove 1 from 3 to 6\nmove 10 from 6 to 8\nmove 13 from 1 to 6\nmove 3 from 4 to 5\nmove 7 from 8 to 6\nmove 3 from 8 to 5\nmove 6 from 5 to 3\nmove 22 from 6 to 9\nmove 4 from 3 to 6\nmove 4 from 9 to 5\nmove 1 from 1 to 5\nmove 2 from 3 to 4\nmove 2 from 1 to 5\nmove 1 from 9 to 2\nmove 5 from 8 to 3\nmove 2 from 9 to 2\nmove 11 from 6 to 9\nmove 3 from 2 to 7\nmove 1 from 6 to 7\nmove 12 from 9 to 8\nmove 4 from 7 to 1\nmove 12 from 4 to 8\nmove 2 from 4 to 7\nmove 1 from 1 to 8\nmove 1 from 5 to 1\nmove 19 from 8 to 4\nmove 4 from 5 to 1\nmove 1 from 7 to 4\nmove 1 from 7 to 1\nmove 3 from 3 to 4\nmove 2 from 8 to 4\nmove 1 from 5 to 7\nmove 1 from 7 to 9\nmove 8 from 1 to 8\nmove 1 from 1 to 4\nmove 1 from 3 to 9\nmove 1 from 3 to 5\nmove 1 from 5 to 2\nmove 7 from 8 to 7\nmove 16 from 4 to 7\nmove 1 from 7 to 4\nmove 3 from 8 to 2\nmove 14 from 7 to 4\nmove 1 from 5 to 8\nmove 5 from 7 to 5\nmove 16 from 4 to 5\nmove 3 from 5 to 4\nmove 3 from 2 to 1\nmove 1 from 7 to 9\nmove 11 from 4 to 2\nmove 3 from 8 to 6\nmove 2 from 1 to 8\nmove 1 from 4 to 9\nmove 18 from 5 to 1\nmove 1 from 8 to 7\nmove 3 from 7 to 9\nmove 18 from 9 to 3\nmove 3 from 6 to 9\nmove 7 from 1 to 6\nmove 1 from 8 to 4\nmove 1 from 4 to 9\nmove 3 from 6 to 4\nmove 5 from 9 to 2\nmove 2 from 4 to 7\nmove 7 from 2 to 8\nmove 1 from 7 to 3\nmove 2 from 6 to 8\nmove 1 from 9 to 5\nmove 1 from 6 to 8\nmove 1 from 4 to 8\nmove 1 from 5 to 3\nmove 1 from 7 to 5\nmove 8 from 8 to 7\nmove 10 from 2 to 6\nmove 1 from 9 to 3\nmove 6 from 6 to 2\nmove 5 from 6 to 2\nmove 7 from 2 to 7\nmove 12 from 1 to 6\nmove 2 from 2 to 1\nmove 1 from 2 to 5\nmove 4 from 7 to 6\nmove 12 from 3 to 1\nmove 2 from 7 to 2\nmove 9 from 3 to 8\nmove 1 from 2 to 6\nmove 1 from 5 to 4\nmove 9 from 6 to 5\nmove 1 from 7 to 6\nmove 1 from 4 to 9\nmove 9 from 6 to 7\nmove 7 from 8 to 3\nmove 6 from 3 to 1\nmove 4 from 8 to 3\nmove 5 from 3 to 1\nmove 1 from 9 to 8\nmove 2 from 8 to 9\nmove 5 from 5 to 7\nmove 14 from 7 to 8\nmove 1 from 9 to 4\nmove 2 from 2 to 1\nmove 3 from 5 to 3\nmove 2 from 3 to 1\nmove 1 from 4 to 6\nmove 6 from 8 to 6\nmove 6 from 8 to 3\nmove 3 from 6 to 1\nmove 2 from 8 to 9\nmove 19 from 1 to 6\nmove 3 from 9 to 3\nmove 6 from 3 to 4\nmove 6 from 6 to 2\nmove 4 from 3 to 9\nmove 1 from 7 to 9\nmove 2 from 5 to 7\nmove 5 from 9 to 6\nmove 6 from 7 to 2\nmove 11 from 2 to 5\nmove 2 from 7 to 4\nmove 4 from 4 to 3\nmove 2 from 4 to 8\nmove 12 from 1 to 2\nmove 1 from 8 to 2\nmove 8 from 5 to 7\nmove 2 from 4 to 9\nmove 2 from 7 to 1\nmove 4 from 2 to 3\nmove 1 from 8 to 6\nmove 1 from 1 to 5\nmove 2 from 9 to 1\nmove 2 from 7 to 3\nmove 2 from 5 to 2\nmove 1 from 5 to 7\nmove 2 from 7 to 8\nmove 1 from 5 to 7\nmove 5 from 3 to 4\nmove 3 from 1 to 7\nmove 1 from 2 to 4\nmove 15 from 6 to 1\nmove 4 from 4 to 1\nmove 4 from 2 to 3\nmove 8 from 3 to 2\nmove 5 from 2 to 4\nmove 1 from 8 to 6\nmove 1 from 8 to 9\nmove 1 from 3 to 1\nmove 3 from 7 to 3\nmove 5 from 7 to 6\nmove 4 from 2 to 9\nmove 6 from 2 to 6\nmove 4 from 9 to 6\nmove 12 from 1 to 5\nmove 6 from 4 to 1\nmove 1 from 3 to 6\nmove 4 from 5 to 8\nmove 7 from 5 to 3\nmove 3 from 8 to 2\nmove 1 from 2 to 3\nmove 1 from 9 to 5\nmove 1 from 4 to 5\nmove 1 from 8 to 5\nmove 8 from 6 to 9\nmove 10 from 1 to 4\nmove 3 from 6 to 1\nmove 9 from 3 to 6\nmove 1 from 3 to 8\nmove 1 from 2 to 4\nmove 6 from 9 to 1\nmove 1 from 1 to 4\nmove 10 from 1 to 6\nmove 1 from 8 to 6\nmove 13 from 6 to 7\nmove 1 from 2 to 1\nmove 1 from 9 to 6\nmove 9 from 7 to 5\nmove 1 from 9 to 4\nmove 3 from 7 to 1\nmove 3 from 5 to 6\nmove 10 from 4 to 7\nmove 5 from 6 to 5\nmove 3 from 4 to 5\nmove 13 from 6 to 9\nmove 7 from 5 to 3\nmove 6 from 3 to 2\nmove 5 from 6 to 4\nmove 4 from 2 to 8";
+    assert_eq!(
+        Ok(String::from("FRDSQRRCD")),
+        crates_rearrange(puzzle_input, crate_mover_9000.as_ref())
+    );
+    assert_eq!(
+        Ok(String::from("HRFTQVWNN")),
+        crates_rearrange(puzzle_input, crate_mover_9001.as_ref())
+    );
+    assert_eq!(
+        Ok(String::from("FRDSQRRCD")),
+        crates_rearrange(puzzle_input, crane_get("capacity:1").unwrap().as_ref())
+    );
+    assert!(crane_get("capacity:0").is_none());
+    assert!(crane_get("9002").is_none());
 }
 
 /*
@@ -200,3 +1292,55 @@ The Elves just need to know which crate will end up on top of each stack; in thi
 
 After the rearrangement procedure completes, what crate ends up on top of each stack?
 */
+
+/*
+--- Part Two ---
+
+As you watch the crane operator expertly rearrange the crates, you notice the process isn't following your prediction.
+
+Some mud was covering the writing on the side of the crane, and you quickly wipe it away. The crane isn't a CrateMover 9000 - it's a CrateMover 9001.
+
+The CrateMover 9001 is notable for many new and exciting features: air conditioning, leather seats, an extra cup holder, and the ability to pick up and move multiple crates at once.
+
+Again considering the example above, the crates begin in the same configuration:
+
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+Moving a single crate from stack 2 to stack 1 behaves the same as before:
+
+[D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+However, the action of moving three crates from stack 1 to stack 3 means that those three moved crates stay in the same order, resulting in this new configuration:
+
+        [D]
+        [N]
+    [C] [Z]
+    [M] [P]
+ 1   2   3
+
+Next, as both crates are moved from stack 2 to stack 1, they retain their order as well:
+
+        [D]
+        [N]
+[C]     [Z]
+[M]     [P]
+ 1   2   3
+
+Finally, a single crate is still moved from stack 1 to stack 2, but now it's crate C that gets moved:
+
+        [D]
+        [N]
+        [Z]
+[M] [C] [P]
+ 1   2   3
+
+In this example, the CrateMover 9001 has put the crates in a totally different order: MCD.
+
+Before the rearrangement process finishes, update your simulation so that the Elves know where they should stand to be ready to unload the final supplies. After the rearrangement procedure completes, what crate ends up on top of each stack?
+*/
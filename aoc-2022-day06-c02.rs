@@ -2,20 +2,51 @@
 // (part 2)
 
 const MARKER_LENTH: usize = 14;
+const PACKET_MARKER_LENTH: usize = 4; // part 1
 
-fn find_marker(signal: &str) -> usize {
-    assert!(signal.len() >= MARKER_LENTH);
+// position (starting at 1) of the end of the first window of `marker_len` different characters,
+// 0 if there is none
+fn find_marker(signal: &str, marker_len: usize) -> usize {
+    assert!(marker_len > 0);
+
+    let bytes: &[u8] = signal.as_bytes();
+    // occurrences of each byte in the current window, and how many bytes occur in it
+    let mut counts: [u32; 256] = [0; 256];
+    let mut distinct: usize = 0;
+    for i in 0..bytes.len() {
+        counts[bytes[i] as usize] += 1;
+        if counts[bytes[i] as usize] == 1 {
+            distinct += 1;
+        }
+        if i >= marker_len {
+            counts[bytes[i - marker_len] as usize] -= 1;
+            if counts[bytes[i - marker_len] as usize] == 0 {
+                distinct -= 1;
+            }
+        }
+        if distinct == marker_len {
+            return i + 1;
+        }
+    }
+
+    return 0;
+}
+
+// original implementation, rebuilding a set for every window, kept to check and benchmark the
+// linear one against
+fn find_marker_reference(signal: &str, marker_len: usize) -> usize {
+    assert!(signal.len() >= marker_len);
 
     let mut marker_end_pos: usize = 0; // return 0 if not found
     let mut packet: std::collections::HashSet<char> = std::collections::HashSet::new();
-    for i_signal in 0..signal.len() - MARKER_LENTH {
+    for i_signal in 0..=signal.len() - marker_len {
         packet.clear();
-        for i in i_signal..i_signal + MARKER_LENTH {
+        for i in i_signal..i_signal + marker_len {
             let c: char = signal.chars().nth(i).unwrap();
             packet.insert(c);
         }
-        if packet.len() == MARKER_LENTH {
-            marker_end_pos = i_signal + MARKER_LENTH; // not -1 cause indices start at 1
+        if packet.len() == marker_len {
+            marker_end_pos = i_signal + marker_len; // not -1 cause indices start at 1
             break;
         }
     }
@@ -23,15 +54,311 @@ fn find_marker(signal: &str) -> usize {
     return marker_end_pos;
 }
 
+// distinct bytes of the last `len` bytes of a stream
+struct MarkerWindow {
+    len: usize,
+    counts: [u32; 256],
+    distinct: usize,
+    // bytes pushed since the window was last cleared
+    filled: usize,
+}
+
+// reports every marker of several lengths in a stream fed byte by byte, a marker being `len`
+// different bytes in a row, with overlapping markers or not
+struct MarkerScanner {
+    windows: Vec<MarkerWindow>,
+    overlapping: bool,
+    // last bytes of the stream, as many as the longest marker
+    recent: std::collections::VecDeque<u8>,
+    position: usize,
+}
+
+impl MarkerScanner {
+    fn new(marker_lens: &[usize], overlapping: bool) -> MarkerScanner {
+        assert!(marker_lens.iter().all(|len| *len > 0));
+        return MarkerScanner {
+            windows: marker_lens
+                .iter()
+                .map(|len| MarkerWindow {
+                    len: *len,
+                    counts: [0; 256],
+                    distinct: 0,
+                    filled: 0,
+                })
+                .collect(),
+            overlapping: overlapping,
+            recent: std::collections::VecDeque::new(),
+            position: 0,
+        };
+    }
+
+    // calls `on_marker` with the marker length and the position (starting at 1) of its end
+    fn push(&mut self, byte: u8, on_marker: &mut dyn FnMut(usize, usize)) {
+        self.position += 1;
+        self.recent.push_front(byte);
+        let recent_len: usize = self.windows.iter().map(|w| w.len).max().unwrap_or(0) + 1;
+        self.recent.truncate(recent_len);
+
+        for window in self.windows.iter_mut() {
+            window.counts[byte as usize] += 1;
+            if window.counts[byte as usize] == 1 {
+                window.distinct += 1;
+            }
+            window.filled += 1;
+            if window.filled > window.len {
+                let old: usize = self.recent[window.len] as usize;
+                window.counts[old] -= 1;
+                if window.counts[old] == 0 {
+                    window.distinct -= 1;
+                }
+            }
+            if window.distinct == window.len {
+                on_marker(window.len, self.position);
+                if !self.overlapping {
+                    window.counts = [0; 256];
+                    window.distinct = 0;
+                    window.filled = 0;
+                }
+            }
+        }
+    }
+}
+
+fn markers_scan<R: std::io::Read>(
+    mut reader: R,
+    scanner: &mut MarkerScanner,
+    on_marker: &mut dyn FnMut(usize, usize),
+) -> std::io::Result<()> {
+    let mut buffer: [u8; 65536] = [0; 65536];
+    loop {
+        let read: usize = reader.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(());
+        }
+        for byte in &buffer[0..read] {
+            scanner.push(*byte, on_marker);
+        }
+    }
+}
+
+// every marker end position (starting at 1) of each length, overlapping ones included
+fn markers_find_all<R: std::io::Read>(
+    reader: R,
+    marker_lens: &[usize],
+) -> std::io::Result<Vec<(usize, usize)>> {
+    let mut markers: Vec<(usize, usize)> = Vec::new();
+    let mut scanner: MarkerScanner = MarkerScanner::new(marker_lens, true);
+    markers_scan(reader, &mut scanner, &mut |len, position| {
+        markers.push((len, position))
+    })?;
+    return Ok(markers);
+}
+
+// splits a stream in packets (or messages) each starting after a marker, calling `on_packet` with
+// the position (starting at 1) of the end of the marker and the bytes up to the next marker,
+// markers don't overlap and bytes before the first one are dropped
+fn packets_split<R: std::io::Read>(
+    reader: R,
+    marker_len: usize,
+    on_packet: &mut dyn FnMut(usize, &[u8]),
+) -> std::io::Result<()> {
+    let mut scanner: MarkerScanner = MarkerScanner::new(&[marker_len], false);
+    let mut packet_start: Option<usize> = None;
+    let mut payload: Vec<u8> = Vec::new();
+
+    let mut buffer: [u8; 65536] = [0; 65536];
+    let mut reader = reader;
+    loop {
+        let read: usize = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        for byte in &buffer[0..read] {
+            payload.push(*byte);
+            let mut marker: bool = false;
+            scanner.push(*byte, &mut |_, _| marker = true);
+            if marker {
+                if let Some(start) = packet_start {
+                    on_packet(start, &payload[0..payload.len() - marker_len]);
+                }
+                packet_start = Some(scanner.position);
+                payload.clear();
+            }
+        }
+    }
+    if let Some(start) = packet_start {
+        on_packet(start, &payload);
+    }
+
+    return Ok(());
+}
+
+// xorshift, good enough to generate inputs without any dependency
+fn random_next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    return *state;
+}
+
+// signal made of only `marker_len - 1` different letters, so its only marker is its end
+fn signal_generate(len: usize, marker_len: usize, seed: u64) -> String {
+    let mut state: u64 = seed;
+    let mut signal: String = String::with_capacity(len);
+    for _ in 0..len - marker_len {
+        signal.push((b'a' + (random_next(&mut state) % (marker_len as u64 - 1)) as u8) as char);
+    }
+    for i in 0..marker_len {
+        signal.push((b'a' + i as u8) as char);
+    }
+    return signal;
+}
+
+fn benchmark() {
+    for len in [1_000_000, 16_000_000] {
+        let signal: String = signal_generate(len, MARKER_LENTH, 0x2022_0006);
+
+        let start = std::time::Instant::now();
+        assert_eq!(len, find_marker(&signal, MARKER_LENTH));
+        println!("{} bytes signal: {:?}", len, start.elapsed());
+    }
+    // the reference being quadratic, it's only compared on a short signal
+    let signal: String = signal_generate(20_000, MARKER_LENTH, 0x2022_0006);
+    let start = std::time::Instant::now();
+    assert_eq!(20_000, find_marker(&signal, MARKER_LENTH));
+    let linear_duration = start.elapsed();
+    let start = std::time::Instant::now();
+    assert_eq!(20_000, find_marker_reference(&signal, MARKER_LENTH));
+    println!(
+        "20000 bytes signal: {:?}, reference {:?}",
+        linear_duration,
+        start.elapsed()
+    );
+}
+
+fn marker_len_arg(arg: &str) -> usize {
+    match arg.parse::<usize>() {
+        Ok(len) if len > 0 => return len,
+        _ => {
+            eprintln!("expected a marker length, not '{}'", arg);
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("bench") {
+        benchmark();
+        return;
+    }
+    if std::env::args().nth(1).as_deref() == Some("scan") {
+        // every marker of the stdin stream, for the lengths given as arguments (4 and 14 by
+        // default), as `length position` lines
+        let mut lens: Vec<usize> = std::env::args()
+            .skip(2)
+            .map(|len| marker_len_arg(&len))
+            .collect();
+        if lens.is_empty() {
+            lens = vec![PACKET_MARKER_LENTH, MARKER_LENTH];
+        }
+        let mut scanner: MarkerScanner = MarkerScanner::new(&lens, true);
+        if let Err(error) = markers_scan(
+            std::io::stdin().lock(),
+            &mut scanner,
+            &mut |len, position| println!("{} {}", len, position),
+        ) {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+    if std::env::args().nth(1).as_deref() == Some("split") {
+        // packets of the stdin stream, or messages with 14 as argument, one per line
+        let len: usize = std::env::args()
+            .nth(2)
+            .map_or(PACKET_MARKER_LENTH, |len| marker_len_arg(&len));
+        if let Err(error) = packets_split(std::io::stdin().lock(), len, &mut |position, payload| {
+            println!("{} {}", position, String::from_utf8_lossy(payload))
+        }) {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // example
-    assert_eq!(19, find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
-    assert_eq!(23, find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz"));
-    assert_eq!(23, find_marker("nppdvjthqldpwncqszvftbrmjlhg"));
-    assert_eq!(29, find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"));
-    assert_eq!(26, find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"));
+    assert_eq!(
+        19,
+        find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", MARKER_LENTH)
+    );
+    assert_eq!(
+        23,
+        find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", MARKER_LENTH)
+    );
+    assert_eq!(
+        23,
+        find_marker("nppdvjthqldpwncqszvftbrmjlhg", MARKER_LENTH)
+    );
+    assert_eq!(
+        29,
+        find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", MARKER_LENTH)
+    );
+    assert_eq!(
+        26,
+        find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", MARKER_LENTH)
+    );
     //// user puzzle input
-    assert_eq!(2383, find_marker("tzltzltthfthtdtstftbfbnffdjjvnvcccnznpndppsvvlnvlnvndntnllwffvwwcpwccssqbqnbbwzbwwfjjscspspfsfvvzjvvmjvjwjljbbbqtbbcqbccdqcdcpddnvnjvvqwwbrbggjllhbbzlblrrrcwwrfrmffgddfsddnqnqpnnmzzwlzwzqwqgqnngnfnvnhvvfnvvszzrbrqbqnbbrsbsvssgngwgqwqbqsbsvbbdvdfvdvbbqlqdlqdllnppwcclwlvwlwjlwwdjjjcschhhmrrnnrznzlnnwmnwnfngghngnpggmrrzrttdbdtddjhdhrrqddnhnjjntjtbtmtffwcfcnfcnfnjjfnjfjjhrhhgfgttgmttltfllfqfnqqcbbrlblrbrcrssrllhddmnddmcdddvttfdfqfmqqcncggczczdccvhcvcjvvgnnqttltcchzhjjwmmqvqtqnqbbhddcqdccnwccttgwwzssncncjjprjjpbprrndnrnrhnhrnhngnhhbjbccpmpmrppjvpjpqjjsvjjhttcclmmqzzlggtqqqgdqqbhhtmmqfmqmwmjwmjmmnsmsvsrrvpvhhtshhhmqmdqqqqlfffwgwhwjjrfjfmfrmffgzzjvzznqqwggcvcnvvcpvpjpjbpbrpbrrzhhcffqqlzzrmzrmzmzrrqdqrqnqrnrnqnrnsrrnjrrgllgqlljldjjmvvqbqhhsmsmddsfdddcmmphhtjhjzhjjjhcjjzppwhwvvdnvnvpnvnlnvnffrjjtzzdqzzngzgqghhmvvgwwqhwhlhvvfzzdpzddtbdtbtvtlvlqlllvvdvsdsslccbscsbbcggldlvvdccdscdsdzsddpggcfggnffpjpnpcncfcqqlvvszzbpbqbcqczcqzcqzqqlrlwwbgglrrbgbhhlbbsffrprtpppdwdhwhbbpssvbvnntbbfnfddmmfcfcjfftbfbdbccngcgjcjtjftjffrwwtwlwddczcnzczpzcpccrqcrczrzcrzzhnntgtqgtgztgzzcpphshwswfwqqnzngzzbpzzpqpzqznzdzmzqqjzzhnhvnnqjnnrdrcctdtppsffzvvwvbbpttsrsspsddfvvfllrtlljpjlplcctthbbgbqgbgmmpwpvvghvhfhwfwcwvwrvwrvvcgccdncddbmbmfmlffgqggrzgzhhzfzdfzfhhzdhzhjzjhhbffrtrjtthssbpspddpttrggdndsnnjwwzrwrvvmqvmvdvhvjvnnqmmhrhrzzzwttnftfddsdfsfjsjcccvhvgvgzvgzvzbvvhwhbbpccfjfllgmlggtbggdsgsmmmnznqnhqhrqhrrtthwhqqvzqvvvqgqffpvptvpvwvbvsscsnnhlnhhzrzjrjrllcffpqpbpsbbflblppqffbqqpttccdgcgdgfdfgdgjddjljpljppdspddflftllhchmcmwcwddrqqsdsswwmwwwjdjvdvwwcgcddmhhqtqvqmvvnffqppnvpphpnnshsmhmdhmdddlnlmnmsnnpttlfttnqnhqnqrnrddplplbpbdpdhppccbnbwwpwzwgwdgdnggcmmtnmnznmzmwmrmtrrjppjgjmggndgnnjzzrczzftztzgzvgvddgmdgmgpmmgjmmnrrmvmnvvhqqggsnnplnlnbbrcbbpphssnqsnspnncfchhvjjdjvjfjddbjbddhlllpdnrhtzhqpphzfbjclncdlrbtzhcwslpnstdvjslnzfrvfdlmpgpfhrqtjvqvjlqgdcjrbjtjrgvbfwjzsvrbmffnhvjqnshvdjbgwmpwlfjznngzpqbvlztnvgvjsnwvhpfwbhfsmjgwwjdrrwbtvpwtzvjfhwrmnrhdsvgpgdsfgndmffqfplsgjrsvztzlznqsrbldbmmhqmjtrzscrbwlpgztlrvllprnhzsvtnvwzmjwqhqpjqhntcrscwcdnwzpvbdczzcmzrmdwthdtszqzftcsfbwfqggpcntfrgwpmjpdzjnczwcjmdjnrqjfwqbznznmcdvzqlpqschnmcfqjjrjwfmqqftfdhdzffvshqbmrgrpvlgqcgsbsngttvcpjswdgrhbblrhjllfbzngqjzzbdwtnlnrbpftvwbmrhvcnntdrbvtrtpcsqdsrvpsgggfpwcbzhwhwmmmmmgjzgdtwnzjdwjfljghbjvjnsgshmdpztnbbrnwfvzhtzqpzttftdmcmqzlnrgncwwtpwqrgmpmwwchwhbbbblcndbsrrqtnztcmqhvdwfcswnswvhqdtqfdrhjgczqvrzqczmnpcgbwntjvlsfrzrrjtsvfzfmbwwsftwqvttpjvbggrlcspnfhwwmrhdbbhdcjvmrhppvcmtmfhszjlcjjsdqfvjttcmffwzfpmjmjzhcrqmhhwzhjlnwphvvhmrbllsvpjljthjndffrdbmdjncnmdtcwfwjdwnrdlvqsbzczlhwrtpnzfwzzwbrqpglgvrjsnsprvwszmlrjcdgzwchmcqrjdlzqfvqwwfszpptprhcfsdfcrnhvhgvcdwgnqzjtmgznltbjjqwzlljrqcmpdncshzvsmvjwlmvtwbtjcgmqfslvwcfqpljzdjmdvqjlztbsbshcwhlvzcmzljvrrhrbzwvthgtnszpcrrdwcmtdncdzlbdscfbhrlqttcfshqrsgvzhlcnvfhppdqvblsznmctftmnslwgbmbgshgwvmzpdnmqmjgqnvrwprmbzrdprrbcwnslczvzgnssjqqzdrlntrnsrgbjjcpqvnqwvnwgslchqzbphcqsbvgvwzlnsndfrhqjvtlnqpcsgswzfvhjmfgwgfvhjgzntdbztmjsbmtwlfmvvgvztvwwmqclcgctqbvljgfngcvfqlmmvqmbtrnnbhqjjndzqhvvdztjgvwgrtltfrlzrjcpgwvpqwmcwmqccjtjhhbrrqphlpljvhjzpdfcdsgzfpnzdzhfdqjsnrvmwstrmmwmlhbvrjbtnmvwcqnzqzzpwzjdnfhqwwlsvgnnjgffzcnrtbjfwllnrgppchqwnfpwpgnfbvwcbjrlscnwlswjmnrcrhtdhgpzvgtfcqzgqtwvlhrgbmjvvzhrlzfvmrdjjctvfwsgmjwbqslhmjlcvlwrdqfmbhcfrmrvqtslplwpsgrfmntmtvmvqttbspmftgqdzlcfplcvvfmmjttwqjpdtjzzsfjcprvbwdvfrpzddhwrlmsnpjzqgdlfdzvdjnjtgtfflzzvjlmnnvmglrptsnppwscznltcvzfjmwshnsqsvsjpqwsqlbwzslhgrdcbbvcjspqfntbcpwwrphgpmwbpqdcfvvtlsgpfshtcrdftsltwnbnmzfwcwlmrhlntmmnnpsdchvntcwbnmjdgwcmzzvbrhbdbmlgwppzwsqvcccdbfzfsfhtmbppnwbtjvrvjtmddhmrjdqgnmrnjjpqsgtbgcvtclzzstlpldtqbnnvqjfbjcfzblvcwhjphzcgwfljjhzzmwdcrzsssznztcwpjlbcffnlmsfjbmtvhhcljmtqdprdmdgwgpnnlmhgwpsgprfqnspmntrdjwjmrflsbfpqhzswbsrdbdhjmvtwmjjnmpllgfllzgwwmswjcmggbrvsbbhjmsdzzpbhbrlphwdsmjdzsqjfrmdmpljnwscjrhdvzqbhhvpmhwqfrrhzlncrrrzhmjdwqjcbsqjbhbdbjzpslrnnbzctnnlhqmqqbdzfbrpfgwsrdglnplpspnnqhtbhzhzgtchcbqcmmcmvlllczqbtmbstzmnlhhhbmmbtjwnbgwjbfhgvfhqlsgdnnrsgghjzjlqfwbbgztdqzbhhwhcwtjwsgstjpzcjjvqbpfpvlqfqshvfzbwmfcwfgqvgmbppfvzgzznzhsqbvzlztsnmnrbgqzbmbhlvqhfncdfcpttgzpvvzdbhvqdtqsblqvrsrnmsfbqhrpvlzffdzptzghvmbmdzjrsqzhqddqm"));
+    assert_eq!(2383, find_marker("tzltzltthfthtdtstftbfbnffdjjvnvcccnznpndppsvvlnvlnvndntnllwffvwwcpwccssqbqnbbwzbwwfjjscspspfsfvvzjvvmjvjwjljbbbqtbbcqbccdqcdcpddnvnjvvqwwbrbggjllhbbzlblrrrcwwrfrmffgddfsddnqnqpnnmzzwlzwzqwqgqnngnfnvnhvvfnvvszzrbrqbqnbbrsbsvssgngwgqwqbqsbsvbbdvdfvdvbbqlqdlqdllnppwcclwlvwlwjlwwdjjjcschhhmrrnnrznzlnnwmnwnfngghngnpggmrrzrttdbdtddjhdhrrqddnhnjjntjtbtmtffwcfcnfcnfnjjfnjfjjhrhhgfgttgmttltfllfqfnqqcbbrlblrbrcrssrllhddmnddmcdddvttfdfqfmqqcncggczczdccvhcvcjvvgnnqttltcchzhjjwmmqvqtqnqbbhddcqdccnwccttgwwzssncncjjprjjpbprrndnrnrhnhrnhngnhhbjbccpmpmrppjvpjpqjjsvjjhttcclmmqzzlggtqqqgdqqbhhtmmqfmqmwmjwmjmmnsmsvsrrvpvhhtshhhmqmdqqqqlfffwgwhwjjrfjfmfrmffgzzjvzznqqwggcvcnvvcpvpjpjbpbrpbrrzhhcffqqlzzrmzrmzmzrrqdqrqnqrnrnqnrnsrrnjrrgllgqlljldjjmvvqbqhhsmsmddsfdddcmmphhtjhjzhjjjhcjjzppwhwvvdnvnvpnvnlnvnffrjjtzzdqzzngzgqghhmvvgwwqhwhlhvvfzzdpzddtbdtbtvtlvlqlllvvdvsdsslccbscsbbcggldlvvdccdscdsdzsddpggcfggnffpjpnpcncfcqqlvvszzbpbqbcqczcqzcqzqqlrlwwbgglrrbgbhhlbbsffrprtpppdwdhwhbbpssvbvnntbbfnfddmmfcfcjfftbfbdbccngcgjcjtjftjffrwwtwlwddczcnzczpzcpccrqcrczrzcrzzhnntgtqgtgztgzzcpphshwswfwqqnzngzzbpzzpqpzqznzdzmzqqjzzhnhvnnqjnnrdrcctdtppsffzvvwvbbpttsrsspsddfvvfllrtlljpjlplcctthbbgbqgbgmmpwpvvghvhfhwfwcwvwrvwrvvcgccdncddbmbmfmlffgqggrzgzhhzfzdfzfhhzdhzhjzjhhbffrtrjtthssbpspddpttrggdndsnnjwwzrwrvvmqvmvdvhvjvnnqmmhrhrzzzwttnftfddsdfsfjsjcccvhvgvgzvgzvzbvvhwhbbpccfjfllgmlggtbggdsgsmmmnznqnhqhrqhrrtthwhqqvzqvvvqgqffpvptvpvwvbvsscsnnhlnhhzrzjrjrllcffpqpbpsbbflblppqffbqqpttccdgcgdgfdfgdgjddjljpljppdspddflftllhchmcmwcwddrqqsdsswwmwwwjdjvdvwwcgcddmhhqtqvqmvvnffqppnvpphpnnshsmhmdhmdddlnlmnmsnnpttlfttnqnhqnqrnrddplplbpbdpdhppccbnbwwpwzwgwdgdnggcmmtnmnznmzmwmrmtrrjppjgjmggndgnnjzzrczzftztzgzvgvddgmdgmgpmmgjmmnrrmvmnvvhqqggsnnplnlnbbrcbbpphssnqsnspnncfchhvjjdjvjfjddbjbddhlllpdnrhtzhqpphzfbjclncdlrbtzhcwslpnstdvjslnzfrvfdlmpgpfhrqtjvqvjlqgdcjrbjtjrgvbfwjzsvrbmffnhvjqnshvdjbgwmpwlfjznngzpqbvlztnvgvjsnwvhpfwbhfsmjgwwjdrrwbtvpwtzvjfhwrmnrhdsvgpgdsfgndmffqfplsgjrsvztzlznqsrbldbmmhqmjtrzscrbwlpgztlrvllprnhzsvtnvwzmjwqhqpjqhntcrscwcdnwzpvbdczzcmzrmdwthdtszqzftcsfbwfqggpcntfrgwpmjpdzjnczwcjmdjnrqjfwqbznznmcdvzqlpqschnmcfqjjrjwfmqqftfdhdzffvshqbmrgrpvlgqcgsbsngttvcpjswdgrhbblrhjllfbzngqjzzbdwtnlnrbpftvwbmrhvcnntdrbvtrtpcsqdsrvpsgggfpwcbzhwhwmmmmmgjzgdtwnzjdwjfljghbjvjnsgshmdpztnbbrnwfvzhtzqpzttftdmcmqzlnrgncwwtpwqrgmpmwwchwhbbbblcndbsrrqtnztcmqhvdwfcswnswvhqdtqfdrhjgczqvrzqczmnpcgbwntjvlsfrzrrjtsvfzfmbwwsftwqvttpjvbggrlcspnfhwwmrhdbbhdcjvmrhppvcmtmfhszjlcjjsdqfvjttcmffwzfpmjmjzhcrqmhhwzhjlnwphvvhmrbllsvpjljthjndffrdbmdjncnmdtcwfwjdwnrdlvqsbzczlhwrtpnzfwzzwbrqpglgvrjsnsprvwszmlrjcdgzwchmcqrjdlzqfvqwwfszpptprhcfsdfcrnhvhgvcdwgnqzjtmgznltbjjqwzlljrqcmpdncshzvsmvjwlmvtwbtjcgmqfslvwcfqpljzdjmdvqjlztbsbshcwhlvzcmzljvrrhrbzwvthgtnszpcrrdwcmtdncdzlbdscfbhrlqttcfshqrsgvzhlcnvfhppdqvblsznmctftmnslwgbmbgshgwvmzpdnmqmjgqnvrwprmbzrdprrbcwnslczvzgnssjqqzdrlntrnsrgbjjcpqvnqwvnwgslchqzbphcqsbvgvwzlnsndfrhqjvtlnqpcsgswzfvhjmfgwgfvhjgzntdbztmjsbmtwlfmvvgvztvwwmqclcgctqbvljgfngcvfqlmmvqmbtrnnbhqjjndzqhvvdztjgvwgrtltfrlzrjcpgwvpqwmcwmqccjtjhhbrrqphlpljvhjzpdfcdsgzfpnzdzhfdqjsnrvmwstrmmwmlhbvrjbtnmvwcqnzqzzpwzjdnfhqwwlsvgnnjgffzcnrtbjfwllnrgppchqwnfpwpgnfbvwcbjrlscnwlswjmnrcrhtdhgpzvgtfcqzgqtwvlhrgbmjvvzhrlzfvmrdjjctvfwsgmjwbqslhmjlcvlwrdqfmbhcfrmrvqtslplwpsgrfmntmtvmvqttbspmftgqdzlcfplcvvfmmjttwqjpdtjzzsfjcprvbwdvfrpzddhwrlmsnpjzqgdlfdzvdjnjtgtfflzzvjlmnnvmglrptsnppwscznltcvzfjmwshnsqsvsjpqwsqlbwzslhgrdcbbvcjspqfntbcpwwrphgpmwbpqdcfvvtlsgpfshtcrdftsltwnbnmzfwcwlmrhlntmmnnpsdchvntcwbnmjdgwcmzzvbrhbdbmlgwppzwsqvcccdbfzfsfhtmbppnwbtjvrvjtmddhmrjdqgnmrnjjpqsgtbgcvtclzzstlpldtqbnnvqjfbjcfzblvcwhjphzcgwfljjhzzmwdcrzsssznztcwpjlbcffnlmsfjbmtvhhcljmtqdprdmdgwgpnnlmhgwpsgprfqnspmntrdjwjmrflsbfpqhzswbsrdbdhjmvtwmjjnmpllgfllzgwwmswjcmggbrvsbbhjmsdzzpbhbrlphwdsmjdzsqjfrmdmpljnwscjrhdvzqbhhvpmhwqfrrhzlncrrrzhmjdwqjcbsqjbhbdbjzpslrnnbzctnnlhqmqqbdzfbrpfgwsrdglnplpspnnqhtbhzhzgtchcbqcmmcmvlllczqbtmbstzmnlhhhbmmbtjwnbgwjbfhgvfhqlsgdnnrsgghjzjlqfwbbgztdqzbhhwhcwtjwsgstjpzcjjvqbpfpvlqfqshvfzbwmfcwfgqvgmbppfvzgzznzhsqbvzlztsnmnrbgqzbmbhlvqhfncdfcpttgzpvvzdbhvqdtqsblqvrsrnmsfbqhrpvlzffdzptzghvmbmdzjrsqzhqddqm", MARKER_LENTH));
+    // every marker, streamed
+    assert_eq!(
+        vec![
+            (4, 7),
+            (4, 8),
+            (5, 8),
+            (4, 9),
+            (5, 9),
+            (4, 10),
+            (5, 10),
+            (4, 11),
+            (5, 11)
+        ],
+        markers_find_all("mjqjpqmgblj".as_bytes(), &[4, 5]).unwrap()
+    );
+    assert_eq!(
+        Some(&(MARKER_LENTH, 19)),
+        markers_find_all(
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes(),
+            &[PACKET_MARKER_LENTH, MARKER_LENTH]
+        )
+        .unwrap()
+        .iter()
+        .find(|(len, _)| *len == MARKER_LENTH)
+    );
+    let mut packets: Vec<(usize, String)> = Vec::new();
+    packets_split(
+        "aaabcdHELLOaaefghWORLD!".as_bytes(),
+        4,
+        &mut |position, payload| {
+            packets.push((position, String::from_utf8(payload.to_vec()).unwrap()))
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            (6, String::from("HELLOa")),
+            (16, String::from("")),
+            (20, String::from("LD!")),
+        ],
+        packets
+    );
+    // marker at the very end, missing marker and other lengths
+    assert_eq!(4, find_marker("abcd", 4));
+    assert_eq!(5, find_marker("aabcd", 4));
+    assert_eq!(5, find_marker_reference("aabcd", 4));
+    assert_eq!(0, find_marker("abcabc", 4));
+    assert_eq!(0, find_marker("ab", 4));
+    assert_eq!(1, find_marker("a", 1));
+    assert_eq!(3, find_marker("aab", 2));
 }
 
 /*
@@ -3,8 +3,11 @@
 
 const FS_SPACE: usize = 70000000;
 const FREE_SPACE_NEED: usize = 30000000;
+// memory the deletion planner can use, in bits
+const PLAN_BITS_MAX: usize = 1 << 30;
 
-fn number_parse(number: &str) -> (usize, usize) {
+// leading number and its digits count, the number being None without digits or when too large
+fn number_parse(number: &str) -> (Option<usize>, usize) {
     let mut digit_count: usize = 0;
     for c in number.chars() {
         if c < '0' || c > '9' {
@@ -12,75 +15,1259 @@ fn number_parse(number: &str) -> (usize, usize) {
         }
         digit_count += 1;
     }
-    let n = number
-        .get(0..digit_count)
-        .unwrap()
-        .parse::<usize>()
-        .unwrap();
+    let n: Option<usize> = number.get(0..digit_count).unwrap().parse::<usize>().ok();
     return (n, digit_count);
 }
 
-fn directory_size(commands: &mut Vec<&str>) -> Vec<usize> {
-    let mut sizes: Vec<usize> = Vec::new();
-
-    let mut this_size: usize = 0;
-    let mut going_up: bool = false;
-    while !going_up && !(commands.len() == 0) {
-        let entry: &str = commands.pop().unwrap();
-        if entry == "$ cd .." {
-            going_up = true;
-        } else if entry == "$ ls" {
-            // do nothing, we don't care about listing command
-        } else if entry.starts_with("$ cd ") {
-            // sub dir, add its size to this dir size
-            let mut subs: Vec<usize> = directory_size(commands);
-            this_size += subs[0];
-            sizes.append(&mut subs);
-        } else {
-            // listing entry get its size if its a file
-            assert!(!entry.starts_with("$")); // make sure it's not a command
-            if !entry.starts_with("dir ") {
-                // don't care about directory entry we need a 'cd' command to know its size
-                let (entry_size, digit_count) = number_parse(entry);
-                assert!(digit_count > 0);
-                this_size += entry_size;
-            }
+#[derive(Debug)]
+struct Directory {
+    name: String,
+    parent: Option<usize>,
+    directories: std::collections::BTreeMap<String, usize>,
+    files: std::collections::BTreeMap<String, usize>,
+}
+
+// directories tree rebuilt from a terminal log, directories are indices in `directories`, the
+// root being 0 and every directory coming after its parent
+#[derive(Debug)]
+struct FileSystem {
+    directories: Vec<Directory>,
+    // inconsistencies found in the log, which doesn't prevent building the tree
+    issues: Vec<String>,
+}
+
+impl FileSystem {
+    fn new() -> FileSystem {
+        return FileSystem {
+            directories: vec![Directory {
+                name: String::new(),
+                parent: None,
+                directories: std::collections::BTreeMap::new(),
+                files: std::collections::BTreeMap::new(),
+            }],
+            issues: Vec::new(),
+        };
+    }
+
+    fn directory_add(&mut self, parent: usize, name: &str) -> usize {
+        if let Some(directory) = self.directories[parent].directories.get(name) {
+            return *directory;
         }
+        self.directories.push(Directory {
+            name: String::from(name),
+            parent: Some(parent),
+            directories: std::collections::BTreeMap::new(),
+            files: std::collections::BTreeMap::new(),
+        });
+        let directory: usize = self.directories.len() - 1;
+        self.directories[parent]
+            .directories
+            .insert(String::from(name), directory);
+        return directory;
     }
 
-    sizes.insert(0, this_size); // always put current size first, so parent can know the sub size
+    fn path(&self, directory: usize) -> String {
+        let mut names: Vec<&str> = Vec::new();
+        let mut current: usize = directory;
+        while let Some(parent) = self.directories[current].parent {
+            names.push(&self.directories[current].name);
+            current = parent;
+        }
+        names.reverse();
+        return format!("/{}", names.join("/"));
+    }
 
-    return sizes;
+    // total size of every directory, sub directories included
+    fn sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self
+            .directories
+            .iter()
+            .map(|d| d.files.values().sum())
+            .collect();
+        // children come after their parent, so they are complete when added to it
+        for directory in (1..self.directories.len()).rev() {
+            let parent: usize = self.directories[directory].parent.unwrap();
+            sizes[parent] += sizes[directory];
+        }
+        return sizes;
+    }
 }
 
-fn find_directory_to_delete(commands: &str) -> usize {
-    let mut command_lines: Vec<&str> = commands.split('\n').collect();
-    command_lines.reverse(); // so we can pop at the end
+fn filesystem_build(terminal: &str) -> Result<FileSystem, String> {
+    let mut fs: FileSystem = FileSystem::new();
 
-    // cd can only move one level up or down, we don't need to keep track of paths
-    let mut sizes = directory_size(&mut command_lines);
+    let mut current: usize = 0;
+    let mut listing: bool = false;
+    for (i, line) in terminal.split('\n').enumerate() {
+        let line_number: usize = i + 1;
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(command) = line.strip_prefix("$ ") {
+            listing = false;
+            if command == "ls" {
+                listing = true;
+            } else if command == "cd /" {
+                current = 0;
+            } else if command == "cd .." {
+                match fs.directories[current].parent {
+                    Some(parent) => current = parent,
+                    None => fs
+                        .issues
+                        .push(format!("line {}: cd .. from the root", line_number)),
+                }
+            } else if let Some(name) = command.strip_prefix("cd ") {
+                if name.is_empty() || name.contains('/') {
+                    return Err(format!(
+                        "line {}: invalid directory '{}'",
+                        line_number, name
+                    ));
+                }
+                if !fs.directories[current].directories.contains_key(name) {
+                    if fs.directories[current].files.contains_key(name) {
+                        return Err(format!(
+                            "line {}: cd into file {}/{}",
+                            line_number,
+                            fs.path(current).trim_end_matches('/'),
+                            name
+                        ));
+                    }
+                    fs.issues.push(format!(
+                        "line {}: cd into unlisted directory {}/{}",
+                        line_number,
+                        fs.path(current).trim_end_matches('/'),
+                        name
+                    ));
+                    current = fs.directory_add(current, name);
+                } else {
+                    current = fs.directories[current].directories[name];
+                }
+            } else {
+                return Err(format!(
+                    "line {}: unknown command '{}'",
+                    line_number, command
+                ));
+            }
+        } else {
+            if !listing {
+                return Err(format!(
+                    "line {}: '{}' outside of a listing",
+                    line_number, line
+                ));
+            }
+            let path: String = fs.path(current);
+            let path: &str = path.trim_end_matches('/');
+            if let Some(name) = line.strip_prefix("dir ") {
+                if fs.directories[current].files.contains_key(name) {
+                    fs.issues.push(format!(
+                        "line {}: {}/{} listed as a file and a directory",
+                        line_number, path, name
+                    ));
+                    continue;
+                }
+                fs.directory_add(current, name);
+            } else {
+                let (size, digit_count) = number_parse(line);
+                let name: Option<&str> = line.get(digit_count + 1..);
+                if digit_count == 0 || line.chars().nth(digit_count) != Some(' ') || name.is_none()
+                {
+                    return Err(format!(
+                        "line {}: invalid listing entry '{}'",
+                        line_number, line
+                    ));
+                }
+                let name: &str = name.unwrap();
+                let size: usize = match size {
+                    Some(size) => size,
+                    None => {
+                        return Err(format!(
+                            "line {}: size of {}/{} too large",
+                            line_number, path, name
+                        ))
+                    }
+                };
+                if fs.directories[current].directories.contains_key(name) {
+                    fs.issues.push(format!(
+                        "line {}: {}/{} listed as a directory and a file",
+                        line_number, path, name
+                    ));
+                    continue;
+                }
+                let previous: Option<usize> = fs.directories[current]
+                    .files
+                    .insert(String::from(name), size);
+                if previous.is_some() && previous != Some(size) {
+                    fs.issues.push(format!(
+                        "line {}: {}/{} size changed from {} to {}",
+                        line_number,
+                        path,
+                        name,
+                        previous.unwrap(),
+                        size
+                    ));
+                }
+            }
+        }
+    }
+    return Ok(fs);
+}
+
+// space to free so the update fits, 0 if there is already enough
+fn space_to_free(fs_space: usize, free_space_need: usize, used: usize) -> usize {
+    let free_space_available: usize = fs_space.saturating_sub(used);
+    return free_space_need.saturating_sub(free_space_available);
+}
+
+fn find_directory_to_delete(
+    fs_space: usize,
+    free_space_need: usize,
+    terminal: &str,
+) -> Result<usize, String> {
+    let fs: FileSystem = filesystem_build(terminal)?;
+    let mut sizes: Vec<usize> = fs.sizes();
+    let root_size: usize = sizes[0];
     sizes.sort();
 
     let mut directory_size_to_delete = 0;
     {
-        let root_size: usize = sizes[sizes.len() - 1];
-        let free_space_available: usize = FS_SPACE - root_size;
-        let space_to_free: usize = FREE_SPACE_NEED - free_space_available;
+        let space_to_free: usize = space_to_free(fs_space, free_space_need, root_size);
         for size in sizes {
-            if size > space_to_free {
+            // freeing exactly the space needed is enough
+            if size >= space_to_free {
                 directory_size_to_delete = size;
                 break;
             }
         }
     }
 
-    return directory_size_to_delete;
+    return Ok(directory_size_to_delete);
+}
+
+impl FileSystem {
+    fn entry_path(&self, directory: usize, name: &str) -> String {
+        return format!("{}/{}", self.path(directory).trim_end_matches('/'), name);
+    }
+
+    // indented view in the same format as the puzzle text, with directory sizes
+    fn tree(&self) -> String {
+        let sizes: Vec<usize> = self.sizes();
+        let mut text: String = String::new();
+        self.tree_append(0, 0, &sizes, &mut text);
+        return text;
+    }
+
+    fn tree_append(&self, directory: usize, depth: usize, sizes: &[usize], text: &mut String) {
+        let name: &str = if directory == 0 {
+            "/"
+        } else {
+            &self.directories[directory].name
+        };
+        text.push_str(&format!(
+            "{}- {} (dir, size={})\n",
+            "  ".repeat(depth),
+            name,
+            sizes[directory]
+        ));
+        // directories and files mixed in name order, like the puzzle does
+        let mut entries: Vec<(&String, Option<usize>, usize)> = Vec::new();
+        for (name, sub) in &self.directories[directory].directories {
+            entries.push((name, Some(*sub), 0));
+        }
+        for (name, size) in &self.directories[directory].files {
+            entries.push((name, None, *size));
+        }
+        entries.sort();
+        for (name, sub, size) in entries {
+            match sub {
+                Some(sub) => self.tree_append(sub, depth + 1, sizes, text),
+                None => text.push_str(&format!(
+                    "{}- {} (file, size={})\n",
+                    "  ".repeat(depth + 1),
+                    name,
+                    size
+                )),
+            }
+        }
+    }
+
+    // every directory path with its total size, largest first
+    fn du(&self) -> Vec<(String, usize)> {
+        let mut rows: Vec<(String, usize)> = self
+            .sizes()
+            .into_iter()
+            .enumerate()
+            .map(|(directory, size)| (self.path(directory), size))
+            .collect();
+        rows.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        return rows;
+    }
+
+    fn directories_largest(&self, count: usize) -> Vec<(String, usize)> {
+        let mut rows: Vec<(String, usize)> = self.du();
+        rows.truncate(count);
+        return rows;
+    }
+
+    // directories whose deletion frees at least `space` bytes, smallest first
+    fn directories_freeing(&self, space: usize) -> Vec<(String, usize)> {
+        let mut rows: Vec<(String, usize)> = self
+            .du()
+            .into_iter()
+            .filter(|(_, size)| *size >= space)
+            .collect();
+        rows.reverse();
+        return rows;
+    }
+
+    // files strictly larger than `size`, largest first
+    fn files_larger(&self, size: usize) -> Vec<(String, usize)> {
+        let mut rows: Vec<(String, usize)> = Vec::new();
+        for (directory, d) in self.directories.iter().enumerate() {
+            for (name, file_size) in &d.files {
+                if *file_size > size {
+                    rows.push((self.entry_path(directory, name), *file_size));
+                }
+            }
+        }
+        rows.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        return rows;
+    }
+
+    // total size and count of files by extension, "" for files without one
+    fn sizes_by_extension(&self) -> Vec<(String, usize, usize)> {
+        let mut extensions: std::collections::BTreeMap<String, (usize, usize)> =
+            std::collections::BTreeMap::new();
+        for d in &self.directories {
+            for (name, size) in &d.files {
+                let extension: &str = match name.rfind('.') {
+                    Some(dot) if dot > 0 => &name[dot + 1..],
+                    _ => "",
+                };
+                let entry: &mut (usize, usize) =
+                    extensions.entry(String::from(extension)).or_insert((0, 0));
+                entry.0 += size;
+                entry.1 += 1;
+            }
+        }
+        let mut rows: Vec<(String, usize, usize)> = extensions
+            .into_iter()
+            .map(|(extension, (size, count))| (extension, size, count))
+            .collect();
+        rows.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        return rows;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct DeletionPlan {
+    entries: Vec<(String, usize)>,
+    size: usize,
+}
+
+fn bits_get(bits: &[u64], i: usize) -> bool {
+    return bits[i / 64] & (1 << (i % 64)) != 0;
+}
+
+fn bits_set(bits: &mut [u64], i: usize) {
+    bits[i / 64] |= 1 << (i % 64);
+}
+
+// `src` shifted left by `shift` bits, cut to the length of `src`
+fn bits_shifted(src: &[u64], shift: usize) -> Vec<u64> {
+    let mut shifted: Vec<u64> = vec![0; src.len()];
+    let (words, bits): (usize, usize) = (shift / 64, shift % 64);
+    for i in words..src.len() {
+        shifted[i] = src[i - words] << bits;
+        if bits > 0 && i > words {
+            shifted[i] |= src[i - words - 1] >> (64 - bits);
+        }
+    }
+    return shifted;
+}
+
+// first reachable sum in [from, len)
+fn bits_first(bits: &[u64], from: usize, len: usize) -> Option<usize> {
+    return (from..len).find(|i| bits_get(bits, *i));
+}
+
+fn plan_memory_check(space: usize, table_bits: usize, sums_bits: usize) -> Result<(), String> {
+    if table_bits.saturating_add(sums_bits) > PLAN_BITS_MAX {
+        return Err(format!(
+            "planning for {} needs more than {} bytes",
+            space,
+            PLAN_BITS_MAX / 8
+        ));
+    }
+    return Ok(());
+}
+
+// smallest total size of non-nested directories (or of any files if `with_files`) freeing at
+// least `space`, as a subset sum bounded by the smallest single directory large enough
+fn deletion_plan(fs: &FileSystem, space: usize, with_files: bool) -> Result<DeletionPlan, String> {
+    let sizes: Vec<usize> = fs.sizes();
+    if space > sizes[0] {
+        return Err(format!("the filesystem holds less than {}", space));
+    }
+    let single: usize = (0..sizes.len())
+        .filter(|d| sizes[*d] >= space)
+        .min_by_key(|d| sizes[*d])
+        .unwrap();
+    // only sums below the single directory size can improve on it
+    let bound: usize = sizes[single];
+    let words: usize = bound / 64 + 1;
+
+    let mut entries: Vec<(String, usize)> = Vec::new();
+    if bound == space {
+        entries.push((fs.path(single), bound));
+        return Ok(DeletionPlan {
+            entries,
+            size: bound,
+        });
+    }
+    if with_files {
+        let mut files: Vec<(usize, &String, usize)> = Vec::new();
+        for (directory, d) in fs.directories.iter().enumerate() {
+            for (name, size) in &d.files {
+                files.push((directory, name, *size));
+            }
+        }
+        // first file table, reachable sums and their shifted copy
+        plan_memory_check(space, (bound + 1).saturating_mul(32), 2 * words * 64)?;
+        // file which first reached every sum, so the sum without it was reached before
+        let mut first: Vec<u32> = vec![u32::MAX; bound + 1];
+        let mut reach: Vec<u64> = vec![0; words];
+        bits_set(&mut reach, 0);
+        for (k, (_, _, size)) in files.iter().enumerate() {
+            let shifted: Vec<u64> = bits_shifted(&reach, *size);
+            for w in 0..words {
+                let mut new: u64 = shifted[w] & !reach[w];
+                reach[w] |= new;
+                while new != 0 {
+                    let sum: usize = w * 64 + new.trailing_zeros() as usize;
+                    if sum <= bound {
+                        first[sum] = k as u32;
+                    }
+                    new &= new - 1;
+                }
+            }
+        }
+        let mut sum: usize = match bits_first(&reach, space, bound) {
+            Some(sum) => sum,
+            None => {
+                entries.push((fs.path(single), bound));
+                return Ok(DeletionPlan {
+                    entries,
+                    size: bound,
+                });
+            }
+        };
+        let total: usize = sum;
+        let mut chosen: std::collections::HashSet<(usize, &String)> =
+            std::collections::HashSet::new();
+        while sum > 0 {
+            let (directory, name, size) = files[first[sum] as usize];
+            chosen.insert((directory, name));
+            sum -= size;
+        }
+        // directories whose files are all chosen are reported as a whole
+        let mut complete: Vec<bool> = vec![false; fs.directories.len()];
+        for directory in (0..fs.directories.len()).rev() {
+            let d: &Directory = &fs.directories[directory];
+            complete[directory] = d
+                .files
+                .keys()
+                .all(|name| chosen.contains(&(directory, name)))
+                && d.directories.values().all(|sub| complete[*sub]);
+        }
+        let mut pending: Vec<usize> = vec![0];
+        while let Some(directory) = pending.pop() {
+            if complete[directory] && sizes[directory] > 0 {
+                entries.push((fs.path(directory), sizes[directory]));
+                continue;
+            }
+            for (name, size) in &fs.directories[directory].files {
+                if chosen.contains(&(directory, name)) {
+                    entries.push((fs.entry_path(directory, name), *size));
+                }
+            }
+            pending.extend(fs.directories[directory].directories.values());
+        }
+        entries.sort();
+        return Ok(DeletionPlan {
+            entries,
+            size: total,
+        });
+    }
+
+    // directories in preorder, deleting one skips its whole subtree which ends at `end`
+    let mut order: Vec<usize> = Vec::new();
+    let mut end: Vec<usize> = Vec::new();
+    let mut depth_max: usize = 0;
+    // directory and depth to visit, or position whose subtree is done
+    let mut pending: Vec<(usize, usize, bool)> = vec![(0, 1, false)];
+    while let Some((directory, depth, done)) = pending.pop() {
+        if done {
+            end[directory] = order.len();
+            continue;
+        }
+        depth_max = depth_max.max(depth);
+        pending.push((order.len(), depth, true));
+        order.push(directory);
+        end.push(0);
+        for sub in fs.directories[directory].directories.values().rev() {
+            pending.push((*sub, depth + 1, false));
+        }
+    }
+    // first directory table, reachable sums, their shifted copy and the sums kept for the
+    // directories whose subtree is not done, at most one per level
+    plan_memory_check(
+        space,
+        (bound + 1).saturating_mul(32),
+        (depth_max + 2).saturating_mul(words * 64),
+    )?;
+
+    // going back from the last position, the sums reachable with the directories from a position
+    // on only grow, so every sum is made of the directory at the position which first reached it
+    // and of a sum reachable after the subtree of that directory
+    let mut first: Vec<u32> = vec![u32::MAX; bound + 1];
+    let mut reach: Vec<u64> = vec![0; words];
+    bits_set(&mut reach, 0);
+    // sums reachable after the end of a subtree, kept until every directory whose subtree ends
+    // there is done
+    let mut uses: Vec<usize> = vec![0; order.len() + 1];
+    for i in 0..order.len() {
+        if end[i] > i + 1 {
+            uses[end[i]] += 1;
+        }
+    }
+    let mut kept: std::collections::HashMap<usize, Vec<u64>> = std::collections::HashMap::new();
+    for i in (0..order.len()).rev() {
+        if uses[i + 1] > 0 {
+            kept.insert(i + 1, reach.clone());
+        }
+        let shifted: Vec<u64> = if end[i] > i + 1 {
+            let shifted: Vec<u64> = bits_shifted(&kept[&end[i]], sizes[order[i]]);
+            uses[end[i]] -= 1;
+            if uses[end[i]] == 0 {
+                kept.remove(&end[i]);
+            }
+            shifted
+        } else {
+            bits_shifted(&reach, sizes[order[i]])
+        };
+        for w in 0..words {
+            let mut new: u64 = shifted[w] & !reach[w];
+            reach[w] |= new;
+            while new != 0 {
+                let sum: usize = w * 64 + new.trailing_zeros() as usize;
+                if sum <= bound {
+                    first[sum] = i as u32;
+                }
+                new &= new - 1;
+            }
+        }
+    }
+    let mut sum: usize = bits_first(&reach, space, bound).unwrap_or(bound);
+    let total: usize = sum;
+    if sum == bound {
+        entries.push((fs.path(single), bound));
+        sum = 0;
+    }
+    while sum > 0 {
+        let i: usize = first[sum] as usize;
+        entries.push((fs.path(order[i]), sizes[order[i]]));
+        sum -= sizes[order[i]];
+    }
+    entries.sort();
+    return Ok(DeletionPlan {
+        entries,
+        size: total,
+    });
+}
+
+fn rows_text(rows: &[(String, usize)]) -> String {
+    let mut text: String = String::new();
+    for (path, size) in rows {
+        text.push_str(&format!("{:>10}  {}\n", size, path));
+    }
+    return text;
+}
+
+fn extensions_text(rows: &[(String, usize, usize)]) -> String {
+    let mut text: String = String::from("      size  files  extension\n");
+    for (extension, size, count) in rows {
+        let extension: &str = if extension.is_empty() {
+            "(none)"
+        } else {
+            extension
+        };
+        text.push_str(&format!("{:>10}  {:>5}  {}\n", size, count, extension));
+    }
+    return text;
+}
+
+fn json_string(s: &str) -> String {
+    let mut json: String = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    return json;
+}
+
+impl FileSystem {
+    // every directory is {"files":{name:size,...},"directories":{name:{...},...}}
+    fn to_json(&self) -> String {
+        return self.directory_json(0);
+    }
+
+    fn directory_json(&self, directory: usize) -> String {
+        let files: Vec<String> = self.directories[directory]
+            .files
+            .iter()
+            .map(|(name, size)| format!("{}:{}", json_string(name), size))
+            .collect();
+        let directories: Vec<String> = self.directories[directory]
+            .directories
+            .iter()
+            .map(|(name, sub)| format!("{}:{}", json_string(name), self.directory_json(*sub)))
+            .collect();
+        return format!(
+            "{{\"files\":{{{}}},\"directories\":{{{}}}}}",
+            files.join(","),
+            directories.join(",")
+        );
+    }
+
+    // terminal log listing every directory once, that `filesystem_build` reads back as is
+    fn transcript(&self) -> String {
+        let mut text: String = String::from("$ cd /\n");
+        self.transcript_append(0, &mut text);
+        return text;
+    }
+
+    fn transcript_append(&self, directory: usize, text: &mut String) {
+        text.push_str("$ ls\n");
+        for name in self.directories[directory].directories.keys() {
+            text.push_str(&format!("dir {}\n", name));
+        }
+        for (name, size) in &self.directories[directory].files {
+            text.push_str(&format!("{} {}\n", size, name));
+        }
+        for (name, sub) in &self.directories[directory].directories {
+            text.push_str(&format!("$ cd {}\n", name));
+            self.transcript_append(*sub, text);
+            text.push_str("$ cd ..\n");
+        }
+    }
+
+    // every path with the size of files, None for directories
+    fn entries(&self) -> std::collections::BTreeMap<String, Option<usize>> {
+        let mut entries: std::collections::BTreeMap<String, Option<usize>> =
+            std::collections::BTreeMap::new();
+        for (directory, d) in self.directories.iter().enumerate() {
+            entries.insert(self.path(directory), None);
+            for (name, size) in &d.files {
+                entries.insert(self.entry_path(directory, name), Some(*size));
+            }
+        }
+        return entries;
+    }
+}
+
+struct JsonReader {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl JsonReader {
+    fn error(&self, message: &str) -> String {
+        return format!("offset {}: {}", self.position, message);
+    }
+
+    fn whitespace_skip(&mut self) {
+        while self.position < self.chars.len() && self.chars[self.position].is_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    // skips whitespace then consumes `c` if it is next
+    fn accept(&mut self, c: char) -> bool {
+        self.whitespace_skip();
+        if self.chars.get(self.position) == Some(&c) {
+            self.position += 1;
+            return true;
+        }
+        return false;
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if !self.accept(c) {
+            return Err(self.error(&format!("expected '{}'", c)));
+        }
+        return Ok(());
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut s: String = String::new();
+        loop {
+            let c: char = match self.chars.get(self.position) {
+                Some(c) => *c,
+                None => return Err(self.error("unterminated string")),
+            };
+            self.position += 1;
+            match c {
+                '"' => return Ok(s),
+                '\\' => {
+                    let escaped: Option<&char> = self.chars.get(self.position);
+                    self.position += 1;
+                    match escaped {
+                        Some('"') => s.push('"'),
+                        Some('\\') => s.push('\\'),
+                        Some('/') => s.push('/'),
+                        Some('n') => s.push('\n'),
+                        Some('t') => s.push('\t'),
+                        Some('r') => s.push('\r'),
+                        Some('u') => {
+                            let hex: String = self
+                                .chars
+                                .get(self.position..self.position + 4)
+                                .unwrap_or(&[])
+                                .iter()
+                                .collect();
+                            match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                                Some(c) if hex.len() == 4 => s.push(c),
+                                _ => return Err(self.error("invalid unicode escape")),
+                            }
+                            self.position += 4;
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    }
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<usize, String> {
+        self.whitespace_skip();
+        let start: usize = self.position;
+        while self.position < self.chars.len() && self.chars[self.position].is_ascii_digit() {
+            self.position += 1;
+        }
+        let digits: String = self.chars[start..self.position].iter().collect();
+        return digits
+            .parse::<usize>()
+            .map_err(|_| self.error("expected a file size"));
+    }
+
+    // calls `on_member` for every key of an object, positioned on its value
+    fn object(
+        &mut self,
+        on_member: &mut dyn FnMut(&mut JsonReader, String) -> Result<(), String>,
+    ) -> Result<(), String> {
+        self.expect('{')?;
+        if self.accept('}') {
+            return Ok(());
+        }
+        loop {
+            let key: String = self.string()?;
+            self.expect(':')?;
+            on_member(self, key)?;
+            if self.accept('}') {
+                return Ok(());
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn directory(&mut self, fs: &mut FileSystem, directory: usize) -> Result<(), String> {
+        return self.object(&mut |reader, key| match key.as_str() {
+            "files" => reader.object(&mut |reader, name| {
+                let size: usize = reader.number()?;
+                if fs.directories[directory].directories.contains_key(&name)
+                    || fs.directories[directory]
+                        .files
+                        .insert(name.clone(), size)
+                        .is_some()
+                {
+                    return Err(
+                        reader.error(&format!("{} listed twice", fs.entry_path(directory, &name)))
+                    );
+                }
+                return Ok(());
+            }),
+            "directories" => reader.object(&mut |reader, name| {
+                if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+                    return Err(reader.error(&format!("invalid directory '{}'", name)));
+                }
+                if fs.directories[directory].directories.contains_key(&name)
+                    || fs.directories[directory].files.contains_key(&name)
+                {
+                    return Err(
+                        reader.error(&format!("{} listed twice", fs.entry_path(directory, &name)))
+                    );
+                }
+                let sub: usize = fs.directory_add(directory, &name);
+                return reader.directory(fs, sub);
+            }),
+            _ => Err(reader.error(&format!("unknown key '{}'", key))),
+        });
+    }
+}
+
+fn filesystem_from_json(json: &str) -> Result<FileSystem, String> {
+    let mut fs: FileSystem = FileSystem::new();
+    let mut reader: JsonReader = JsonReader {
+        chars: json.chars().collect(),
+        position: 0,
+    };
+    reader.directory(&mut fs, 0)?;
+    reader.whitespace_skip();
+    if reader.position != reader.chars.len() {
+        return Err(reader.error("trailing characters"));
+    }
+    return Ok(fs);
+}
+
+// snapshot of an actual directory, symbolic links are not followed
+fn filesystem_from_directory(root: &std::path::Path) -> Result<FileSystem, String> {
+    let mut fs: FileSystem = FileSystem::new();
+    directory_walk(&mut fs, 0, root)?;
+    return Ok(fs);
+}
+
+fn directory_walk(
+    fs: &mut FileSystem,
+    directory: usize,
+    path: &std::path::Path,
+) -> Result<(), String> {
+    let entries = std::fs::read_dir(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    for entry in entries {
+        let entry: std::fs::DirEntry = entry.map_err(|e| format!("{}: {}", path.display(), e))?;
+        let name: String = match entry.file_name().into_string() {
+            Ok(name) if !name.contains('\n') => name,
+            _ => {
+                return Err(format!(
+                    "{}: name can't be written in a transcript",
+                    entry.path().display()
+                ))
+            }
+        };
+        let file_type: std::fs::FileType = entry
+            .file_type()
+            .map_err(|e| format!("{}: {}", entry.path().display(), e))?;
+        if file_type.is_dir() {
+            let sub: usize = fs.directory_add(directory, &name);
+            directory_walk(fs, sub, &entry.path())?;
+        } else if file_type.is_file() {
+            let metadata: std::fs::Metadata = entry
+                .metadata()
+                .map_err(|e| format!("{}: {}", entry.path().display(), e))?;
+            fs.directories[directory]
+                .files
+                .insert(name, metadata.len() as usize);
+        }
+    }
+    return Ok(());
+}
+
+// JSON export or terminal log
+fn filesystem_parse(input: &str) -> Result<FileSystem, String> {
+    if input.trim_start().starts_with('{') {
+        return filesystem_from_json(input);
+    }
+    return filesystem_build(input);
+}
+
+// "+" added, "-" removed, "~" file size changed, directories appear as "path/"
+fn filesystem_diff(old: &FileSystem, new: &FileSystem) -> Vec<String> {
+    let old_entries: std::collections::BTreeMap<String, Option<usize>> = old.entries();
+    let new_entries: std::collections::BTreeMap<String, Option<usize>> = new.entries();
+    let entry_text = |path: &str, size: Option<usize>| -> String {
+        match size {
+            Some(size) => format!("{} ({})", path, size),
+            None => format!("{}/", path.trim_end_matches('/')),
+        }
+    };
+
+    let mut paths: Vec<&String> = old_entries.keys().chain(new_entries.keys()).collect();
+    paths.sort();
+    paths.dedup();
+    let mut changes: Vec<String> = Vec::new();
+    for path in paths {
+        match (old_entries.get(path), new_entries.get(path)) {
+            (Some(a), Some(b)) if a == b => {}
+            (Some(Some(a)), Some(Some(b))) => {
+                changes.push(format!("~ {} ({} -> {})", path, a, b));
+            }
+            (Some(a), Some(b)) => {
+                changes.push(format!("- {}", entry_text(path, *a)));
+                changes.push(format!("+ {}", entry_text(path, *b)));
+            }
+            (Some(a), None) => changes.push(format!("- {}", entry_text(path, *a))),
+            (None, Some(b)) => changes.push(format!("+ {}", entry_text(path, *b))),
+            (None, None) => {}
+        }
+    }
+    return changes;
+}
+
+fn snapshot_load(path: &str) -> FileSystem {
+    let input: String = match std::fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            std::process::exit(1);
+        }
+    };
+    match filesystem_parse(&input) {
+        Ok(fs) => return fs,
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn number_arg(arg: Option<&String>, what: &str) -> usize {
+    match arg.map(|a| a.parse::<usize>()) {
+        Some(Ok(n)) => return n,
+        _ => {
+            eprintln!("expected {}", what);
+            std::process::exit(1);
+        }
+    }
+}
+
+// <report> [ARG] [--space N] [--need N] (`plan [SPACE] [files]` for the deletion planner),
+// reading the terminal log or its JSON export on stdin, or `walk [DIR] [json]` and
+// `diff OLD NEW` which read files
+fn report_run(args: &[String]) {
+    let mut fs_space: usize = FS_SPACE;
+    let mut free_space_need: usize = FREE_SPACE_NEED;
+    let mut positional: Vec<&String> = Vec::new();
+    let mut i: usize = 0;
+    while i < args.len() {
+        if args[i] == "--space" {
+            fs_space = number_arg(args.get(i + 1), "a size after --space");
+            i += 1;
+        } else if args[i] == "--need" {
+            free_space_need = number_arg(args.get(i + 1), "a size after --need");
+            i += 1;
+        } else {
+            positional.push(&args[i]);
+        }
+        i += 1;
+    }
+
+    let report: &str = positional.first().map(|r| r.as_str()).unwrap_or("");
+    if report == "walk" {
+        let root: &str = positional.get(1).map(|p| p.as_str()).unwrap_or(".");
+        let fs: FileSystem = match filesystem_from_directory(std::path::Path::new(root)) {
+            Ok(fs) => fs,
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(1);
+            }
+        };
+        if positional.get(2).map(|p| p.as_str()) == Some("json") {
+            println!("{}", fs.to_json());
+        } else {
+            print!("{}", fs.transcript());
+        }
+        return;
+    }
+    if report == "diff" {
+        if positional.len() != 3 {
+            eprintln!("expected two snapshots to compare");
+            std::process::exit(1);
+        }
+        let old: FileSystem = snapshot_load(positional[1]);
+        let new: FileSystem = snapshot_load(positional[2]);
+        for change in filesystem_diff(&old, &new) {
+            println!("{}", change);
+        }
+        return;
+    }
+
+    // terminal log or JSON export
+    let mut input: String = String::new();
+    std::io::Read::read_to_string(&mut std::io::stdin(), &mut input).unwrap();
+    let fs: FileSystem = match filesystem_parse(&input) {
+        Ok(fs) => fs,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+    for issue in &fs.issues {
+        eprintln!("warning: {}", issue);
+    }
+
+    let text: String = match report {
+        "tree" => fs.tree(),
+        "du" => rows_text(&fs.du()),
+        "largest" => rows_text(
+            &fs.directories_largest(number_arg(positional.get(1).copied(), "a directory count")),
+        ),
+        "larger" => {
+            rows_text(&fs.files_larger(number_arg(positional.get(1).copied(), "a file size")))
+        }
+        "extensions" => extensions_text(&fs.sizes_by_extension()),
+        "json" => format!("{}\n", fs.to_json()),
+        "transcript" => fs.transcript(),
+        "frees" => {
+            let space: usize = match positional.get(1) {
+                Some(space) => number_arg(Some(space), "a size"),
+                None => space_to_free(fs_space, free_space_need, fs.sizes()[0]),
+            };
+            rows_text(&fs.directories_freeing(space))
+        }
+        "plan" => {
+            let space: usize = match positional.get(1).filter(|p| p.as_str() != "files") {
+                Some(space) => number_arg(Some(space), "a size"),
+                None => space_to_free(fs_space, free_space_need, fs.sizes()[0]),
+            };
+            let with_files: bool = positional.last().map(|p| p.as_str()) == Some("files");
+            match deletion_plan(&fs, space, with_files) {
+                Ok(plan) => format!("{}{:>10}  total\n", rows_text(&plan.entries), plan.size),
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            }
+        }
+        report => {
+            eprintln!("unknown report '{}'", report);
+            std::process::exit(1);
+        }
+    };
+    print!("{}", text);
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        report_run(&args);
+        return;
+    }
+
     // example
-    assert_eq!(24933642, find_directory_to_delete("$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k"));
-    assert_eq!(5649896, find_directory_to_delete("$ cd /\n$ ls\ndir cmjgvh\ndir czrzl\ndir fcbt\ndir hdh\n259661 hjsbd.mzp\ndir jgrdd\ndir lqblqtng\ndir pgvmpmn\ndir pqqcvcm\ndir zglbptq\n$ cd cmjgvh\n$ ls\ndir hdh\n134565 hdh.sjv\ndir hgrpfmt\n282147 mjtq.ffd\n42343 rvmzv.rtb\ndir sjgvbd\n31468 wgtjmb.thf\n$ cd hdh\n$ ls\n267125 htplc.gdw\n$ cd ..\n$ cd hgrpfmt\n$ ls\n39132 lndwz\n280595 rffmsvdw\n$ cd ..\n$ cd sjgvbd\n$ ls\n26464 ghg.zmq\n1533 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd czrzl\n$ ls\ndir cmh\n242795 hpgnd.mmt\n157748 hps.ptg\n129797 qjrhbjql.zdc\n18290 sfhrzzcd.hwm\n63141 sngg.vdw\n$ cd cmh\n$ ls\ndir nglsj\ndir szs\n76775 vdpqhvrm.mcz\n$ cd nglsj\n$ ls\n307082 cjvph.fvc\n286825 szs.vpj\n$ cd ..\n$ cd szs\n$ ls\n6233 fgpnnvm\n94070 jjc.szq\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd fcbt\n$ ls\n150417 grncq.brq\n$ cd ..\n$ cd hdh\n$ ls\ndir cljdmh\ndir fptsr\ndir schjz\ndir vrrcrhzs\n$ cd cljdmh\n$ ls\ndir blsrsqz\n21876 blthtcl\n287047 cjvph.fvc\ndir grncq\ndir hdh\ndir jdjps\ndir mgzlprt\ndir mrwc\n33008 pcwnlp.czm\ndir rdtdjb\n135650 smf\n173978 zsgdbd.dmm\n$ cd blsrsqz\n$ ls\n275130 dvqsffcn\n$ cd ..\n$ cd grncq\n$ ls\n213418 bhdpmv.zzt\n153483 grncq.jsf\n$ cd ..\n$ cd hdh\n$ ls\ndir dzrrh\ndir gjjqhfq\n231302 grncq\ndir hdh\ndir jnfczjjt\ndir ljl\ndir pwv\n154659 rqvgf\ndir vdvg\ndir vpdqnv\n$ cd dzrrh\n$ ls\ndir grncq\n$ cd grncq\n$ ls\n288753 grd\n$ cd ..\n$ cd ..\n$ cd gjjqhfq\n$ ls\ndir hdh\ndir mnj\n$ cd hdh\n$ ls\n241795 szs.mhq\n$ cd ..\n$ cd mnj\n$ ls\n200772 sfhrzzcd.hwm\n237342 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd hdh\n$ ls\n30588 qptfqt.ggc\n$ cd ..\n$ cd jnfczjjt\n$ ls\n93796 rqbsqhp.grv\n30301 sfhrzzcd.hwm\n$ cd ..\n$ cd ljl\n$ ls\ndir lvpvmlnb\ndir tsb\n227393 twnj\n$ cd lvpvmlnb\n$ ls\n16586 gnlmdb\n340921 grncq\ndir lqblqtng\ndir nvp\n$ cd lqblqtng\n$ ls\n190888 dfjrnbwq\n79149 dvqsffcn\ndir grncq\n267567 lqblqtng\n169475 vzwm.pnd\n305249 zsgdbd.dmm\n$ cd grncq\n$ ls\n323438 zjpg\n$ cd ..\n$ cd ..\n$ cd nvp\n$ ls\n239588 mffsww.qzc\ndir qqhwjn\n208451 qrjv.lns\n34563 sfhrzzcd.hwm\n$ cd qqhwjn\n$ ls\n47343 wcpqgvh.cfl\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd tsb\n$ ls\ndir lqblqtng\n40229 szs\n214138 whfw\n$ cd lqblqtng\n$ ls\n16005 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd pwv\n$ ls\ndir hzwljqm\n51565 pjvpm\n$ cd hzwljqm\n$ ls\n106132 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd vdvg\n$ ls\n267870 zgm.wbw\n$ cd ..\n$ cd vpdqnv\n$ ls\ndir cmmc\n239093 dvqsffcn\ndir rhgb\n142265 sfhrzzcd.hwm\ndir szs\n$ cd cmmc\n$ ls\ndir pjhhbggb\n$ cd pjhhbggb\n$ ls\n195797 psqml.cjl\n$ cd ..\n$ cd ..\n$ cd rhgb\n$ ls\n102805 qrnntf\n$ cd ..\n$ cd szs\n$ ls\n314078 tcslpbc\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd jdjps\n$ ls\n12330 twnj\n$ cd ..\n$ cd mgzlprt\n$ ls\n15552 gzthzjsr\ndir hdh\ndir qrfvn\ndir twnj\n331113 zsgdbd.dmm\n$ cd hdh\n$ ls\ndir cfdrnjsg\n343470 zsgdbd.dmm\n$ cd cfdrnjsg\n$ ls\ndir gddbd\n309726 grncq.gmr\ndir vzdfj\n$ cd gddbd\n$ ls\n226040 cjvph.fvc\n47672 twnj\n67109 zsgdbd.dmm\n$ cd ..\n$ cd vzdfj\n$ ls\n29653 grncq\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd qrfvn\n$ ls\n74136 lqblqtng\ndir mjjrz\ndir zrsz\n$ cd mjjrz\n$ ls\n27688 fgpnnvm\n$ cd ..\n$ cd zrsz\n$ ls\n9100 wbrgdtv\n$ cd ..\n$ cd ..\n$ cd twnj\n$ ls\n41216 cjvph.fvc\n250320 wnhjfm\n$ cd ..\n$ cd ..\n$ cd mrwc\n$ ls\ndir bjwnmw\n116091 grncq.phw\n24868 hdh.lwn\n29567 qsrtrvr.jbw\n100251 szs.dvg\n$ cd bjwnmw\n$ ls\n128769 rdpsnm\n$ cd ..\n$ cd ..\n$ cd rdtdjb\n$ ls\n132588 gsgjr\n149600 lqblqtng.nnr\n179302 wfbqblml.tgc\n76170 wfrcm.fvp\n$ cd ..\n$ cd ..\n$ cd fptsr\n$ ls\n169594 lzlcml.mgm\ndir zrc\ndir ztd\n$ cd zrc\n$ ls\n110339 gjpgwrcm.lhg\n$ cd ..\n$ cd ztd\n$ ls\n203770 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd schjz\n$ ls\n3212 cjvph.fvc\n288619 jcltshwj\n295116 qmbp.mpd\n$ cd ..\n$ cd vrrcrhzs\n$ ls\ndir twnj\n$ cd twnj\n$ ls\n70492 dvqsffcn\n44411 mhgspcgz\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd jgrdd\n$ ls\n325202 dvqsffcn\n$ cd ..\n$ cd lqblqtng\n$ ls\ndir bbzv\ndir gzqqp\ndir vgt\n$ cd bbzv\n$ ls\n326190 fgpnnvm\n$ cd ..\n$ cd gzqqp\n$ ls\n179929 crn.vpf\n263365 dvqsffcn\n251298 hdh.rsh\ndir jgrtqpv\n62514 lqblqtng.spm\ndir njdhqsvj\n32894 sfhrzzcd.hwm\n$ cd jgrtqpv\n$ ls\ndir bmvfszlz\n286549 zsgdbd.dmm\n$ cd bmvfszlz\n$ ls\n169838 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd njdhqsvj\n$ ls\n2729 bgd\n294119 fgcfbrdz.bll\n$ cd ..\n$ cd ..\n$ cd vgt\n$ ls\ndir btf\ndir csgvtvsq\ndir czbhw\ndir jqnvscr\ndir nqp\ndir rlvnnmh\ndir tmf\ndir twjtnhll\n$ cd btf\n$ ls\n333891 cjvph.fvc\n$ cd ..\n$ cd csgvtvsq\n$ ls\ndir ccrvn\ndir szs\ndir tsmqm\ndir tssfbq\n$ cd ccrvn\n$ ls\n14614 qbl.rjg\n$ cd ..\n$ cd szs\n$ ls\n92863 vswznzs\n$ cd ..\n$ cd tsmqm\n$ ls\n206954 dncrjt.sch\n252714 zsgdbd.dmm\n64306 zzr.snv\n$ cd ..\n$ cd tssfbq\n$ ls\n326310 chffstg.qcr\n51533 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd czbhw\n$ ls\n290187 bdn.vjp\n185832 cjvph.fvc\ndir lqblqtng\n198843 twnj.flf\n64179 zjwg.jwc\n256134 zrtmfn\n$ cd lqblqtng\n$ ls\n82035 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd jqnvscr\n$ ls\ndir bgqf\n255437 dvqsffcn\n147384 fzwzqb\ndir gbf\ndir gqzf\ndir grncq\n287135 grncq.pcg\n333854 hqw.hgc\ndir nzrsswd\ndir svmqtq\n$ cd bgqf\n$ ls\n325037 jsv\n$ cd ..\n$ cd gbf\n$ ls\n274259 fgpnnvm\n$ cd ..\n$ cd gqzf\n$ ls\n224386 hwwqrq.hcz\n$ cd ..\n$ cd grncq\n$ ls\ndir bvsfn\ndir gcbzghtz\n100460 njbgfrg\n210677 phnslpqc.rbf\ndir tgz\n95034 vdpbwsn\ndir vsrnbb\n$ cd bvsfn\n$ ls\n184581 lwj.gvm\ndir pszwl\n129220 sfhrzzcd.hwm\n162987 smdvbnrq.zjd\n71207 tspgccr.gdf\n323030 zsgdbd.dmm\n$ cd pszwl\n$ ls\ndir szs\ndir twnj\n$ cd szs\n$ ls\n341367 cbcvvtg.hcg\n112908 rggrhm\n$ cd ..\n$ cd twnj\n$ ls\n29609 sfhrzzcd.hwm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd gcbzghtz\n$ ls\n139868 scggzqr.bzw\n268881 smlhjltf.rwr\n247122 wbwzdpg.djs\n$ cd ..\n$ cd tgz\n$ ls\n214842 grncq\ndir njffnbp\ndir sfvsdzs\ndir sslvmwt\n212789 szqph.gmw\n$ cd njffnbp\n$ ls\n243579 lmwd.mgz\n$ cd ..\n$ cd sfvsdzs\n$ ls\n7846 nthbtv.zdp\n$ cd ..\n$ cd sslvmwt\n$ ls\n241215 szs.rlb\n$ cd ..\n$ cd ..\n$ cd vsrnbb\n$ ls\n69152 cjvph.fvc\ndir dvjmd\n142844 dvqsffcn\n242145 msm.nlf\ndir nttvm\ndir rjfjwbsm\n47597 twnj.lft\n123883 zsgdbd.dmm\n$ cd dvjmd\n$ ls\ndir lnhr\n70772 qvs.gmv\n80548 twnj.jdf\n$ cd lnhr\n$ ls\n202959 hdh\n$ cd ..\n$ cd ..\n$ cd nttvm\n$ ls\ndir hdh\n$ cd hdh\n$ ls\n178915 fzgzdpbv.jdj\n$ cd ..\n$ cd ..\n$ cd rjfjwbsm\n$ ls\n114145 grncq\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd nzrsswd\n$ ls\ndir bnrj\n17967 cjvph.fvc\n237712 fgpnnvm\ndir spqqqd\ndir szs\n96651 twnj\n6447 twnj.dft\n$ cd bnrj\n$ ls\n40924 cbhq.wpc\n175930 cjvph.fvc\ndir grncq\ndir lfrtwv\n267323 mmq.zwz\ndir qsbmrs\ndir wpsj\n$ cd grncq\n$ ls\n151440 ctmwl\n83350 lqblqtng\n145599 tpqfd.zfv\n$ cd ..\n$ cd lfrtwv\n$ ls\ndir cdzvst\n144562 fgpnnvm\ndir pllhlhr\ndir qvdlrsw\n$ cd cdzvst\n$ ls\n211524 dvsnph.hrf\ndir wchrhl\n$ cd wchrhl\n$ ls\n73032 lntqbfl.nbz\n$ cd ..\n$ cd ..\n$ cd pllhlhr\n$ ls\n70197 dtccz\n$ cd ..\n$ cd qvdlrsw\n$ ls\ndir gdmppfzv\n$ cd gdmppfzv\n$ ls\n244356 nhnsd\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd qsbmrs\n$ ls\n155216 cvt.bdn\ndir gcvlpg\ndir hdh\n$ cd gcvlpg\n$ ls\n306542 bwnfl.rsl\n67067 cjvph.fvc\n341913 zsgdbd.dmm\n$ cd ..\n$ cd hdh\n$ ls\n138120 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd wpsj\n$ ls\n301391 sfhrzzcd.hwm\n$ cd ..\n$ cd ..\n$ cd spqqqd\n$ ls\n40806 dvqsffcn\ndir gqsbsmfm\n41886 grncq.jqr\ndir pdfsb\n44167 pnq.nfr\n127242 twnj.chn\n125868 vvgzv.rmv\n$ cd gqsbsmfm\n$ ls\n99936 dvqsffcn\n$ cd ..\n$ cd pdfsb\n$ ls\n253190 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd szs\n$ ls\n164955 vswnnw\n$ cd ..\n$ cd ..\n$ cd svmqtq\n$ ls\ndir grncq\n$ cd grncq\n$ ls\n274192 hjdfj.qnw\ndir tcrhb\ndir twnj\n$ cd tcrhb\n$ ls\n64293 grncq.jvh\n$ cd ..\n$ cd twnj\n$ ls\n149048 cjvph.fvc\n192053 rdgv\n101473 zct.rmf\n280169 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd nqp\n$ ls\n107620 ndgz.gwb\n$ cd ..\n$ cd rlvnnmh\n$ ls\n27340 gcpcffp.fqg\n$ cd ..\n$ cd tmf\n$ ls\n193196 fgpnnvm\n22126 gwftf.wcr\n92461 hdh\n50807 qbdmzjd.jvg\ndir qrrmhwn\ndir rdcsmpfm\ndir rgl\n36742 zsgdbd.dmm\n$ cd qrrmhwn\n$ ls\ndir jgqzqhdc\n109318 vtrtz.zvh\ndir zmbtd\n$ cd jgqzqhdc\n$ ls\ndir zsdbppb\n$ cd zsdbppb\n$ ls\n325921 fgpnnvm\n156452 tlcs.vzz\n$ cd ..\n$ cd ..\n$ cd zmbtd\n$ ls\n93641 bpzttjt\ndir grncq\ndir hdh\n7832 shl.gbz\n95398 twnj.fsd\n$ cd grncq\n$ ls\ndir qshl\n$ cd qshl\n$ ls\n312490 stqg.gwj\n$ cd ..\n$ cd ..\n$ cd hdh\n$ ls\n225454 sfhrzzcd.hwm\n265069 twnj.hfs\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd rdcsmpfm\n$ ls\n72657 dvqsffcn\n308572 fgpnnvm\ndir hdh\ndir lqblqtng\n180474 lsgjgsrb.jfm\n67774 ptpjjwc.bqn\n$ cd hdh\n$ ls\n19858 sfhrzzcd.hwm\n$ cd ..\n$ cd lqblqtng\n$ ls\n142164 cjvph.fvc\ndir jpbh\ndir prwnwvp\ndir qbnthms\ndir sthfhjf\n$ cd jpbh\n$ ls\n167969 fgpnnvm\n182107 jfzrww.tgb\n$ cd ..\n$ cd prwnwvp\n$ ls\ndir cczhv\n70167 dvqsffcn\ndir grncq\ndir mbjwpdb\ndir nll\ndir qjjgjzbl\n101178 qtqgcj\n113714 sfhrzzcd.hwm\n$ cd cczhv\n$ ls\n158391 fmwzpjdm.nwz\n$ cd ..\n$ cd grncq\n$ ls\n238901 hdh\n188528 mjwcd.bqt\n61037 wpfg.shs\n$ cd ..\n$ cd mbjwpdb\n$ ls\ndir bphnpft\n72033 fgpnnvm\ndir hdh\ndir qdmjnqc\ndir rplsvs\n85983 tpfjp\n303863 wzwvbnw.blb\n$ cd bphnpft\n$ ls\n274331 gmmmlsj.pwp\n$ cd ..\n$ cd hdh\n$ ls\n154778 szs.dtw\n$ cd ..\n$ cd qdmjnqc\n$ ls\n133639 grncq.wnj\n$ cd ..\n$ cd rplsvs\n$ ls\n332906 zpstcbj.zvt\n$ cd ..\n$ cd ..\n$ cd nll\n$ ls\n82368 cjvph.fvc\n278280 ctmnzd\ndir hdh\ndir lqblqtng\n211682 pshd.fds\n319207 sfhrzzcd.hwm\n46873 vrf.fgg\n$ cd hdh\n$ ls\ndir hdtg\n$ cd hdtg\n$ ls\n19779 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd lqblqtng\n$ ls\n301792 dmhslp.hvz\n305639 dvqsffcn\n321833 strqtwrw\n112471 twnj.fbn\n20731 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd qjjgjzbl\n$ ls\n78166 sztm.zrz\n$ cd ..\n$ cd ..\n$ cd qbnthms\n$ ls\n158365 bvwjvrvr.bvb\n$ cd ..\n$ cd sthfhjf\n$ ls\n239051 dvqsffcn\ndir ghgcfwg\n327346 glfcswq.cqc\ndir tjqhqs\n124072 twnj.cbn\n261890 zsgdbd.dmm\n28917 zvbn.rbz\n$ cd ghgcfwg\n$ ls\ndir szs\n$ cd szs\n$ ls\n29912 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd tjqhqs\n$ ls\n125555 twnj.dnv\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd rgl\n$ ls\ndir grncq\n59380 szs.jvh\ndir wjjzprnz\n$ cd grncq\n$ ls\n283850 lqblqtng.cdf\n$ cd ..\n$ cd wjjzprnz\n$ ls\n339632 hbg.vpp\n15887 zglntj.qtt\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd twjtnhll\n$ ls\n207430 cjvph.fvc\ndir dnv\ndir hdh\n232881 wwp\n$ cd dnv\n$ ls\n215966 lqblqtng.tdf\n39850 szb.fqn\n45436 twnj\n$ cd ..\n$ cd hdh\n$ ls\ndir grrs\n288906 hvjv\n219092 mnr.qhg\n336470 rwh\n145474 sfhrzzcd.hwm\n24384 szs.fwn\ndir twnj\n$ cd grrs\n$ ls\n94496 dvqsffcn\n$ cd ..\n$ cd twnj\n$ ls\ndir sbhbnsw\n$ cd sbhbnsw\n$ ls\n162705 sfhrzzcd.hwm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd pgvmpmn\n$ ls\n18511 sfhrzzcd.hwm\n$ cd ..\n$ cd pqqcvcm\n$ ls\n201925 lqblqtng.mwb\n300811 scjclwwz.bng\n$ cd ..\n$ cd zglbptq\n$ ls\ndir brhmv\n237267 grncq\ndir hdh\ndir mhnfrn\n131601 qtjwj\n69185 tdtgmgw.qwc\n96764 vppqqpf.znc\n$ cd brhmv\n$ ls\ndir ddd\ndir dpnghvpp\n102457 grncq\n239654 jtvzhvd.jvm\n276919 sfhrzzcd.hwm\n235776 zsgdbd.dmm\n$ cd ddd\n$ ls\ndir grncq\ndir mbrcgcd\n$ cd grncq\n$ ls\n110323 fgpnnvm\n$ cd ..\n$ cd mbrcgcd\n$ ls\n100317 pbb.hcb\n$ cd ..\n$ cd ..\n$ cd dpnghvpp\n$ ls\n259305 bscmwtd.mlw\ndir hdh\n$ cd hdh\n$ ls\n49708 grncq.zdl\ndir szs\n$ cd szs\n$ ls\n225139 fgpnnvm\n258801 twnj.nrj\ndir wwzpvb\n$ cd wwzpvb\n$ ls\n4309 qlddd.cps\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd hdh\n$ ls\n24775 hdh.htz\n238695 jzpvhl\n$ cd ..\n$ cd mhnfrn\n$ ls\n30250 sfhrzzcd.hwm\ndir twnj\n$ cd twnj\n$ ls\n244061 qddtlr.mpf"));
+    let example: &str = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
+    assert_eq!(
+        Ok(24933642),
+        find_directory_to_delete(FS_SPACE, FREE_SPACE_NEED, example)
+    );
+    assert_eq!(Ok(5649896), find_directory_to_delete(FS_SPACE, FREE_SPACE_NEED, "$ cd /\n$ ls\ndir cmjgvh\ndir czrzl\ndir fcbt\ndir hdh\n259661 hjsbd.mzp\ndir jgrdd\ndir lqblqtng\ndir pgvmpmn\ndir pqqcvcm\ndir zglbptq\n$ cd cmjgvh\n$ ls\ndir hdh\n134565 hdh.sjv\ndir hgrpfmt\n282147 mjtq.ffd\n42343 rvmzv.rtb\ndir sjgvbd\n31468 wgtjmb.thf\n$ cd hdh\n$ ls\n267125 htplc.gdw\n$ cd ..\n$ cd hgrpfmt\n$ ls\n39132 lndwz\n280595 rffmsvdw\n$ cd ..\n$ cd sjgvbd\n$ ls\n26464 ghg.zmq\n1533 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd czrzl\n$ ls\ndir cmh\n242795 hpgnd.mmt\n157748 hps.ptg\n129797 qjrhbjql.zdc\n18290 sfhrzzcd.hwm\n63141 sngg.vdw\n$ cd cmh\n$ ls\ndir nglsj\ndir szs\n76775 vdpqhvrm.mcz\n$ cd nglsj\n$ ls\n307082 cjvph.fvc\n286825 szs.vpj\n$ cd ..\n$ cd szs\n$ ls\n6233 fgpnnvm\n94070 jjc.szq\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd fcbt\n$ ls\n150417 grncq.brq\n$ cd ..\n$ cd hdh\n$ ls\ndir cljdmh\ndir fptsr\ndir schjz\ndir vrrcrhzs\n$ cd cljdmh\n$ ls\ndir blsrsqz\n21876 blthtcl\n287047 cjvph.fvc\ndir grncq\ndir hdh\ndir jdjps\ndir mgzlprt\ndir mrwc\n33008 pcwnlp.czm\ndir rdtdjb\n135650 smf\n173978 zsgdbd.dmm\n$ cd blsrsqz\n$ ls\n275130 dvqsffcn\n$ cd ..\n$ cd grncq\n$ ls\n213418 bhdpmv.zzt\n153483 grncq.jsf\n$ cd ..\n$ cd hdh\n$ ls\ndir dzrrh\ndir gjjqhfq\n231302 grncq\ndir hdh\ndir jnfczjjt\ndir ljl\ndir pwv\n154659 rqvgf\ndir vdvg\ndir vpdqnv\n$ cd dzrrh\n$ ls\ndir grncq\n$ cd grncq\n$ ls\n288753 grd\n$ cd ..\n$ cd ..\n$ cd gjjqhfq\n$ ls\ndir hdh\ndir mnj\n$ cd hdh\n$ ls\n241795 szs.mhq\n$ cd ..\n$ cd mnj\n$ ls\n200772 sfhrzzcd.hwm\n237342 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd hdh\n$ ls\n30588 qptfqt.ggc\n$ cd ..\n$ cd jnfczjjt\n$ ls\n93796 rqbsqhp.grv\n30301 sfhrzzcd.hwm\n$ cd ..\n$ cd ljl\n$ ls\ndir lvpvmlnb\ndir tsb\n227393 twnj\n$ cd lvpvmlnb\n$ ls\n16586 gnlmdb\n340921 grncq\ndir lqblqtng\ndir nvp\n$ cd lqblqtng\n$ ls\n190888 dfjrnbwq\n79149 dvqsffcn\ndir grncq\n267567 lqblqtng\n169475 vzwm.pnd\n305249 zsgdbd.dmm\n$ cd grncq\n$ ls\n323438 zjpg\n$ cd ..\n$ cd ..\n$ cd nvp\n$ ls\n239588 mffsww.qzc\ndir qqhwjn\n208451 qrjv.lns\n34563 sfhrzzcd.hwm\n$ cd qqhwjn\n$ ls\n47343 wcpqgvh.cfl\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd tsb\n$ ls\ndir lqblqtng\n40229 szs\n214138 whfw\n$ cd lqblqtng\n$ ls\n16005 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd pwv\n$ ls\ndir hzwljqm\n51565 pjvpm\n$ cd hzwljqm\n$ ls\n106132 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd vdvg\n$ ls\n267870 zgm.wbw\n$ cd ..\n$ cd vpdqnv\n$ ls\ndir cmmc\n239093 dvqsffcn\ndir rhgb\n142265 sfhrzzcd.hwm\ndir szs\n$ cd cmmc\n$ ls\ndir pjhhbggb\n$ cd pjhhbggb\n$ ls\n195797 psqml.cjl\n$ cd ..\n$ cd ..\n$ cd rhgb\n$ ls\n102805 qrnntf\n$ cd ..\n$ cd szs\n$ ls\n314078 tcslpbc\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd jdjps\n$ ls\n12330 twnj\n$ cd ..\n$ cd mgzlprt\n$ ls\n15552 gzthzjsr\ndir hdh\ndir qrfvn\ndir twnj\n331113 zsgdbd.dmm\n$ cd hdh\n$ ls\ndir cfdrnjsg\n343470 zsgdbd.dmm\n$ cd cfdrnjsg\n$ ls\ndir gddbd\n309726 grncq.gmr\ndir vzdfj\n$ cd gddbd\n$ ls\n226040 cjvph.fvc\n47672 twnj\n67109 zsgdbd.dmm\n$ cd ..\n$ cd vzdfj\n$ ls\n29653 grncq\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd qrfvn\n$ ls\n74136 lqblqtng\ndir mjjrz\ndir zrsz\n$ cd mjjrz\n$ ls\n27688 fgpnnvm\n$ cd ..\n$ cd zrsz\n$ ls\n9100 wbrgdtv\n$ cd ..\n$ cd ..\n$ cd twnj\n$ ls\n41216 cjvph.fvc\n250320 wnhjfm\n$ cd ..\n$ cd ..\n$ cd mrwc\n$ ls\ndir bjwnmw\n116091 grncq.phw\n24868 hdh.lwn\n29567 qsrtrvr.jbw\n100251 szs.dvg\n$ cd bjwnmw\n$ ls\n128769 rdpsnm\n$ cd ..\n$ cd ..\n$ cd rdtdjb\n$ ls\n132588 gsgjr\n149600 lqblqtng.nnr\n179302 wfbqblml.tgc\n76170 wfrcm.fvp\n$ cd ..\n$ cd ..\n$ cd fptsr\n$ ls\n169594 lzlcml.mgm\ndir zrc\ndir ztd\n$ cd zrc\n$ ls\n110339 gjpgwrcm.lhg\n$ cd ..\n$ cd ztd\n$ ls\n203770 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd schjz\n$ ls\n3212 cjvph.fvc\n288619 jcltshwj\n295116 qmbp.mpd\n$ cd ..\n$ cd vrrcrhzs\n$ ls\ndir twnj\n$ cd twnj\n$ ls\n70492 dvqsffcn\n44411 mhgspcgz\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd jgrdd\n$ ls\n325202 dvqsffcn\n$ cd ..\n$ cd lqblqtng\n$ ls\ndir bbzv\ndir gzqqp\ndir vgt\n$ cd bbzv\n$ ls\n326190 fgpnnvm\n$ cd ..\n$ cd gzqqp\n$ ls\n179929 crn.vpf\n263365 dvqsffcn\n251298 hdh.rsh\ndir jgrtqpv\n62514 lqblqtng.spm\ndir njdhqsvj\n32894 sfhrzzcd.hwm\n$ cd jgrtqpv\n$ ls\ndir bmvfszlz\n286549 zsgdbd.dmm\n$ cd bmvfszlz\n$ ls\n169838 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd njdhqsvj\n$ ls\n2729 bgd\n294119 fgcfbrdz.bll\n$ cd ..\n$ cd ..\n$ cd vgt\n$ ls\ndir btf\ndir csgvtvsq\ndir czbhw\ndir jqnvscr\ndir nqp\ndir rlvnnmh\ndir tmf\ndir twjtnhll\n$ cd btf\n$ ls\n333891 cjvph.fvc\n$ cd ..\n$ cd csgvtvsq\n$ ls\ndir ccrvn\ndir szs\ndir tsmqm\ndir tssfbq\n$ cd ccrvn\n$ ls\n14614 qbl.rjg\n$ cd ..\n$ cd szs\n$ ls\n92863 vswznzs\n$ cd ..\n$ cd tsmqm\n$ ls\n206954 dncrjt.sch\n252714 zsgdbd.dmm\n64306 zzr.snv\n$ cd ..\n$ cd tssfbq\n$ ls\n326310 chffstg.qcr\n51533 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd czbhw\n$ ls\n290187 bdn.vjp\n185832 cjvph.fvc\ndir lqblqtng\n198843 twnj.flf\n64179 zjwg.jwc\n256134 zrtmfn\n$ cd lqblqtng\n$ ls\n82035 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd jqnvscr\n$ ls\ndir bgqf\n255437 dvqsffcn\n147384 fzwzqb\ndir gbf\ndir gqzf\ndir grncq\n287135 grncq.pcg\n333854 hqw.hgc\ndir nzrsswd\ndir svmqtq\n$ cd bgqf\n$ ls\n325037 jsv\n$ cd ..\n$ cd gbf\n$ ls\n274259 fgpnnvm\n$ cd ..\n$ cd gqzf\n$ ls\n224386 hwwqrq.hcz\n$ cd ..\n$ cd grncq\n$ ls\ndir bvsfn\ndir gcbzghtz\n100460 njbgfrg\n210677 phnslpqc.rbf\ndir tgz\n95034 vdpbwsn\ndir vsrnbb\n$ cd bvsfn\n$ ls\n184581 lwj.gvm\ndir pszwl\n129220 sfhrzzcd.hwm\n162987 smdvbnrq.zjd\n71207 tspgccr.gdf\n323030 zsgdbd.dmm\n$ cd pszwl\n$ ls\ndir szs\ndir twnj\n$ cd szs\n$ ls\n341367 cbcvvtg.hcg\n112908 rggrhm\n$ cd ..\n$ cd twnj\n$ ls\n29609 sfhrzzcd.hwm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd gcbzghtz\n$ ls\n139868 scggzqr.bzw\n268881 smlhjltf.rwr\n247122 wbwzdpg.djs\n$ cd ..\n$ cd tgz\n$ ls\n214842 grncq\ndir njffnbp\ndir sfvsdzs\ndir sslvmwt\n212789 szqph.gmw\n$ cd njffnbp\n$ ls\n243579 lmwd.mgz\n$ cd ..\n$ cd sfvsdzs\n$ ls\n7846 nthbtv.zdp\n$ cd ..\n$ cd sslvmwt\n$ ls\n241215 szs.rlb\n$ cd ..\n$ cd ..\n$ cd vsrnbb\n$ ls\n69152 cjvph.fvc\ndir dvjmd\n142844 dvqsffcn\n242145 msm.nlf\ndir nttvm\ndir rjfjwbsm\n47597 twnj.lft\n123883 zsgdbd.dmm\n$ cd dvjmd\n$ ls\ndir lnhr\n70772 qvs.gmv\n80548 twnj.jdf\n$ cd lnhr\n$ ls\n202959 hdh\n$ cd ..\n$ cd ..\n$ cd nttvm\n$ ls\ndir hdh\n$ cd hdh\n$ ls\n178915 fzgzdpbv.jdj\n$ cd ..\n$ cd ..\n$ cd rjfjwbsm\n$ ls\n114145 grncq\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd nzrsswd\n$ ls\ndir bnrj\n17967 cjvph.fvc\n237712 fgpnnvm\ndir spqqqd\ndir szs\n96651 twnj\n6447 twnj.dft\n$ cd bnrj\n$ ls\n40924 cbhq.wpc\n175930 cjvph.fvc\ndir grncq\ndir lfrtwv\n267323 mmq.zwz\ndir qsbmrs\ndir wpsj\n$ cd grncq\n$ ls\n151440 ctmwl\n83350 lqblqtng\n145599 tpqfd.zfv\n$ cd ..\n$ cd lfrtwv\n$ ls\ndir cdzvst\n144562 fgpnnvm\ndir pllhlhr\ndir qvdlrsw\n$ cd cdzvst\n$ ls\n211524 dvsnph.hrf\ndir wchrhl\n$ cd wchrhl\n$ ls\n73032 lntqbfl.nbz\n$ cd ..\n$ cd ..\n$ cd pllhlhr\n$ ls\n70197 dtccz\n$ cd ..\n$ cd qvdlrsw\n$ ls\ndir gdmppfzv\n$ cd gdmppfzv\n$ ls\n244356 nhnsd\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd qsbmrs\n$ ls\n155216 cvt.bdn\ndir gcvlpg\ndir hdh\n$ cd gcvlpg\n$ ls\n306542 bwnfl.rsl\n67067 cjvph.fvc\n341913 zsgdbd.dmm\n$ cd ..\n$ cd hdh\n$ ls\n138120 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd wpsj\n$ ls\n301391 sfhrzzcd.hwm\n$ cd ..\n$ cd ..\n$ cd spqqqd\n$ ls\n40806 dvqsffcn\ndir gqsbsmfm\n41886 grncq.jqr\ndir pdfsb\n44167 pnq.nfr\n127242 twnj.chn\n125868 vvgzv.rmv\n$ cd gqsbsmfm\n$ ls\n99936 dvqsffcn\n$ cd ..\n$ cd pdfsb\n$ ls\n253190 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd szs\n$ ls\n164955 vswnnw\n$ cd ..\n$ cd ..\n$ cd svmqtq\n$ ls\ndir grncq\n$ cd grncq\n$ ls\n274192 hjdfj.qnw\ndir tcrhb\ndir twnj\n$ cd tcrhb\n$ ls\n64293 grncq.jvh\n$ cd ..\n$ cd twnj\n$ ls\n149048 cjvph.fvc\n192053 rdgv\n101473 zct.rmf\n280169 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd nqp\n$ ls\n107620 ndgz.gwb\n$ cd ..\n$ cd rlvnnmh\n$ ls\n27340 gcpcffp.fqg\n$ cd ..\n$ cd tmf\n$ ls\n193196 fgpnnvm\n22126 gwftf.wcr\n92461 hdh\n50807 qbdmzjd.jvg\ndir qrrmhwn\ndir rdcsmpfm\ndir rgl\n36742 zsgdbd.dmm\n$ cd qrrmhwn\n$ ls\ndir jgqzqhdc\n109318 vtrtz.zvh\ndir zmbtd\n$ cd jgqzqhdc\n$ ls\ndir zsdbppb\n$ cd zsdbppb\n$ ls\n325921 fgpnnvm\n156452 tlcs.vzz\n$ cd ..\n$ cd ..\n$ cd zmbtd\n$ ls\n93641 bpzttjt\ndir grncq\ndir hdh\n7832 shl.gbz\n95398 twnj.fsd\n$ cd grncq\n$ ls\ndir qshl\n$ cd qshl\n$ ls\n312490 stqg.gwj\n$ cd ..\n$ cd ..\n$ cd hdh\n$ ls\n225454 sfhrzzcd.hwm\n265069 twnj.hfs\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd rdcsmpfm\n$ ls\n72657 dvqsffcn\n308572 fgpnnvm\ndir hdh\ndir lqblqtng\n180474 lsgjgsrb.jfm\n67774 ptpjjwc.bqn\n$ cd hdh\n$ ls\n19858 sfhrzzcd.hwm\n$ cd ..\n$ cd lqblqtng\n$ ls\n142164 cjvph.fvc\ndir jpbh\ndir prwnwvp\ndir qbnthms\ndir sthfhjf\n$ cd jpbh\n$ ls\n167969 fgpnnvm\n182107 jfzrww.tgb\n$ cd ..\n$ cd prwnwvp\n$ ls\ndir cczhv\n70167 dvqsffcn\ndir grncq\ndir mbjwpdb\ndir nll\ndir qjjgjzbl\n101178 qtqgcj\n113714 sfhrzzcd.hwm\n$ cd cczhv\n$ ls\n158391 fmwzpjdm.nwz\n$ cd ..\n$ cd grncq\n$ ls\n238901 hdh\n188528 mjwcd.bqt\n61037 wpfg.shs\n$ cd ..\n$ cd mbjwpdb\n$ ls\ndir bphnpft\n72033 fgpnnvm\ndir hdh\ndir qdmjnqc\ndir rplsvs\n85983 tpfjp\n303863 wzwvbnw.blb\n$ cd bphnpft\n$ ls\n274331 gmmmlsj.pwp\n$ cd ..\n$ cd hdh\n$ ls\n154778 szs.dtw\n$ cd ..\n$ cd qdmjnqc\n$ ls\n133639 grncq.wnj\n$ cd ..\n$ cd rplsvs\n$ ls\n332906 zpstcbj.zvt\n$ cd ..\n$ cd ..\n$ cd nll\n$ ls\n82368 cjvph.fvc\n278280 ctmnzd\ndir hdh\ndir lqblqtng\n211682 pshd.fds\n319207 sfhrzzcd.hwm\n46873 vrf.fgg\n$ cd hdh\n$ ls\ndir hdtg\n$ cd hdtg\n$ ls\n19779 cjvph.fvc\n$ cd ..\n$ cd ..\n$ cd lqblqtng\n$ ls\n301792 dmhslp.hvz\n305639 dvqsffcn\n321833 strqtwrw\n112471 twnj.fbn\n20731 zsgdbd.dmm\n$ cd ..\n$ cd ..\n$ cd qjjgjzbl\n$ ls\n78166 sztm.zrz\n$ cd ..\n$ cd ..\n$ cd qbnthms\n$ ls\n158365 bvwjvrvr.bvb\n$ cd ..\n$ cd sthfhjf\n$ ls\n239051 dvqsffcn\ndir ghgcfwg\n327346 glfcswq.cqc\ndir tjqhqs\n124072 twnj.cbn\n261890 zsgdbd.dmm\n28917 zvbn.rbz\n$ cd ghgcfwg\n$ ls\ndir szs\n$ cd szs\n$ ls\n29912 fgpnnvm\n$ cd ..\n$ cd ..\n$ cd tjqhqs\n$ ls\n125555 twnj.dnv\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd rgl\n$ ls\ndir grncq\n59380 szs.jvh\ndir wjjzprnz\n$ cd grncq\n$ ls\n283850 lqblqtng.cdf\n$ cd ..\n$ cd wjjzprnz\n$ ls\n339632 hbg.vpp\n15887 zglntj.qtt\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd twjtnhll\n$ ls\n207430 cjvph.fvc\ndir dnv\ndir hdh\n232881 wwp\n$ cd dnv\n$ ls\n215966 lqblqtng.tdf\n39850 szb.fqn\n45436 twnj\n$ cd ..\n$ cd hdh\n$ ls\ndir grrs\n288906 hvjv\n219092 mnr.qhg\n336470 rwh\n145474 sfhrzzcd.hwm\n24384 szs.fwn\ndir twnj\n$ cd grrs\n$ ls\n94496 dvqsffcn\n$ cd ..\n$ cd twnj\n$ ls\ndir sbhbnsw\n$ cd sbhbnsw\n$ ls\n162705 sfhrzzcd.hwm\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd pgvmpmn\n$ ls\n18511 sfhrzzcd.hwm\n$ cd ..\n$ cd pqqcvcm\n$ ls\n201925 lqblqtng.mwb\n300811 scjclwwz.bng\n$ cd ..\n$ cd zglbptq\n$ ls\ndir brhmv\n237267 grncq\ndir hdh\ndir mhnfrn\n131601 qtjwj\n69185 tdtgmgw.qwc\n96764 vppqqpf.znc\n$ cd brhmv\n$ ls\ndir ddd\ndir dpnghvpp\n102457 grncq\n239654 jtvzhvd.jvm\n276919 sfhrzzcd.hwm\n235776 zsgdbd.dmm\n$ cd ddd\n$ ls\ndir grncq\ndir mbrcgcd\n$ cd grncq\n$ ls\n110323 fgpnnvm\n$ cd ..\n$ cd mbrcgcd\n$ ls\n100317 pbb.hcb\n$ cd ..\n$ cd ..\n$ cd dpnghvpp\n$ ls\n259305 bscmwtd.mlw\ndir hdh\n$ cd hdh\n$ ls\n49708 grncq.zdl\ndir szs\n$ cd szs\n$ ls\n225139 fgpnnvm\n258801 twnj.nrj\ndir wwzpvb\n$ cd wwzpvb\n$ ls\n4309 qlddd.cps\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd ..\n$ cd hdh\n$ ls\n24775 hdh.htz\n238695 jzpvhl\n$ cd ..\n$ cd mhnfrn\n$ ls\n30250 sfhrzzcd.hwm\ndir twnj\n$ cd twnj\n$ ls\n244061 qddtlr.mpf"));
+    // reports
+    let fs: FileSystem = filesystem_build(example).unwrap();
+    assert_eq!(
+        "- / (dir, size=48381165)\n  - a (dir, size=94853)\n    - e (dir, size=584)\n      - i (file, size=584)\n    - f (file, size=29116)\n    - g (file, size=2557)\n    - h.lst (file, size=62596)\n  - b.txt (file, size=14848514)\n  - c.dat (file, size=8504156)\n  - d (dir, size=24933642)\n    - d.ext (file, size=5626152)\n    - d.log (file, size=8033020)\n    - j (file, size=4060174)\n    - k (file, size=7214296)\n",
+        fs.tree()
+    );
+    assert_eq!(
+        "  48381165  /\n  24933642  /d\n     94853  /a\n       584  /a/e\n",
+        rows_text(&fs.du())
+    );
+    assert_eq!(
+        vec![
+            (String::from("/"), 48381165),
+            (String::from("/d"), 24933642)
+        ],
+        fs.directories_largest(2)
+    );
+    assert_eq!(
+        vec![
+            (String::from("/b.txt"), 14848514),
+            (String::from("/c.dat"), 8504156),
+            (String::from("/d/d.log"), 8033020),
+            (String::from("/d/k"), 7214296),
+        ],
+        fs.files_larger(7000000)
+    );
+    assert_eq!(
+        "      size  files  extension\n  14848514      1  txt\n  11306727      5  (none)\n   8504156      1  dat\n   8033020      1  log\n   5626152      1  ext\n     62596      1  lst\n",
+        extensions_text(&fs.sizes_by_extension())
+    );
+    assert_eq!(8381165, space_to_free(FS_SPACE, FREE_SPACE_NEED, 48381165));
+    assert_eq!(0, space_to_free(100000000, FREE_SPACE_NEED, 48381165));
+    assert_eq!(
+        vec![
+            (String::from("/d"), 24933642),
+            (String::from("/"), 48381165)
+        ],
+        fs.directories_freeing(8381165)
+    );
+    assert_eq!(
+        Ok(94853),
+        find_directory_to_delete(FS_SPACE, 21700000, example)
+    );
+    // a directory freeing exactly the missing space is chosen by every report
+    assert_eq!(94853, space_to_free(FS_SPACE, 21713688, 48381165));
+    assert_eq!(
+        Ok(94853),
+        find_directory_to_delete(FS_SPACE, 21713688, example)
+    );
+    assert_eq!(
+        Some(&(String::from("/a"), 94853)),
+        fs.directories_freeing(94853).first()
+    );
+
+    // JSON export and transcript generation give back the same tree
+    let json: String = fs.to_json();
+    assert_eq!(
+        "{\"files\":{\"b.txt\":14848514,\"c.dat\":8504156},\"directories\":{\"a\":{\"files\":{\"f\":29116,\"g\":2557,\"h.lst\":62596},\"directories\":{\"e\":{\"files\":{\"i\":584},\"directories\":{}}}},\"d\":{\"files\":{\"d.ext\":5626152,\"d.log\":8033020,\"j\":4060174,\"k\":7214296},\"directories\":{}}}}",
+        json
+    );
+    let imported: FileSystem = filesystem_from_json(&json).unwrap();
+    assert_eq!(fs.tree(), imported.tree());
+    assert_eq!(
+        json,
+        filesystem_parse(&imported.transcript()).unwrap().to_json()
+    );
+    assert_eq!(
+        Ok(24933642),
+        find_directory_to_delete(FS_SPACE, FREE_SPACE_NEED, &imported.transcript())
+    );
+    assert_eq!(
+        "$ cd /\n$ ls\ndir a\n1 \"x y\"\n$ cd a\n$ ls\n$ cd ..\n",
+        filesystem_from_json(" { \"directories\": {\"a\": {}}, \"files\": {\"\\\"x y\\\"\": 1} } ")
+            .unwrap()
+            .transcript()
+    );
+    assert_eq!(
+        Err(String::from("offset 8: unknown key 'file'")),
+        filesystem_from_json("{\"file\": {}}").map(|_| ())
+    );
+    assert_eq!(
+        Err(String::from("offset 21: /a listed twice")),
+        filesystem_from_json("{\"files\":{\"a\":1,\"a\":2}}").map(|_| ())
+    );
+    let changed: FileSystem = filesystem_build(
+        "$ cd /\n$ ls\ndir a\n14848514 b.txt\n9000000 c.dat\n$ cd a\n$ ls\n29116 f\n2557 g\n62596 h.lst\n1 new",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            "- /a/e/",
+            "- /a/e/i (584)",
+            "+ /a/new (1)",
+            "~ /c.dat (8504156 -> 9000000)",
+            "- /d/",
+            "- /d/d.ext (5626152)",
+            "- /d/d.log (8033020)",
+            "- /d/j (4060174)",
+            "- /d/k (7214296)",
+        ],
+        filesystem_diff(&fs, &changed)
+    );
+    assert_eq!(0, filesystem_diff(&fs, &imported).len());
+
+    // deletion planner, several directories can beat the smallest large enough one
+    assert_eq!(
+        Ok(DeletionPlan {
+            entries: vec![(String::from("/d"), 24933642)],
+            size: 24933642
+        }),
+        deletion_plan(&fs, 8381165, false)
+    );
+    assert_eq!(
+        Ok(DeletionPlan {
+            entries: vec![(String::from("/c.dat"), 8504156)],
+            size: 8504156
+        }),
+        deletion_plan(&fs, 8381165, true)
+    );
+    assert_eq!(
+        Ok(DeletionPlan {
+            entries: vec![(String::from("/d/j"), 4060174)],
+            size: 4060174
+        }),
+        deletion_plan(&fs, 100000, true)
+    );
+    assert_eq!(
+        Err(String::from("the filesystem holds less than 48381166")),
+        deletion_plan(&fs, 48381166, false)
+    );
+    let fs: FileSystem = filesystem_build("$ cd /\n$ ls\ndir a\ndir b\ndir c\n$ cd a\n$ ls\n30 x\n30 y\n$ cd ..\n$ cd b\n$ ls\n50 z\n$ cd ..\n$ cd c\n$ ls\n200 w").unwrap();
+    let plan = |space: usize, with_files: bool| -> Vec<String> {
+        let plan: DeletionPlan = deletion_plan(&fs, space, with_files).unwrap();
+        let paths: Vec<String> = plan.entries.into_iter().map(|(path, _)| path).collect();
+        assert_eq!(
+            plan.size,
+            fs.entries()
+                .iter()
+                .filter(|(path, _)| paths.iter().any(|p| path.starts_with(p.as_str())))
+                .map(|(_, size)| size.unwrap_or(0))
+                .sum::<usize>()
+        );
+        return paths;
+    };
+    assert_eq!(vec!["/a", "/b"], plan(75, false));
+    assert_eq!(vec!["/a/x", "/b"], plan(75, true));
+    assert_eq!(vec!["/a", "/b"], plan(100, true));
+    assert_eq!(vec!["/c"], plan(200, false));
+    assert_eq!(Vec::<String>::new(), plan(0, false));
+    // the planner gives up before allocating its tables when they are too large
+    let fs: FileSystem =
+        filesystem_build("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n2000000000 x\n1 y").unwrap();
+    for with_files in [false, true] {
+        assert_eq!(
+            Err(String::from(
+                "planning for 1999999999 needs more than 134217728 bytes"
+            )),
+            deletion_plan(&fs, 1999999999, with_files)
+        );
+    }
+
+    // jumping back to the root, revisiting and listing twice doesn't count anything twice
+    let terminal: &str = "$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\ndir c\n20 d\n$ cd c\n$ ls\n30 e\n$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ cd c\n$ ls\n30 e\n$ cd ..\n$ cd ..\n";
+    let fs: FileSystem = filesystem_build(terminal).unwrap();
+    assert_eq!(3, fs.directories.len());
+    assert_eq!(vec![60, 50, 30], fs.sizes());
+    assert_eq!("/", fs.path(0));
+    assert_eq!("/a/c", fs.path(2));
+    assert_eq!(0, fs.issues.len());
+
+    // inconsistencies are reported with their line, the tree is still built
+    let fs: FileSystem = filesystem_build(
+        "$ cd /\n$ ls\n10 b\n$ cd x\n$ ls\n5 y\n$ cd ..\n$ ls\n12 b\ndir b\n$ cd ..",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            "line 4: cd into unlisted directory /x",
+            "line 9: /b size changed from 10 to 12",
+            "line 10: /b listed as a file and a directory",
+            "line 11: cd .. from the root",
+        ],
+        fs.issues
+    );
+    assert_eq!(vec![17, 5], fs.sizes());
+    assert_eq!(
+        Err(String::from("line 2: 'dir a' outside of a listing")),
+        filesystem_build("$ cd /\ndir a").map(|_| ())
+    );
+    assert_eq!(
+        Err(String::from("line 1: unknown command 'rm -rf /'")),
+        filesystem_build("$ rm -rf /").map(|_| ())
+    );
+    assert_eq!(
+        Err(String::from("line 3: invalid listing entry 'abc d'")),
+        filesystem_build("$ cd /\n$ ls\nabc d").map(|_| ())
+    );
+    assert_eq!(
+        Err(String::from("line 6: size of /a/b too large")),
+        filesystem_build("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n99999999999999999999999 b")
+            .map(|_| ())
+    );
 }
 
 /*
@@ -1,22 +1,159 @@
 // https://adventofcode.com/2022/day/8
 // (part 1)
 
-fn count_trees_visible(input: &str) -> usize {
-    let mut count: usize = 0;
+type Height = u32;
 
-    let trees_lines: Vec<&str> = input.split('\n').collect();
-    if !trees_lines.is_empty() {
-        let cols_count = trees_lines[0].len();
-        let rows_count = trees_lines.len();
-        // convert all the char to u8 and put all the data into a vector to ease readability
-        let mut trees: Vec<u8> = Vec::with_capacity(cols_count * rows_count);
-        for tree_line in trees_lines {
-            for c in tree_line.chars() {
-                assert!(c >= '0' && c <= '9');
-                trees.push(c as u8 - '0' as u8);
+// heights are either single digits or separated by whitespace, so they can go beyond 9, the same
+// way on every row, all rows must have the same count of trees as the first one, blank lines
+// before it, whitespace around a row and a trailing newline are ignored
+fn trees_parse(input: &str) -> Result<(Vec<Height>, usize, usize), String> {
+    let input: &str = input.strip_suffix('\n').unwrap_or(input);
+    let is_separator = |c: char| c == ' ' || c == '\t';
+    // whether heights are separated, known from the first row with trees
+    let mut separated: Option<bool> = None;
+    let mut trees: Vec<Height> = Vec::new();
+    let mut cols_count: usize = 0;
+    let mut rows_count: usize = 0;
+    for (i, tree_line) in input.split('\n').enumerate() {
+        let row: &str = tree_line.trim_start_matches(is_separator);
+        // columns in errors are counted from the start of the line
+        let row_column: usize = tree_line.chars().count() - row.chars().count();
+        let row: &str = row.trim_end_matches(|c: char| is_separator(c) || c == '\r');
+        if row.is_empty() && rows_count == 0 {
+            continue;
+        }
+        if !row.is_empty() {
+            let row_separated: bool = row.contains(is_separator);
+            match separated {
+                None => separated = Some(row_separated),
+                Some(true) if !row_separated => {
+                    return Err(format!(
+                        "line {}: digits in a grid of separated heights",
+                        i + 1
+                    ))
+                }
+                Some(false) if row_separated => {
+                    return Err(format!(
+                        "line {}: separated heights in a grid of digits",
+                        i + 1
+                    ))
+                }
+                _ => {}
             }
         }
+        let row_start: usize = trees.len();
+        if separated == Some(true) {
+            let mut column: usize = row_column;
+            for token in row.split(is_separator) {
+                if !token.is_empty() {
+                    match token.parse::<Height>() {
+                        Ok(height) if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                            trees.push(height)
+                        }
+                        _ => {
+                            return Err(format!(
+                                "line {}, column {}: invalid height '{}'",
+                                i + 1,
+                                column + 1,
+                                token
+                            ))
+                        }
+                    }
+                }
+                column += token.chars().count() + 1;
+            }
+        } else {
+            for (column, c) in row.chars().enumerate() {
+                match c.to_digit(10) {
+                    Some(height) => trees.push(height),
+                    None => {
+                        return Err(format!(
+                            "line {}, column {}: invalid height '{}'",
+                            i + 1,
+                            row_column + column + 1,
+                            c
+                        ))
+                    }
+                }
+            }
+        }
+        let row_len: usize = trees.len() - row_start;
+        if rows_count == 0 {
+            cols_count = row_len;
+        } else if row_len != cols_count {
+            return Err(format!(
+                "line {}: {} trees instead of {}",
+                i + 1,
+                row_len,
+                cols_count
+            ));
+        }
+        rows_count += 1;
+    }
+    if trees.is_empty() {
+        return Err(String::from("no trees"));
+    }
+    return Ok((trees, rows_count, cols_count));
+}
 
+// a tree is visible from an edge when it is higher than every tree before it, so walking lines
+// from every edge keeping the highest tree seen finds them all in O(rows * cols), columns are
+// walked a row at a time to stay cache friendly
+fn visibility_compute(trees: &[Height], rows_count: usize, cols_count: usize) -> Vec<bool> {
+    let mut visible: Vec<bool> = vec![false; trees.len()];
+    for row in 0..rows_count {
+        let line: std::ops::Range<usize> = row * cols_count..(row + 1) * cols_count;
+        let mut highest: i64 = -1;
+        for index in line.clone() {
+            visible[index] |= trees[index] as i64 > highest;
+            highest = highest.max(trees[index] as i64);
+        }
+        highest = -1;
+        for index in line.rev() {
+            visible[index] |= trees[index] as i64 > highest;
+            highest = highest.max(trees[index] as i64);
+        }
+    }
+    let mut highest: Vec<i64> = vec![-1; cols_count];
+    for row in 0..rows_count {
+        for col in 0..cols_count {
+            let index: usize = row * cols_count + col;
+            visible[index] |= trees[index] as i64 > highest[col];
+            highest[col] = highest[col].max(trees[index] as i64);
+        }
+    }
+    highest.fill(-1);
+    for row in (0..rows_count).rev() {
+        for col in 0..cols_count {
+            let index: usize = row * cols_count + col;
+            visible[index] |= trees[index] as i64 > highest[col];
+            highest[col] = highest[col].max(trees[index] as i64);
+        }
+    }
+    return visible;
+}
+
+// xorshift, good enough to generate inputs without any dependency
+fn random_next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    return *state;
+}
+
+fn forest_generate(rows_count: usize, cols_count: usize, seed: u64) -> Vec<Height> {
+    let mut state: u64 = seed;
+    return (0..rows_count * cols_count)
+        .map(|_| (random_next(&mut state) % 10) as Height)
+        .collect();
+}
+
+// original implementation, walking rays from every tree, kept to check and benchmark the linear
+// one against
+fn count_visible_reference(trees: &[Height], rows_count: usize, cols_count: usize) -> usize {
+    let mut count: usize = 0;
+
+    if !trees.is_empty() {
         for row in 1..rows_count - 1 {
             // skip first and last row, we know they are visible
             for col in 1..cols_count - 1 {
@@ -86,10 +223,166 @@ fn count_trees_visible(input: &str) -> usize {
     return count;
 }
 
+fn count_visible(trees: &[Height], rows_count: usize, cols_count: usize) -> usize {
+    return visibility_compute(trees, rows_count, cols_count)
+        .iter()
+        .filter(|v| **v)
+        .count();
+}
+
+fn count_trees_visible(input: &str) -> Result<usize, String> {
+    let (trees, rows_count, cols_count) = trees_parse(input)?;
+    return Ok(count_visible(&trees, rows_count, cols_count));
+}
+
+// heights rising to the center, so that every tree is visible and the rays of the reference go
+// all the way to the edges
+fn forest_pyramid(size: usize) -> Vec<Height> {
+    let mut trees: Vec<Height> = Vec::with_capacity(size * size);
+    for row in 0..size {
+        for col in 0..size {
+            let ring: usize = row.min(col).min(size - 1 - row).min(size - 1 - col);
+            trees.push(ring as Height);
+        }
+    }
+    return trees;
+}
+
+fn benchmark() {
+    for size in [1000, 5000] {
+        let trees: Vec<Height> = forest_generate(size, size, 0x2022_0008);
+        let start = std::time::Instant::now();
+        let count: usize = count_visible(&trees, size, size);
+        let linear_duration = start.elapsed();
+        let start = std::time::Instant::now();
+        assert_eq!(count, count_visible_reference(&trees, size, size));
+        println!(
+            "{}x{} random forest: {:?}, reference {:?}",
+            size,
+            size,
+            linear_duration,
+            start.elapsed()
+        );
+    }
+    // heights beyond digits let views go far, where the reference becomes cubic
+    let trees: Vec<Height> = forest_pyramid(5000);
+    let start = std::time::Instant::now();
+    assert_eq!(5000 * 5000, count_visible(&trees, 5000, 5000));
+    println!("5000x5000 pyramid forest: {:?}", start.elapsed());
+    // the reference being cubic on it, it only runs on a small pyramid
+    let size: usize = 500;
+    let trees: Vec<Height> = forest_pyramid(size);
+    let start = std::time::Instant::now();
+    assert_eq!(size * size, count_visible(&trees, size, size));
+    let linear_duration = start.elapsed();
+    let start = std::time::Instant::now();
+    assert_eq!(size * size, count_visible_reference(&trees, size, size));
+    println!(
+        "{}x{} pyramid forest: {:?}, reference {:?}",
+        size,
+        size,
+        linear_duration,
+        start.elapsed()
+    );
+}
+
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("bench") {
+        benchmark();
+        return;
+    }
+
     // example
-    assert_eq!(21, count_trees_visible("30373\n25512\n65332\n33549\n35390"));
-    assert_eq!(1533, count_trees_visible("222322213033345255533423306545562424165440655115256171674620442636621123532003623343351021112300040\n110200134424131544435511513034235114207220346712122743100173142606016164253614600344452542043234001\n130241122341034230060506400411050451436457131115555076655121366640166143420114243260041315245140033\n044201155341204150154421223651421072455456424262034065175730703125367662022422056423113110423033043\n341242043015141323013444355403421162464354167412604633147554242145501111651130260045523542142405124\n104105335542011255154624541673170452421201174751065233125053535203606402110631631303264231443334523\n022251232401442443240604013073567051751261046006060310057162723056356047312301014332262320405344224\n005045010303234050011604134516553223421500156708426287011206217044163376565240420066564035322425031\n433400302252163605112001002152533254526577025774185772532522645162112005222006334211222615202150023\n514421532420126245053363421750507267400406222575606338538284336516406721212651434025136363121253314\n145500111514555142462447264776571468434327564631361615475570716477174343523375640422633224154214330\n450422253465464405163267335560338812657620448402661157163042574016476542116027110002556401004144041\n315250435323412100266435752414460614818523238015312045767348145251346503446110002404643412031033110\n335554260614516260275514014501773808684115234652573523444781127146772327147027164546041361322264113\n552212024666234517403145053462886425015423537515312180012163268455035077313422213613314122403623422\n554116462330326333717726071743410713076038127356572547895446465004382875747801510560161030046236534\n105512113261674505476760210308840028176731611387575863387841328453867641286570312570624613101433450\n201620055416403673411402684861111672134437556816818962692824522386258176417471541216750515352320263\n100064152502471121126620147804267474177133917793419871348867649139837456117360472552512240365464543\n434125501030234366461354647212882961264276372277577656415297827484543874788435871670574372006541656\n354231632241763433421115602887827646148345796776349994375746935256394267608007744846602344220506426\n320055542235452511286776246551436249838211357522914296165427513242286591157867047217062671166212220\n630533311432024274286282747578525892839185272964722232382686773975896514921876068064231003115130640\n054351445323650230374860016787334957865672953893647532392634515193476444753234751431776670673443625\n122161643553043361465661234215425777375428328842756826885237348622679574815841302214422454334622455\n310361643547534388254741787957515835394388424384634948237897787328779899668951118145613227272341123\n553203043313014483116602739588328966436465926583287463393949837268357287783771610187112515346661056\n361112773030626240167311193287631239264325599932928434546936456455966294955629721437363071113555100\n223116444062065127823414995768335224963787626949338374537976548374532595386885887817322311512135354\n165533456256685465133638322212456677839479985883969576788652889447966945526287513125252251227266360\n050427105764654780569971719539982722683736488643647867988885285677254929533615456260111805471544762\n612564200420147420768231643572583337363778975687346456978779493453467879471982875321111751612056704\n154715031561015145153929571787325853755436888933888338496548994492842954225615713257348715562770051\n042706406641878154761493925944579827297695668447479695968796749364665547438536956376866503052450261\n111103621158778860687333696596964667768654674764978799646936368489433243322422814121081720104310375\n256106134082036385674358835842774753667963458466356667369999749769462758256568366264544861030650514\n412372400235780556155749924663448584664436986744984487994753564686643982758245295828805442657020150\n316402420685442228837832744963276587378457659649458546869353576749393683736838444639170007152747441\n343664452531110164851537867349623666557879764884856546986489797534353938689749649842383748067621444\n004444326256452015911854963372679986857738459895764946977798848396957278774226377383181551011246332\n324213275444575144439872496886847394968576968689979497664689986774356463883323582931740408774664723\n167274127524542565635816457474433733785665667989859545577574597555588393452438866921746374545730347\n312523103467383946589439238265777486435445747964954446957896799994745579737227145455742444466763354\n042101230038108485461717949225836749699798599445858954785786668636987937236256346361252208168136312\n447311762287744692475573737395565466544999496459956989976897778946499374824747773197595161266172226\n371671376376358926187663595697867739555794587855655786889854499686796363666939989643254552166201754\n305514713774776948141752365256767736786987588655596589796495868756859657723267975757697551713050730\n505672367783107579727693596546487757757799568755578685859899884563658799498726941257953878711714017\n457520154136801497456138426564536866785654498777579675857476597474753988679778543462582232418154231\n050570576377124118562748278969974597659546757999795788577798949457569458597349739399679324147816176\n064032506374261599382993468784374444687579995969557585785777758755334674823733669516557164016427124\n346565566106489839877287435227877647875697657779798687889989777455747976752522588271842557284604304\n320630184267887846149779595575797453867985966985895787576699496556963977669453525683498188461017663\n155470636481748966932554775295985386984477788779777769886686888674939686949557211549684318562843364\n355422032736165249425585822655654853976657576675655856568867858693534395443986997713966364825241516\n014307615355116598362643769888978393745848466775686878856446548776874844332797955528420388441472072\n434677415171655145937867232795798374659889895676897986696777754584999873398343952258322518308312370\n614462625176544968398772979677993886597595888444699849445968855456666794598363878647397472370611124\n342426038105820512291673236242588648634466744646845777477557645589764482986857645122398666420720742\n303446222610437533389493227477389695959868644577787974876888684975836425953723362621837202318302515\n101334031274541867475755424764549646588488959767847459756867963388465874367294735519312864601156672\n311162646802122687272848655359448638774475595989694889897479694563583798875942767189780830474311553\n670525052313105734222877229995545497896735689799858955459867784679684938565425586596166033035473164\n625002126405824409197545328243248943664786877896564684896757759666544465836966863943043883874535107\n304504507410725215339562444778866968394554865956559998446945777964348595952748915112623570510705144\n447671532535258771129625534992686968475466769559485845995386695599378847572918425825332176641466062\n022123400381035622672492659655854245887594385374756573547685957733982636227675215225616456802746606\n143231134353837650051751146983252488977634698367786398395985875992826958228379769214124442703715262\n135170610171160182526139534726928582959959837673658879565485535346824864319872955144130017452466064\n102646524660025304032183156274795333553888848798733487439766887562335538983732625811202053035706412\n105313211023023674376715267861525696823925879646687867997695888347896781494452164804031634615425042\n256205106513734680231582333362957987973927998375834773457684994689349817858524813244430142704015520\n021404347726028733646641268434424287525532769279452643745555528837589599615841240070086220361652546\n224024214320367157758819746327682985953929797339659337579259325447482167689237531580534124157434533\n432614136036164731080370551893833148799729789672732794952993828437433612152513202747342230044776363\n314003554513122735473322134512738781597463362529856243299295546734985494922406881657630707442021510\n514504073756450312710340856355119778239528232648473554453443349559599228483527304806846455610024545\n424156167665132531871223763346631626522767446256444526857773846279396461782420177025261412610600431\n440544203354025350033001226782826234581598745799586998494951719551921447963680834604761003456033433\n030261134056327505538138763401733512835785873969133667323138843188738957384853306401550446745105426\n524041041607103210643621736677777415881386586134322795167285489925353353273682055677320161701632002\n532513043015722226170325461521708421659348265984277116386737884679183833325305770247107056515000632\n450631113064541102213228045638208741315429696459599317816954821479256672633762880514531576001505104\n214165164302237003505054233882824157688219632493411326562973319962407073638136757124756516600661503\n304465104303261562212345808766401574871933592693641614758134144886301815682807153126027453244204015\n135203445204521251600013125103586202465438696331861292616228156657585723603452536740112103314030555\n514516400551406327564011243567833388064737417461387480870726483204868682076024701011125332602254113\n421341053633214625706123464303687686511262673301412775315212752511877023033424215721261615303053012\n022044105062611536144720566574153723272833357078078000286705430350220142407371067103103416226230555\n410213236064223263635201443404636873030520630316216081306535811175741246467466645353201136163050545\n303520211553166661506232367472736401455544832872162057805076267517121166516261355403030034621243454\n203254222143353045650002244735005340271145025041572764542706827413452562704066262265323455430133504\n441232355335366123446141420412727113134404853821274587121188823260264625415453362560311464455210441\n414115054424201303442043777071262633223473236654360080370734624214365605172654033011620153152523134\n412115334452334460565220356727405160520263276133202400177553665136056247040612461135000421154322204\n041335455114425155032554320512112112454416721273507036712353407602170012763156424133043141034050241\n030023102524414043125455041405115606664661260240725146305660271437703406461522205621551001134541002\n404443532404034526430106366644217325436253634242603222554235163606432511155652343363021250505540430\n324114252001344342204144440320420641222766650735522654115551234652234245431600152550532022520100132"));
+    assert_eq!(
+        Ok(21),
+        count_trees_visible("30373\n25512\n65332\n33549\n35390")
+    );
+    assert_eq!(Ok(1533), count_trees_visible("222322213033345255533423306545562424165440655115256171674620442636621123532003623343351021112300040\n110200134424131544435511513034235114207220346712122743100173142606016164253614600344452542043234001\n130241122341034230060506400411050451436457131115555076655121366640166143420114243260041315245140033\n044201155341204150154421223651421072455456424262034065175730703125367662022422056423113110423033043\n341242043015141323013444355403421162464354167412604633147554242145501111651130260045523542142405124\n104105335542011255154624541673170452421201174751065233125053535203606402110631631303264231443334523\n022251232401442443240604013073567051751261046006060310057162723056356047312301014332262320405344224\n005045010303234050011604134516553223421500156708426287011206217044163376565240420066564035322425031\n433400302252163605112001002152533254526577025774185772532522645162112005222006334211222615202150023\n514421532420126245053363421750507267400406222575606338538284336516406721212651434025136363121253314\n145500111514555142462447264776571468434327564631361615475570716477174343523375640422633224154214330\n450422253465464405163267335560338812657620448402661157163042574016476542116027110002556401004144041\n315250435323412100266435752414460614818523238015312045767348145251346503446110002404643412031033110\n335554260614516260275514014501773808684115234652573523444781127146772327147027164546041361322264113\n552212024666234517403145053462886425015423537515312180012163268455035077313422213613314122403623422\n554116462330326333717726071743410713076038127356572547895446465004382875747801510560161030046236534\n105512113261674505476760210308840028176731611387575863387841328453867641286570312570624613101433450\n201620055416403673411402684861111672134437556816818962692824522386258176417471541216750515352320263\n100064152502471121126620147804267474177133917793419871348867649139837456117360472552512240365464543\n434125501030234366461354647212882961264276372277577656415297827484543874788435871670574372006541656\n354231632241763433421115602887827646148345796776349994375746935256394267608007744846602344220506426\n320055542235452511286776246551436249838211357522914296165427513242286591157867047217062671166212220\n630533311432024274286282747578525892839185272964722232382686773975896514921876068064231003115130640\n054351445323650230374860016787334957865672953893647532392634515193476444753234751431776670673443625\n122161643553043361465661234215425777375428328842756826885237348622679574815841302214422454334622455\n310361643547534388254741787957515835394388424384634948237897787328779899668951118145613227272341123\n553203043313014483116602739588328966436465926583287463393949837268357287783771610187112515346661056\n361112773030626240167311193287631239264325599932928434546936456455966294955629721437363071113555100\n223116444062065127823414995768335224963787626949338374537976548374532595386885887817322311512135354\n165533456256685465133638322212456677839479985883969576788652889447966945526287513125252251227266360\n050427105764654780569971719539982722683736488643647867988885285677254929533615456260111805471544762\n612564200420147420768231643572583337363778975687346456978779493453467879471982875321111751612056704\n154715031561015145153929571787325853755436888933888338496548994492842954225615713257348715562770051\n042706406641878154761493925944579827297695668447479695968796749364665547438536956376866503052450261\n111103621158778860687333696596964667768654674764978799646936368489433243322422814121081720104310375\n256106134082036385674358835842774753667963458466356667369999749769462758256568366264544861030650514\n412372400235780556155749924663448584664436986744984487994753564686643982758245295828805442657020150\n316402420685442228837832744963276587378457659649458546869353576749393683736838444639170007152747441\n343664452531110164851537867349623666557879764884856546986489797534353938689749649842383748067621444\n004444326256452015911854963372679986857738459895764946977798848396957278774226377383181551011246332\n324213275444575144439872496886847394968576968689979497664689986774356463883323582931740408774664723\n167274127524542565635816457474433733785665667989859545577574597555588393452438866921746374545730347\n312523103467383946589439238265777486435445747964954446957896799994745579737227145455742444466763354\n042101230038108485461717949225836749699798599445858954785786668636987937236256346361252208168136312\n447311762287744692475573737395565466544999496459956989976897778946499374824747773197595161266172226\n371671376376358926187663595697867739555794587855655786889854499686796363666939989643254552166201754\n305514713774776948141752365256767736786987588655596589796495868756859657723267975757697551713050730\n505672367783107579727693596546487757757799568755578685859899884563658799498726941257953878711714017\n457520154136801497456138426564536866785654498777579675857476597474753988679778543462582232418154231\n050570576377124118562748278969974597659546757999795788577798949457569458597349739399679324147816176\n064032506374261599382993468784374444687579995969557585785777758755334674823733669516557164016427124\n346565566106489839877287435227877647875697657779798687889989777455747976752522588271842557284604304\n320630184267887846149779595575797453867985966985895787576699496556963977669453525683498188461017663\n155470636481748966932554775295985386984477788779777769886686888674939686949557211549684318562843364\n355422032736165249425585822655654853976657576675655856568867858693534395443986997713966364825241516\n014307615355116598362643769888978393745848466775686878856446548776874844332797955528420388441472072\n434677415171655145937867232795798374659889895676897986696777754584999873398343952258322518308312370\n614462625176544968398772979677993886597595888444699849445968855456666794598363878647397472370611124\n342426038105820512291673236242588648634466744646845777477557645589764482986857645122398666420720742\n303446222610437533389493227477389695959868644577787974876888684975836425953723362621837202318302515\n101334031274541867475755424764549646588488959767847459756867963388465874367294735519312864601156672\n311162646802122687272848655359448638774475595989694889897479694563583798875942767189780830474311553\n670525052313105734222877229995545497896735689799858955459867784679684938565425586596166033035473164\n625002126405824409197545328243248943664786877896564684896757759666544465836966863943043883874535107\n304504507410725215339562444778866968394554865956559998446945777964348595952748915112623570510705144\n447671532535258771129625534992686968475466769559485845995386695599378847572918425825332176641466062\n022123400381035622672492659655854245887594385374756573547685957733982636227675215225616456802746606\n143231134353837650051751146983252488977634698367786398395985875992826958228379769214124442703715262\n135170610171160182526139534726928582959959837673658879565485535346824864319872955144130017452466064\n102646524660025304032183156274795333553888848798733487439766887562335538983732625811202053035706412\n105313211023023674376715267861525696823925879646687867997695888347896781494452164804031634615425042\n256205106513734680231582333362957987973927998375834773457684994689349817858524813244430142704015520\n021404347726028733646641268434424287525532769279452643745555528837589599615841240070086220361652546\n224024214320367157758819746327682985953929797339659337579259325447482167689237531580534124157434533\n432614136036164731080370551893833148799729789672732794952993828437433612152513202747342230044776363\n314003554513122735473322134512738781597463362529856243299295546734985494922406881657630707442021510\n514504073756450312710340856355119778239528232648473554453443349559599228483527304806846455610024545\n424156167665132531871223763346631626522767446256444526857773846279396461782420177025261412610600431\n440544203354025350033001226782826234581598745799586998494951719551921447963680834604761003456033433\n030261134056327505538138763401733512835785873969133667323138843188738957384853306401550446745105426\n524041041607103210643621736677777415881386586134322795167285489925353353273682055677320161701632002\n532513043015722226170325461521708421659348265984277116386737884679183833325305770247107056515000632\n450631113064541102213228045638208741315429696459599317816954821479256672633762880514531576001505104\n214165164302237003505054233882824157688219632493411326562973319962407073638136757124756516600661503\n304465104303261562212345808766401574871933592693641614758134144886301815682807153126027453244204015\n135203445204521251600013125103586202465438696331861292616228156657585723603452536740112103314030555\n514516400551406327564011243567833388064737417461387480870726483204868682076024701011125332602254113\n421341053633214625706123464303687686511262673301412775315212752511877023033424215721261615303053012\n022044105062611536144720566574153723272833357078078000286705430350220142407371067103103416226230555\n410213236064223263635201443404636873030520630316216081306535811175741246467466645353201136163050545\n303520211553166661506232367472736401455544832872162057805076267517121166516261355403030034621243454\n203254222143353045650002244735005340271145025041572764542706827413452562704066262265323455430133504\n441232355335366123446141420412727113134404853821274587121188823260264625415453362560311464455210441\n414115054424201303442043777071262633223473236654360080370734624214365605172654033011620153152523134\n412115334452334460565220356727405160520263276133202400177553665136056247040612461135000421154322204\n041335455114425155032554320512112112454416721273507036712353407602170012763156424133043141034050241\n030023102524414043125455041405115606664661260240725146305660271437703406461522205621551001134541002\n404443532404034526430106366644217325436253634242603222554235163606432511155652343363021250505540430\n324114252001344342204144440320420641222766650735522654115551234652234245431600152550532022520100132"));
+
+    // same results as the reference
+    let (trees, rows_count, cols_count) = trees_parse("30373\n25512\n65332\n33549\n35390").unwrap();
+    assert_eq!(21, count_visible_reference(&trees, rows_count, cols_count));
+    for (rows_count, cols_count) in [(1, 1), (1, 7), (2, 3), (40, 30)] {
+        let trees: Vec<Height> = forest_generate(rows_count, cols_count, 0x2022_0008);
+        assert_eq!(
+            count_visible_reference(&trees, rows_count, cols_count),
+            count_visible(&trees, rows_count, cols_count)
+        );
+    }
+    assert_eq!(49, count_visible(&forest_pyramid(7), 7, 7));
+    // the reference counts the border of a single column twice
+    assert_eq!(Ok(7), count_trees_visible("1\n2\n0\n5\n5\n1\n9"));
+
+    // parser: multi-digit heights, shape and trailing newline
+    assert_eq!(
+        Ok((vec![3, 0, 3, 2, 5, 5], 2, 3)),
+        trees_parse("303\n255\n")
+    );
+    assert_eq!(
+        Ok((vec![30, 7, 3, 2, 115, 5], 2, 3)),
+        trees_parse("30  7 3\n\t2 115 5\n")
+    );
+    assert_eq!(
+        Err(String::from("line 2: 2 trees instead of 3")),
+        trees_parse("303\n25\n")
+    );
+    assert_eq!(
+        Err(String::from("line 3: 0 trees instead of 3")),
+        trees_parse("303\n255\n\n")
+    );
+    assert_eq!(
+        Err(String::from("line 2, column 3: invalid height 'x'")),
+        trees_parse("303\n25x")
+    );
+    assert_eq!(
+        Err(String::from("line 1, column 4: invalid height '-2'")),
+        trees_parse("10 -2")
+    );
+    assert_eq!(Err(String::from("no trees")), trees_parse("\n"));
+    // whitespace around rows, mixed or ragged rows
+    assert_eq!(
+        Ok((vec![3, 0, 3, 2, 5, 5], 2, 3)),
+        trees_parse("303 \n\t255\t\r\n")
+    );
+    assert_eq!(
+        Ok((vec![30, 7, 3, 2, 115, 5], 2, 3)),
+        trees_parse(" 30 7 3 \n2 115 5\t\n")
+    );
+    assert_eq!(
+        Err(String::from(
+            "line 2: separated heights in a grid of digits"
+        )),
+        trees_parse("303\n2 5 5")
+    );
+    assert_eq!(
+        Err(String::from(
+            "line 2: digits in a grid of separated heights"
+        )),
+        trees_parse("3 0 3\n255")
+    );
+    assert_eq!(
+        Err(String::from("line 2, column 4: invalid height 'x'")),
+        trees_parse("303\n  2x5")
+    );
+    assert_eq!(
+        Err(String::from("line 2: 4 trees instead of 3")),
+        trees_parse("303 \n2553 ")
+    );
+    // the size of the grid is known from the first row with trees
+    assert_eq!(
+        Ok((vec![3, 0, 3, 2, 5, 5], 2, 3)),
+        trees_parse("\n \n303\n255")
+    );
+    assert_eq!(
+        Err(String::from("line 3: 2 trees instead of 3")),
+        trees_parse("\n303\n25")
+    );
+    assert_eq!(
+        Ok(21),
+        count_trees_visible("30373 \n25512\n65332\n33549\n35390\t\n")
+    );
+    assert_eq!(Ok(8), count_trees_visible("10 10 10\n10 9 10\n10 10 10"));
+    assert_eq!(Ok(9), count_trees_visible("10 10 10\n10 11 10\n10 10 10\n"));
 }
 
 /*
@@ -1,7 +1,7 @@
 // https://adventofcode.com/2022/day/9
-// (part 1)
+// (part 1 and 2)
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Point {
     x: i32,
     y: i32,
@@ -20,105 +20,855 @@ impl std::ops::AddAssign for Point {
     }
 }
 
-fn distance_compte(p1: &Point, p2: &Point) -> f64 {
-    return f64::sqrt(((p1.x - p2.x).pow(2) + (p1.y - p2.y).pow(2)) as f64);
+// count of king moves between two points
+fn distance_chebyshev(p1: &Point, p2: &Point) -> i32 {
+    return (p1.x - p2.x).abs().max((p1.y - p2.y).abs());
 }
 
-fn number_parse(number: &str) -> (usize, usize) {
-    let mut digit_count: usize = 0;
-    for c in number.chars() {
-        if c < '0' || c > '9' {
-            break;
+// moves the follower one step towards its leader when they aren't touching anymore, returns
+// whether it moved
+fn knot_follow(leader: Point, follower: &mut Point) -> bool {
+    if distance_chebyshev(&leader, follower) < 2 {
+        return false;
+    }
+    follower.x += (leader.x - follower.x).signum();
+    follower.y += (leader.y - follower.y).signum();
+    return true;
+}
+
+// how a knot follows the one before it, `follow` must leave the follower where it is when called
+// again with the same leader, since the knots after one that didn't move aren't updated
+trait FollowRule {
+    fn name(&self) -> String;
+    // calls `on_cell` with every cell the follower goes through, the last one being where it
+    // stops, returns whether it moved
+    fn follow(&self, leader: Point, follower: &mut Point, on_cell: &mut dyn FnMut(Point)) -> bool;
+}
+
+// the puzzle rule
+struct Touching {}
+
+impl FollowRule for Touching {
+    fn name(&self) -> String {
+        return String::from("touching");
+    }
+
+    fn follow(&self, leader: Point, follower: &mut Point, on_cell: &mut dyn FnMut(Point)) -> bool {
+        if !knot_follow(leader, follower) {
+            return false;
+        }
+        on_cell(*follower);
+        return true;
+    }
+}
+
+// follows one step at a time, diagonally if needed, as soon as the leader is further than
+// `distance`, the puzzle rule having a slack of 1
+struct Slack {
+    distance: i32,
+}
+
+impl FollowRule for Slack {
+    fn name(&self) -> String {
+        return format!("slack of {}", self.distance);
+    }
+
+    fn follow(&self, leader: Point, follower: &mut Point, on_cell: &mut dyn FnMut(Point)) -> bool {
+        let mut moved: bool = false;
+        while distance_chebyshev(&leader, follower) > self.distance {
+            follower.x += (leader.x - follower.x).signum();
+            follower.y += (leader.y - follower.y).signum();
+            on_cell(*follower);
+            moved = true;
+        }
+        return moved;
+    }
+}
+
+// keeps touching the leader with horizontal and vertical steps only, along the largest gap
+// first, horizontally on ties
+struct Orthogonal {}
+
+impl FollowRule for Orthogonal {
+    fn name(&self) -> String {
+        return String::from("orthogonal");
+    }
+
+    fn follow(&self, leader: Point, follower: &mut Point, on_cell: &mut dyn FnMut(Point)) -> bool {
+        let mut moved: bool = false;
+        while distance_chebyshev(&leader, follower) >= 2 {
+            let (dx, dy) = (leader.x - follower.x, leader.y - follower.y);
+            if dx.abs() >= dy.abs() {
+                follower.x += dx.signum();
+            } else {
+                follower.y += dy.signum();
+            }
+            on_cell(*follower);
+            moved = true;
+        }
+        return moved;
+    }
+}
+
+// the rope stretches, the follower lagging behind by up to `lag` cells, then it snaps back
+// against the leader
+struct Stretch {
+    lag: i32,
+}
+
+impl FollowRule for Stretch {
+    fn name(&self) -> String {
+        return format!("stretch up to {}", self.lag);
+    }
+
+    fn follow(&self, leader: Point, follower: &mut Point, on_cell: &mut dyn FnMut(Point)) -> bool {
+        if distance_chebyshev(&leader, follower) <= self.lag.max(1) {
+            return false;
+        }
+        while knot_follow(leader, follower) {
+            on_cell(*follower);
+        }
+        return true;
+    }
+}
+
+// `touching`, `slack:N`, `orthogonal` or `stretch:N`
+fn follow_rule_get(name: &str) -> Option<Box<dyn FollowRule>> {
+    if name == "touching" {
+        return Some(Box::new(Touching {}));
+    } else if name == "orthogonal" {
+        return Some(Box::new(Orthogonal {}));
+    } else if let Some(distance) = name.strip_prefix("slack:") {
+        return match distance.parse::<i32>() {
+            Ok(distance) if distance > 0 => Some(Box::new(Slack { distance: distance })),
+            _ => None,
+        };
+    } else if let Some(lag) = name.strip_prefix("stretch:") {
+        return match lag.parse::<i32>() {
+            Ok(lag) if lag > 0 => Some(Box::new(Stretch { lag: lag })),
+            _ => None,
+        };
+    } else {
+        return None;
+    }
+}
+
+// head step of a motion and how many times it is taken
+type Motion = (Point, usize);
+
+// motions a repeat block can expand to
+const MOTIONS_MAX: usize = 10_000_000;
+// head steps the motions can add up to, the rope running every one of them
+const STEPS_MAX: usize = 10_000_000;
+
+// R, D, L, U or a diagonal made of a vertical and an horizontal one in any order (UR, RU, DL...)
+fn direction_parse(direction: &str) -> Option<Point> {
+    let mut head_move: Point = Point::new();
+    for c in direction.chars() {
+        match c {
+            'R' if head_move.x == 0 => head_move.x = 1,
+            'L' if head_move.x == 0 => head_move.x = -1,
+            'U' if head_move.y == 0 => head_move.y = 1,
+            'D' if head_move.y == 0 => head_move.y = -1,
+            _ => return None,
+        }
+    }
+    if head_move == Point::new() {
+        return None;
+    }
+    return Some(head_move);
+}
+
+// words with their line number, braces being words of their own and comments starting with '#'
+fn tokens_get(input: &str) -> Vec<(usize, &str)> {
+    let mut tokens: Vec<(usize, &str)> = Vec::new();
+    for (i, line) in input.split('\n').enumerate() {
+        let line: &str = line.split('#').next().unwrap();
+        let mut start: Option<usize> = None;
+        for (position, c) in line.char_indices() {
+            if c.is_whitespace() || c == '{' || c == '}' {
+                if let Some(s) = start.take() {
+                    tokens.push((i + 1, &line[s..position]));
+                }
+                if c == '{' || c == '}' {
+                    tokens.push((i + 1, &line[position..position + 1]));
+                }
+            } else if start.is_none() {
+                start = Some(position);
+            }
+        }
+        if let Some(s) = start {
+            tokens.push((i + 1, &line[s..]));
+        }
+    }
+    return tokens;
+}
+
+// motions up to the closing brace of a block, or to the end at the top level, `steps` being the
+// head steps of `motions`
+fn sequence_parse(
+    tokens: &[(usize, &str)],
+    position: &mut usize,
+    block_line: Option<usize>,
+    motions: &mut Vec<Motion>,
+    steps: &mut usize,
+) -> Result<(), String> {
+    while *position < tokens.len() {
+        let (line, token) = tokens[*position];
+        *position += 1;
+        if token == "}" {
+            if block_line.is_none() {
+                return Err(format!("line {}: unexpected '}}'", line));
+            }
+            return Ok(());
+        }
+        if let Some(times) = token.strip_suffix('x') {
+            // repeat block, unrolled
+            let times: usize = times
+                .parse::<usize>()
+                .map_err(|_| format!("line {}: invalid repeat count '{}'", line, token))?;
+            if tokens.get(*position).map(|t| t.1) != Some("{") {
+                return Err(format!("line {}: expected '{{' after '{}'", line, token));
+            }
+            *position += 1;
+            let mut block: Vec<Motion> = Vec::new();
+            let mut block_steps: usize = 0;
+            sequence_parse(tokens, position, Some(line), &mut block, &mut block_steps)?;
+            if motions.len() + block.len().saturating_mul(times) > MOTIONS_MAX {
+                return Err(format!("line {}: more than {} motions", line, MOTIONS_MAX));
+            }
+            *steps = steps.saturating_add(block_steps.saturating_mul(times));
+            if *steps > STEPS_MAX {
+                return Err(format!("line {}: more than {} steps", line, STEPS_MAX));
+            }
+            for _ in 0..times {
+                motions.extend_from_slice(&block);
+            }
+            continue;
         }
-        digit_count += 1;
+        let head_move: Point = direction_parse(token)
+            .ok_or_else(|| format!("line {}: invalid direction '{}'", line, token))?;
+        let count: &str = match tokens.get(*position) {
+            Some((count_line, count)) if *count_line == line => count,
+            _ => return Err(format!("line {}: missing count after '{}'", line, token)),
+        };
+        *position += 1;
+        // a negative count goes the other way
+        let count: i64 = count
+            .parse::<i64>()
+            .map_err(|_| format!("line {}: invalid count '{}'", line, count))?;
+        let head_move: Point = if count < 0 {
+            Point {
+                x: -head_move.x,
+                y: -head_move.y,
+            }
+        } else {
+            head_move
+        };
+        let count: usize = count.unsigned_abs() as usize;
+        motions.push((head_move, count));
+        if motions.len() > MOTIONS_MAX {
+            return Err(format!("line {}: more than {} motions", line, MOTIONS_MAX));
+        }
+        *steps = steps.saturating_add(count);
+        if *steps > STEPS_MAX {
+            return Err(format!("line {}: more than {} steps", line, STEPS_MAX));
+        }
+    }
+    if let Some(line) = block_line {
+        return Err(format!("line {}: unclosed repeat block", line));
+    }
+    return Ok(());
+}
+
+// `R 4` motions as in the puzzle, with diagonals, negative counts, `#` comments and repeat
+// blocks such as `3x { R 2 U 1 }` which can be nested and span several lines
+fn motions_parse(input: &str) -> Result<Vec<Motion>, String> {
+    let tokens: Vec<(usize, &str)> = tokens_get(input);
+    let mut motions: Vec<Motion> = Vec::new();
+    let mut position: usize = 0;
+    let mut steps: usize = 0;
+    sequence_parse(&tokens, &mut position, None, &mut motions, &mut steps)?;
+    return Ok(motions);
+}
+
+struct Rope {
+    // the head first
+    knots: Vec<Point>,
+    // positions visited by every knot, in the same order
+    visited: Vec<std::collections::HashSet<Point>>,
+    rule: Box<dyn FollowRule>,
+}
+
+impl Rope {
+    fn new(knots_count: usize) -> Rope {
+        return Rope::with_rule(knots_count, Box::new(Touching {}));
+    }
+
+    fn with_rule(knots_count: usize, rule: Box<dyn FollowRule>) -> Rope {
+        assert!(knots_count > 0);
+        let start: Point = Point::new();
+        return Rope {
+            knots: vec![start; knots_count],
+            visited: vec![std::collections::HashSet::from([start]); knots_count],
+            rule: rule,
+        };
+    }
+
+    fn step(&mut self, head_move: Point) {
+        self.knots[0] += head_move;
+        self.visited[0].insert(self.knots[0]);
+        for i in 1..self.knots.len() {
+            let leader: Point = self.knots[i - 1];
+            let visited: &mut std::collections::HashSet<Point> = &mut self.visited[i];
+            if !self.rule.follow(leader, &mut self.knots[i], &mut |cell| {
+                visited.insert(cell);
+            }) {
+                // the next knots can't move either
+                break;
+            }
+        }
+    }
+
+    fn run(&mut self, motions: &[Motion]) {
+        for (head_move, count) in motions {
+            for _ in 0..*count {
+                self.step(*head_move);
+            }
+        }
+    }
+}
+
+// count of positions visited by the tail of a rope of `knots_count` knots
+fn count_visited(input: &str, knots_count: usize) -> Result<usize, String> {
+    let mut rope: Rope = Rope::new(knots_count);
+    rope.run(&motions_parse(input)?);
+    return Ok(rope.visited[knots_count - 1].len());
+}
+
+fn motion_title(motion: &Motion) -> String {
+    let (head_move, count) = motion;
+    let mut direction: String = String::new();
+    if head_move.y != 0 {
+        direction.push(if head_move.y > 0 { 'U' } else { 'D' });
+    }
+    if head_move.x != 0 {
+        direction.push(if head_move.x > 0 { 'R' } else { 'L' });
+    }
+    return format!("{} {}", direction, count);
+}
+
+// H for the head, T for the tail of a two knots rope, the knot index otherwise
+fn knot_label(index: usize, knots_count: usize) -> char {
+    if index == 0 {
+        return 'H';
+    }
+    if knots_count == 2 {
+        return 'T';
     }
-    let n = number
-        .get(0..digit_count)
-        .unwrap()
-        .parse::<usize>()
-        .unwrap();
+    return std::char::from_digit(index as u32, 36).unwrap_or('?');
+}
+
+// cells shown in a frame, the start being at the origin
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Viewport {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
 
-    return (n, digit_count);
+impl Viewport {
+    // smallest viewport showing every point and the start
+    fn fit<'a>(points: impl Iterator<Item = &'a Point>) -> Viewport {
+        let mut viewport: Viewport = Viewport {
+            x_min: 0,
+            x_max: 0,
+            y_min: 0,
+            y_max: 0,
+        };
+        for p in points {
+            viewport.x_min = viewport.x_min.min(p.x);
+            viewport.x_max = viewport.x_max.max(p.x);
+            viewport.y_min = viewport.y_min.min(p.y);
+            viewport.y_max = viewport.y_max.max(p.y);
+        }
+        return viewport;
+    }
 }
 
-fn move_knots(
-    head: &mut Point,
-    head_move: Point,
-    count: usize,
-    tail: &mut Point,
-    visited: &mut std::collections::HashSet<Point>,
-) {
-    for _ in 0..count {
-        *head += head_move;
-        let distance = distance_compte(head, tail);
-        if distance >= 2.0 {
-            if head.x > tail.x {
-                tail.x += 1;
-            } else if head.x < tail.x {
-                tail.x -= 1
+// knots drawn over the start, the first knot on a cell covering the next ones and the start,
+// which is noted at the end of the row
+fn frame_render(knots: &[Point], viewport: &Viewport) -> String {
+    let mut frame: String = String::new();
+    for y in (viewport.y_min..=viewport.y_max).rev() {
+        let mut covers: Vec<String> = Vec::new();
+        for x in viewport.x_min..=viewport.x_max {
+            let mut labels: Vec<String> = Vec::new();
+            for (i, knot) in knots.iter().enumerate() {
+                if knot.x == x && knot.y == y {
+                    labels.push(knot_label(i, knots.len()).to_string());
+                }
+            }
+            if x == 0 && y == 0 {
+                labels.push(String::from("s"));
             }
-            if head.y > tail.y {
-                tail.y += 1;
-            } else if head.y < tail.y {
-                tail.y -= 1
+            match labels.first() {
+                Some(label) => frame.push_str(label),
+                None => frame.push('.'),
             }
-            visited.insert(*tail);
+            if labels.len() > 1 {
+                covers.push(format!("({} covers {})", labels[0], labels[1..].join(", ")));
+            }
+        }
+        for cover in covers {
+            frame.push_str("  ");
+            frame.push_str(&cover);
+        }
+        frame.push('\n');
+    }
+    return frame;
+}
+
+// # for the visited cells, s for the start
+fn visited_render(visited: &std::collections::HashSet<Point>, viewport: &Viewport) -> String {
+    let mut map: String = String::new();
+    for y in (viewport.y_min..=viewport.y_max).rev() {
+        for x in viewport.x_min..=viewport.x_max {
+            map.push(if x == 0 && y == 0 {
+                's'
+            } else if visited.contains(&Point { x, y }) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        map.push('\n');
+    }
+    return map;
+}
+
+// frames of the steps of `rope` in `steps`, 0 being the initial state, under the title of their
+// motion, only the last step of every motion unless `every_step`, the viewport fitting every frame
+// unless given
+fn frames_render(
+    motions: &[Motion],
+    mut rope: Rope,
+    steps: std::ops::Range<usize>,
+    every_step: bool,
+    viewport: Option<Viewport>,
+) -> String {
+    // title and knots of every frame shown
+    let mut frames: Vec<(Option<String>, Vec<Point>)> = Vec::new();
+    if steps.contains(&0) {
+        frames.push((Some(String::from("Initial State")), rope.knots.clone()));
+    }
+    let mut step: usize = 0;
+    for motion in motions {
+        let mut title: Option<String> = Some(motion_title(motion));
+        for i in 0..motion.1 {
+            rope.step(motion.0);
+            step += 1;
+            if steps.contains(&step) && (every_step || i + 1 == motion.1) {
+                frames.push((title.take(), rope.knots.clone()));
+            }
+        }
+        if step >= steps.end {
+            break;
+        }
+    }
+
+    let viewport: Viewport =
+        viewport.unwrap_or_else(|| Viewport::fit(frames.iter().flat_map(|(_, knots)| knots)));
+    let mut text: String = String::new();
+    for (i, (title, knots)) in frames.iter().enumerate() {
+        if let Some(title) = title {
+            if i > 0 {
+                text.push('\n');
+            }
+            text.push_str(&format!("== {} ==\n", title));
+        }
+        text.push('\n');
+        text.push_str(&frame_render(knots, &viewport));
+    }
+    return text;
+}
+
+fn number_arg(arg: Option<&String>, what: &str) -> i32 {
+    match arg.map(|a| a.parse::<i32>()) {
+        Some(Ok(n)) => return n,
+        _ => {
+            eprintln!("expected {}", what);
+            std::process::exit(1);
         }
     }
 }
 
-fn count_visited(input: &str) -> usize {
-    let mut visited: std::collections::HashSet<Point> = std::collections::HashSet::new();
-
-    let mut head: Point = Point::new();
-    let mut tail: Point = Point::new();
-    visited.insert(tail);
-    let commands: Vec<&str> = input.split('\n').collect();
-    for command in commands {
-        assert!(command.chars().nth(1).unwrap() == ' ');
-        let direction: char = command.chars().nth(0).unwrap();
-        let (move_count, chars_read_count) = number_parse(command.get(2..).unwrap());
-        assert!(chars_read_count + 2 == command.len());
-        match direction {
-            'R' => move_knots(
-                &mut head,
-                Point { x: 1, y: 0 },
-                move_count,
-                &mut tail,
-                &mut visited,
-            ),
-            'D' => move_knots(
-                &mut head,
-                Point { x: 0, y: -1 },
-                move_count,
-                &mut tail,
-                &mut visited,
-            ),
-            'L' => move_knots(
-                &mut head,
-                Point { x: -1, y: 0 },
-                move_count,
-                &mut tail,
-                &mut visited,
-            ),
-            'U' => move_knots(
-                &mut head,
-                Point { x: 0, y: 1 },
-                move_count,
-                &mut tail,
-                &mut visited,
-            ),
-            _ => assert!(false),
+fn follow_rule_arg(arg: Option<&String>) -> Box<dyn FollowRule> {
+    match arg.and_then(|a| follow_rule_get(a)) {
+        Some(rule) => return rule,
+        None => {
+            eprintln!("expected touching, slack:N, orthogonal or stretch:N");
+            std::process::exit(1);
         }
     }
+}
 
-    return visited.len();
+// `render KNOTS [--steps FROM TO] [--every-step] [--view X_MIN X_MAX Y_MIN Y_MAX]`,
+// `visited KNOTS [KNOT]` (the tail by default), both with `[--rule RULE]`, or
+// `compare KNOTS [RULE...]`, reading the motions on stdin
+fn render_run(args: &[String]) {
+    let knots_count: i32 = number_arg(args.get(1), "a count of knots");
+    if knots_count < 1 {
+        eprintln!("expected a count of knots");
+        std::process::exit(1);
+    }
+    let knots_count: usize = knots_count as usize;
+
+    let mut steps: std::ops::Range<usize> = 0..usize::MAX;
+    let mut every_step: bool = false;
+    let mut viewport: Option<Viewport> = None;
+    let mut rule: Box<dyn FollowRule> = Box::new(Touching {});
+    let mut positional: Vec<&String> = Vec::new();
+    let mut i: usize = 2;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--steps" => {
+                let from: i32 = number_arg(args.get(i + 1), "the first step");
+                let to: i32 = number_arg(args.get(i + 2), "the last step");
+                steps = from.max(0) as usize..to.max(0) as usize + 1;
+                i += 2;
+            }
+            "--every-step" => every_step = true,
+            "--view" => {
+                viewport = Some(Viewport {
+                    x_min: number_arg(args.get(i + 1), "the viewport bounds"),
+                    x_max: number_arg(args.get(i + 2), "the viewport bounds"),
+                    y_min: number_arg(args.get(i + 3), "the viewport bounds"),
+                    y_max: number_arg(args.get(i + 4), "the viewport bounds"),
+                });
+                i += 4;
+            }
+            "--rule" => {
+                rule = follow_rule_arg(args.get(i + 1));
+                i += 1;
+            }
+            _ => positional.push(&args[i]),
+        }
+        i += 1;
+    }
+
+    let mut input: String = String::new();
+    std::io::Read::read_to_string(&mut std::io::stdin(), &mut input).unwrap();
+    let motions: Vec<Motion> = match motions_parse(&input) {
+        Ok(motions) => motions,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+
+    match args[0].as_str() {
+        "visited" => {
+            let knot: i32 = match positional.first() {
+                Some(knot) => number_arg(Some(knot), "a knot index"),
+                None => knots_count as i32 - 1,
+            };
+            if knot < 0 || knot as usize >= knots_count {
+                eprintln!("no knot {} in a rope of {}", knot, knots_count);
+                std::process::exit(1);
+            }
+            let mut rope: Rope = Rope::with_rule(knots_count, rule);
+            rope.run(&motions);
+            let visited: &std::collections::HashSet<Point> = &rope.visited[knot as usize];
+            print!(
+                "{}",
+                visited_render(visited, &Viewport::fit(visited.iter()))
+            );
+            println!("{} positions visited", visited.len());
+        }
+        "compare" => {
+            let names: Vec<String> = match positional.is_empty() {
+                true => ["touching", "slack:2", "orthogonal", "stretch:3"]
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect(),
+                false => positional.iter().map(|n| n.to_string()).collect(),
+            };
+            for name in names {
+                let mut rope: Rope = Rope::with_rule(knots_count, follow_rule_arg(Some(&name)));
+                rope.run(&motions);
+                println!(
+                    "{}: {} positions visited by the tail",
+                    rope.rule.name(),
+                    rope.visited[knots_count - 1].len()
+                );
+            }
+        }
+        _ => print!(
+            "{}",
+            frames_render(
+                &motions,
+                Rope::with_rule(knots_count, rule),
+                steps,
+                every_step,
+                viewport
+            )
+        ),
+    }
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if matches!(
+        args.first().map(|a| a.as_str()),
+        Some("render" | "visited" | "compare")
+    ) {
+        render_run(&args);
+        return;
+    }
+
     // example
-    assert_eq!(13, count_visited("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2"));
-    assert_eq!(6266, count_visited("D 2\nU 1\nD 2\nR 2\nL 1\nU 2\nL 1\nU 2\nD 1\nR 1\nD 2\nR 1\nD 1\nU 2\nD 2\nR 1\nU 1\nR 1\nU 2\nR 1\nL 1\nU 2\nR 1\nL 2\nU 1\nR 1\nU 1\nR 1\nL 2\nU 2\nD 1\nU 1\nD 2\nL 1\nU 1\nL 1\nR 1\nD 1\nR 1\nD 1\nU 2\nD 1\nR 1\nU 1\nL 1\nU 2\nL 1\nD 1\nU 1\nD 1\nL 1\nU 2\nL 1\nD 2\nR 2\nL 1\nR 2\nD 1\nL 2\nD 2\nU 2\nR 1\nL 1\nD 2\nU 2\nL 1\nD 2\nU 1\nL 2\nR 2\nU 1\nD 1\nU 1\nD 1\nR 1\nD 1\nU 2\nD 1\nR 1\nD 1\nL 1\nD 1\nL 2\nU 2\nD 1\nR 1\nD 1\nL 1\nD 2\nR 2\nU 2\nD 1\nU 2\nD 1\nU 1\nL 1\nR 2\nD 2\nR 1\nD 2\nL 2\nD 2\nR 1\nL 1\nU 1\nD 2\nR 2\nU 2\nR 2\nL 1\nR 1\nL 3\nU 3\nL 3\nD 2\nU 1\nD 1\nU 2\nD 1\nR 1\nD 1\nR 2\nD 3\nU 1\nL 2\nU 1\nD 2\nR 1\nD 1\nL 2\nR 2\nU 1\nL 2\nD 2\nL 1\nU 2\nD 3\nL 1\nU 1\nR 2\nU 3\nD 1\nL 3\nU 1\nL 3\nD 1\nR 2\nL 3\nU 2\nL 2\nU 1\nL 1\nR 3\nD 3\nL 1\nR 2\nD 3\nR 2\nL 3\nD 1\nU 2\nR 3\nU 2\nR 3\nL 2\nU 1\nL 1\nD 3\nU 1\nD 1\nU 2\nD 3\nU 3\nR 1\nU 2\nL 1\nD 1\nL 2\nU 1\nD 1\nL 1\nR 3\nU 3\nL 3\nD 3\nL 2\nD 1\nU 1\nL 1\nU 3\nL 1\nD 3\nR 1\nL 1\nR 2\nU 1\nR 2\nU 3\nR 1\nD 3\nL 3\nU 2\nL 3\nR 1\nD 2\nU 3\nR 3\nD 3\nU 1\nR 3\nD 2\nR 3\nU 1\nD 1\nU 3\nR 1\nL 1\nU 2\nL 1\nU 1\nL 1\nR 1\nD 1\nR 3\nD 4\nL 2\nR 4\nU 3\nD 1\nR 4\nL 4\nR 1\nD 2\nU 3\nR 2\nD 2\nU 3\nR 4\nL 2\nD 3\nL 4\nD 4\nU 4\nR 2\nU 1\nL 3\nD 4\nU 2\nR 1\nL 3\nD 3\nR 4\nD 2\nR 2\nD 4\nU 4\nL 4\nD 4\nR 2\nD 2\nU 3\nD 3\nR 3\nL 2\nD 2\nL 2\nU 3\nL 4\nU 2\nR 4\nD 4\nL 3\nR 1\nU 1\nR 2\nL 1\nD 1\nU 2\nD 3\nL 4\nU 3\nL 1\nU 3\nR 2\nU 3\nR 1\nU 1\nR 2\nU 2\nR 1\nD 4\nU 4\nL 2\nU 2\nD 4\nU 1\nR 4\nL 4\nD 4\nU 3\nD 4\nL 4\nU 3\nL 1\nR 4\nL 3\nD 3\nU 1\nR 4\nD 3\nU 4\nL 2\nU 4\nD 3\nR 2\nD 3\nU 4\nR 4\nL 4\nR 3\nU 1\nR 4\nD 1\nL 3\nU 3\nL 3\nD 1\nL 4\nR 2\nU 1\nL 3\nR 2\nU 4\nL 1\nU 5\nR 2\nL 2\nU 2\nL 2\nR 4\nD 4\nL 5\nD 1\nR 4\nU 5\nL 1\nD 4\nU 1\nL 4\nU 3\nL 1\nR 3\nD 1\nR 2\nL 5\nU 3\nL 5\nU 4\nD 3\nL 5\nD 2\nR 4\nU 2\nL 1\nR 4\nL 5\nR 3\nL 1\nU 2\nL 4\nD 1\nR 2\nL 5\nU 3\nD 2\nR 5\nL 4\nD 2\nR 3\nD 2\nR 4\nD 3\nU 1\nD 1\nU 4\nD 2\nL 2\nR 1\nU 5\nD 5\nU 3\nR 2\nU 3\nL 5\nR 1\nU 3\nL 4\nR 2\nU 3\nD 1\nU 2\nD 2\nL 5\nR 5\nD 3\nL 1\nR 3\nU 2\nD 5\nU 1\nD 5\nR 3\nU 1\nL 5\nR 1\nD 4\nL 4\nD 1\nU 2\nL 4\nR 5\nU 5\nL 4\nD 2\nR 5\nU 1\nL 5\nD 1\nL 1\nU 5\nR 1\nL 2\nR 5\nL 5\nR 2\nL 1\nD 2\nU 1\nL 3\nR 1\nU 5\nD 1\nU 1\nL 5\nU 6\nR 6\nU 5\nD 5\nR 3\nU 1\nR 1\nL 6\nR 6\nD 5\nR 6\nD 4\nL 3\nR 6\nU 5\nD 6\nL 2\nR 1\nD 5\nR 3\nD 2\nL 1\nR 5\nU 5\nD 4\nU 3\nR 5\nU 5\nR 3\nD 5\nR 3\nU 3\nR 2\nD 5\nL 6\nD 3\nU 3\nD 6\nR 5\nU 4\nL 4\nD 3\nU 6\nL 2\nR 1\nU 4\nD 1\nL 2\nU 1\nL 3\nD 4\nU 1\nL 5\nD 1\nR 2\nU 3\nL 1\nR 1\nU 1\nL 4\nU 6\nR 6\nD 3\nU 4\nR 2\nD 5\nL 6\nD 2\nR 4\nD 2\nU 5\nD 3\nU 2\nR 6\nL 5\nR 6\nU 1\nL 5\nU 1\nL 6\nU 1\nD 4\nR 3\nD 3\nL 1\nD 3\nR 2\nL 1\nR 3\nD 4\nL 6\nU 2\nD 5\nU 3\nR 6\nU 2\nR 6\nL 5\nR 1\nU 2\nR 3\nU 1\nR 6\nL 2\nU 6\nR 5\nU 1\nR 4\nL 1\nR 3\nU 3\nL 5\nD 7\nR 2\nD 4\nR 5\nL 3\nR 6\nU 7\nL 7\nU 6\nL 2\nD 2\nR 7\nD 7\nU 3\nR 1\nU 7\nL 2\nD 3\nL 5\nR 4\nU 7\nR 3\nL 7\nD 2\nR 7\nD 5\nL 7\nD 1\nU 7\nR 5\nU 4\nD 4\nU 4\nD 4\nU 1\nR 7\nL 3\nD 3\nU 2\nL 3\nU 1\nD 7\nL 6\nR 3\nU 6\nD 1\nR 6\nD 1\nR 1\nD 7\nU 4\nR 1\nL 4\nU 3\nL 7\nR 2\nD 2\nL 6\nU 2\nR 2\nU 7\nR 5\nL 6\nR 5\nD 1\nR 4\nU 2\nL 1\nD 4\nU 5\nR 4\nD 7\nU 7\nR 1\nU 3\nR 4\nL 1\nR 6\nL 4\nU 4\nL 2\nU 3\nD 2\nL 2\nU 2\nL 6\nR 5\nD 7\nL 2\nU 5\nL 6\nR 7\nL 7\nD 2\nU 6\nD 3\nR 1\nU 1\nD 4\nL 7\nD 7\nU 5\nL 6\nD 1\nL 4\nD 1\nU 1\nL 5\nU 1\nR 5\nD 8\nR 7\nL 5\nU 3\nD 2\nR 2\nL 6\nR 1\nL 4\nR 8\nU 8\nL 5\nR 7\nD 7\nL 2\nR 5\nL 6\nD 2\nL 7\nU 3\nR 2\nD 3\nU 3\nL 6\nU 5\nD 3\nL 6\nU 5\nD 5\nL 1\nU 4\nL 8\nD 3\nL 3\nR 2\nU 6\nD 8\nU 7\nL 6\nD 2\nU 4\nD 1\nU 3\nL 6\nR 2\nD 2\nU 4\nR 8\nD 3\nR 6\nD 6\nU 1\nL 4\nU 3\nL 6\nD 8\nU 7\nD 5\nL 3\nU 4\nD 2\nR 7\nL 8\nU 1\nR 3\nD 5\nU 5\nD 6\nR 3\nL 2\nR 8\nL 3\nU 5\nL 3\nU 5\nL 7\nU 7\nL 2\nU 1\nL 5\nD 7\nU 7\nD 8\nR 2\nD 7\nU 4\nR 4\nU 6\nL 2\nU 8\nD 1\nU 1\nD 4\nL 4\nU 1\nL 5\nR 8\nU 5\nD 4\nR 2\nD 6\nL 1\nD 8\nU 4\nD 5\nL 8\nU 3\nL 5\nD 1\nU 3\nR 1\nD 7\nR 9\nU 8\nR 7\nL 3\nD 8\nU 9\nD 1\nU 7\nL 6\nU 4\nR 8\nU 1\nD 6\nR 3\nL 5\nD 3\nU 1\nL 3\nU 2\nL 3\nU 2\nR 7\nD 2\nR 7\nD 8\nR 1\nD 4\nR 1\nD 6\nR 8\nU 8\nL 2\nU 8\nL 5\nD 1\nU 7\nR 4\nL 9\nU 2\nL 3\nU 4\nL 2\nD 3\nL 4\nU 9\nD 2\nR 2\nU 9\nR 1\nL 4\nD 5\nR 9\nU 3\nL 3\nU 8\nR 5\nU 7\nD 5\nU 9\nR 3\nD 9\nR 1\nD 7\nR 3\nD 6\nL 6\nD 5\nU 9\nD 8\nU 4\nL 5\nR 6\nD 7\nR 6\nL 8\nR 3\nU 3\nR 9\nU 8\nR 6\nL 9\nU 2\nR 9\nL 8\nU 1\nR 9\nL 5\nD 5\nU 9\nL 6\nD 7\nU 3\nD 3\nR 4\nD 1\nL 4\nD 5\nL 4\nU 2\nR 9\nU 7\nL 4\nD 7\nU 9\nR 4\nD 7\nR 7\nD 4\nL 1\nD 2\nL 1\nU 7\nR 8\nD 2\nU 9\nD 6\nL 7\nR 5\nL 3\nR 5\nD 8\nL 3\nR 8\nL 6\nU 6\nL 6\nU 5\nR 7\nL 9\nU 1\nR 3\nD 7\nU 10\nD 1\nU 8\nD 8\nL 5\nU 6\nD 5\nU 9\nR 8\nD 8\nR 9\nD 2\nL 1\nD 8\nR 2\nD 6\nL 10\nR 1\nU 3\nL 8\nU 7\nR 7\nU 7\nD 6\nL 7\nU 3\nL 4\nR 9\nD 8\nU 4\nD 3\nL 8\nR 7\nU 1\nL 9\nD 10\nU 2\nL 5\nU 3\nL 4\nR 10\nL 2\nD 6\nL 6\nD 2\nU 5\nD 3\nR 9\nD 2\nU 1\nD 5\nU 9\nD 7\nR 2\nU 5\nR 4\nD 9\nU 6\nL 10\nD 3\nU 1\nL 1\nD 9\nU 2\nL 8\nU 3\nR 5\nU 7\nD 8\nR 5\nL 8\nU 9\nL 1\nD 5\nU 8\nR 4\nD 7\nU 4\nR 10\nU 9\nR 9\nU 7\nR 3\nU 10\nR 5\nU 7\nR 6\nU 10\nR 9\nU 8\nD 8\nL 1\nU 2\nD 1\nR 9\nU 10\nD 6\nL 3\nD 4\nR 11\nD 11\nR 7\nU 3\nL 3\nU 11\nD 11\nU 5\nR 9\nU 3\nR 4\nD 1\nU 11\nR 2\nL 7\nR 8\nL 2\nD 4\nU 4\nL 9\nR 10\nL 3\nR 7\nD 7\nL 9\nU 6\nL 5\nD 8\nU 4\nD 4\nL 4\nD 2\nU 8\nR 4\nD 4\nU 10\nD 5\nR 5\nD 5\nU 8\nD 2\nL 6\nU 8\nL 2\nD 8\nR 5\nD 5\nU 1\nD 1\nL 9\nR 7\nU 2\nL 7\nR 1\nL 1\nU 2\nR 10\nD 2\nU 8\nL 1\nR 6\nL 3\nR 4\nD 6\nL 2\nD 11\nR 8\nD 11\nL 3\nR 4\nL 10\nU 7\nL 9\nU 4\nL 8\nD 6\nU 8\nR 7\nL 5\nR 8\nL 4\nU 6\nR 5\nU 7\nD 3\nL 8\nU 2\nR 7\nD 3\nR 4\nD 11\nL 8\nU 6\nD 1\nR 11\nL 7\nD 4\nR 4\nL 4\nU 5\nL 8\nR 7\nU 8\nD 12\nR 9\nD 8\nR 7\nL 9\nR 2\nD 1\nL 7\nD 3\nL 1\nR 5\nU 1\nD 12\nR 4\nU 1\nD 4\nL 9\nU 10\nR 5\nD 11\nR 2\nL 3\nU 8\nR 5\nD 8\nU 12\nD 6\nR 2\nD 5\nR 12\nU 7\nL 2\nU 4\nD 5\nU 8\nR 12\nU 1\nL 11\nU 7\nD 10\nL 6\nD 6\nR 1\nL 6\nD 2\nU 5\nR 6\nD 8\nR 4\nL 7\nD 2\nL 7\nD 8\nU 10\nR 1\nU 6\nR 5\nL 4\nR 8\nD 9\nR 5\nU 11\nD 4\nU 11\nR 8\nL 8\nU 2\nR 11\nL 12\nU 7\nL 12\nU 10\nR 3\nD 11\nR 1\nL 8\nD 2\nL 5\nR 4\nU 3\nD 9\nR 8\nD 11\nL 12\nR 11\nL 4\nR 10\nD 6\nU 4\nR 6\nD 6\nU 9\nR 12\nU 5\nR 8\nL 10\nD 1\nU 7\nD 12\nL 3\nU 10\nD 2\nU 6\nD 12\nR 3\nU 2\nD 2\nU 3\nD 11\nR 2\nU 2\nD 9\nR 11\nD 4\nU 6\nR 11\nL 9\nR 10\nD 13\nL 2\nD 2\nL 4\nD 13\nR 7\nL 3\nU 8\nD 5\nR 7\nD 7\nU 13\nD 5\nU 5\nL 12\nD 9\nL 2\nD 6\nU 13\nR 2\nD 10\nL 9\nD 7\nR 2\nU 3\nD 2\nL 3\nR 9\nU 3\nL 12\nU 4\nR 1\nU 13\nR 4\nU 1\nD 10\nR 10\nD 10\nU 5\nL 7\nD 8\nR 1\nD 12\nL 13\nR 10\nD 6\nR 9\nU 4\nR 9\nU 1\nL 3\nR 3\nD 8\nU 6\nL 11\nR 8\nD 5\nU 2\nL 9\nR 7\nL 7\nD 8\nL 12\nR 12\nU 9\nD 9\nU 7\nD 10\nL 3\nR 3\nD 8\nL 11\nU 8\nD 7\nU 11\nL 4\nD 4\nR 3\nU 4\nR 6\nD 5\nU 5\nL 8\nU 4\nL 9\nU 6\nL 8\nD 3\nU 12\nD 5\nL 8\nD 11\nU 4\nR 12\nU 7\nL 5\nD 4\nR 4\nU 7\nR 9\nL 5\nU 2\nL 3\nD 2\nR 6\nU 2\nR 8\nD 10\nU 14\nL 3\nR 4\nU 10\nR 7\nL 11\nR 7\nU 4\nR 8\nL 14\nD 11\nU 12\nR 10\nL 8\nR 11\nL 4\nU 1\nL 2\nR 7\nU 13\nD 5\nU 2\nL 6\nD 7\nR 5\nU 4\nD 10\nU 5\nD 2\nR 9\nU 13\nL 10\nU 1\nR 10\nD 14\nL 5\nR 12\nL 7\nD 2\nL 2\nD 5\nR 4\nU 6\nD 7\nR 12\nU 9\nD 9\nR 6\nU 1\nD 14\nU 14\nD 14\nL 7\nR 10\nU 3\nD 2\nR 10\nL 14\nR 7\nD 5\nU 1\nL 8\nU 2\nD 3\nU 6\nL 11\nR 13\nL 10\nD 9\nU 5\nR 6\nU 2\nD 7\nU 5\nL 6\nR 8\nD 11\nR 14\nD 5\nR 10\nL 13\nR 1\nU 4\nL 9\nR 2\nD 1\nU 10\nD 6\nU 3\nR 13\nL 9\nR 2\nD 3\nR 14\nL 2\nR 14\nU 1\nR 7\nL 1\nU 6\nD 6\nR 10\nL 12\nD 10\nR 11\nD 13\nL 3\nR 2\nL 1\nR 10\nU 1\nL 7\nR 5\nD 6\nU 11\nL 8\nR 7\nD 10\nU 13\nR 12\nD 8\nL 7\nU 8\nR 14\nU 10\nL 6\nD 15\nR 8\nL 10\nU 13\nL 3\nU 13\nL 9\nR 4\nD 6\nR 12\nL 12\nR 4\nU 12\nD 6\nR 2\nL 11\nR 7\nD 1\nU 4\nR 14\nU 6\nD 1\nU 15\nD 14\nR 3\nD 14\nL 1\nR 9\nL 2\nD 11\nR 11\nD 10\nR 1\nL 9\nU 6\nR 8\nL 4\nR 9\nU 13\nD 15\nL 2\nD 2\nL 2\nD 6\nR 10\nU 10\nL 8\nD 11\nL 8\nR 10\nU 3\nR 7\nU 15\nR 11\nL 10\nU 2\nR 8\nL 8\nD 5\nR 11\nU 7\nL 13\nD 15\nU 4\nD 10\nR 11\nD 6\nL 2\nU 3\nR 2\nL 1\nD 14\nU 5\nL 14\nD 13\nR 12\nU 8\nR 1\nL 14\nU 6\nD 10\nR 3\nU 8\nL 2\nR 6\nD 10\nU 9\nL 2\nD 13\nL 13\nD 9\nL 10\nU 14\nR 11\nL 14\nU 10\nL 6\nD 14\nL 11\nR 4\nL 9\nU 3\nD 13\nL 5\nR 16\nU 3\nL 9\nD 6\nR 15\nU 6\nL 4\nR 10\nD 12\nR 4\nD 2\nU 11\nD 13\nR 6\nU 15\nR 14\nD 14\nU 8\nR 9\nL 5\nD 16\nU 9\nR 15\nD 13\nR 3\nL 2\nR 3\nU 14\nD 9\nU 2\nD 6\nU 9\nD 7\nL 13\nD 7\nL 5\nR 3\nU 2\nL 13\nR 9\nD 1\nL 16\nD 8\nR 12\nU 15\nR 4\nD 15\nL 12\nD 13\nR 4\nL 2\nU 5\nR 13\nL 15\nD 12\nL 3\nD 13\nL 5\nD 16\nL 11\nD 13\nU 1\nD 9\nL 10\nU 11\nD 12\nU 11\nD 14\nU 16\nD 14\nU 10\nR 7\nL 11\nR 14\nU 11\nR 5\nU 1\nD 16\nR 16\nL 9\nU 7\nD 7\nR 8\nD 7\nU 10\nL 7\nD 6\nU 4\nD 2\nL 3\nD 3\nL 2\nR 3\nL 9\nD 2\nR 12\nD 11\nU 11\nL 14\nU 4\nL 12\nR 13\nL 9\nD 11\nR 12\nL 15\nR 15\nL 15\nR 16\nU 17\nD 4\nL 9\nU 9\nD 17\nL 8\nU 15\nD 17\nU 9\nR 4\nU 6\nL 1\nD 7\nR 3\nD 11\nL 10\nR 1\nU 14\nD 13\nR 7\nL 8\nU 16\nL 16\nU 12\nD 3\nR 4\nD 8\nR 8\nD 1\nL 12\nR 16\nD 3\nR 7\nD 16\nU 11\nR 6\nD 13\nR 4\nL 3\nR 6\nU 1\nR 4\nD 4\nU 13\nL 7\nD 17\nR 4\nD 6\nL 1\nU 17\nL 16\nU 12\nL 11\nR 1\nL 11\nD 8\nU 15\nD 6\nL 5\nU 16\nR 6\nD 11\nL 17\nD 7\nL 1\nD 8\nL 16\nU 8\nR 4\nL 17\nD 2\nL 1\nU 16\nD 8\nL 17\nU 12\nD 4\nR 1\nD 12\nU 2\nL 11\nU 17\nL 3\nR 7\nL 10\nU 16\nR 8\nU 6\nL 3\nD 15\nL 11\nR 7\nU 14\nR 8\nL 9\nD 6\nL 3\nU 17\nR 1\nD 13\nU 15\nL 18\nU 11\nR 3\nU 16\nD 12\nR 1\nD 6\nL 17\nR 9\nL 6\nU 2\nD 2\nU 16\nD 12\nR 1\nU 17\nR 16\nD 12\nU 3\nD 11\nL 13\nU 11\nR 15\nU 13\nD 11\nU 8\nL 3\nD 1\nL 7\nR 9\nD 18\nR 17\nU 2\nL 16\nD 8\nL 14\nD 11\nR 4\nD 12\nU 5\nD 6\nU 2\nD 4\nL 1\nR 11\nD 3\nU 10\nL 1\nR 16\nU 10\nL 17\nD 5\nR 16\nU 18\nR 1\nD 16\nR 14\nD 11\nU 13\nD 13\nR 5\nL 9\nU 11\nR 18\nL 14\nU 14\nL 18\nR 15\nD 10\nU 10\nR 17\nU 12\nL 2\nD 11\nR 15\nU 3\nR 17\nL 1\nR 1\nU 1\nL 8\nU 15\nR 15\nL 12\nU 2\nR 1\nL 9\nR 3\nL 6\nR 18\nD 9\nR 12\nU 6\nL 17\nR 11\nD 8\nR 17\nD 12\nL 2\nR 9\nU 4\nD 17\nR 18\nD 12\nL 9\nR 16\nU 5\nR 1\nL 12\nR 9\nL 8\nU 8\nR 2\nU 9\nL 2\nD 18\nU 11\nL 17\nU 3\nL 11\nR 3\nL 5\nD 1\nR 4\nU 10\nD 18\nR 10\nD 10\nL 5\nR 7\nD 2\nR 2\nL 18\nU 4\nD 10\nU 16\nD 1\nL 2\nU 17\nD 5\nU 9\nR 13\nD 6\nR 14\nD 10\nL 15\nR 13\nL 6\nU 11\nR 6\nL 4\nR 19\nL 4\nD 13\nR 1\nL 9\nD 4\nR 12\nU 18\nD 3\nU 17\nR 19\nL 6\nR 6\nD 8\nR 12\nL 10\nR 3\nU 5\nL 2\nD 7\nL 9\nD 16\nL 16\nU 14\nL 12\nR 2\nL 17\nR 18\nD 12\nR 16\nD 19\nU 6\nD 19\nU 13\nR 4\nD 18\nL 14\nU 14\nD 12\nR 10\nL 13\nD 10\nU 10\nD 2\nU 18\nL 19\nR 15\nD 6\nL 1\nD 10\nR 7\nD 2\nR 19\nD 5\nU 19\nL 13\nU 17\nL 3\nR 19\nU 9\nL 7\nD 6\nR 12\nL 10\nR 9\nD 9\nU 13\nR 7\nL 1\nU 1\nD 17"));
+    assert_eq!(
+        Ok(13),
+        count_visited("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2", 2)
+    );
+    assert_eq!(
+        Ok(1),
+        count_visited("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2", 10)
+    );
+    assert_eq!(
+        Ok(36),
+        count_visited("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20", 10)
+    );
+
+    //// user puzzle input, both parts from one simulation: the second knot of the long rope
+    //// moves like the tail of the short one
+    let motions: Vec<Motion> = motions_parse("D 2\nU 1\nD 2\nR 2\nL 1\nU 2\nL 1\nU 2\nD 1\nR 1\nD 2\nR 1\nD 1\nU 2\nD 2\nR 1\nU 1\nR 1\nU 2\nR 1\nL 1\nU 2\nR 1\nL 2\nU 1\nR 1\nU 1\nR 1\nL 2\nU 2\nD 1\nU 1\nD 2\nL 1\nU 1\nL 1\nR 1\nD 1\nR 1\nD 1\nU 2\nD 1\nR 1\nU 1\nL 1\nU 2\nL 1\nD 1\nU 1\nD 1\nL 1\nU 2\nL 1\nD 2\nR 2\nL 1\nR 2\nD 1\nL 2\nD 2\nU 2\nR 1\nL 1\nD 2\nU 2\nL 1\nD 2\nU 1\nL 2\nR 2\nU 1\nD 1\nU 1\nD 1\nR 1\nD 1\nU 2\nD 1\nR 1\nD 1\nL 1\nD 1\nL 2\nU 2\nD 1\nR 1\nD 1\nL 1\nD 2\nR 2\nU 2\nD 1\nU 2\nD 1\nU 1\nL 1\nR 2\nD 2\nR 1\nD 2\nL 2\nD 2\nR 1\nL 1\nU 1\nD 2\nR 2\nU 2\nR 2\nL 1\nR 1\nL 3\nU 3\nL 3\nD 2\nU 1\nD 1\nU 2\nD 1\nR 1\nD 1\nR 2\nD 3\nU 1\nL 2\nU 1\nD 2\nR 1\nD 1\nL 2\nR 2\nU 1\nL 2\nD 2\nL 1\nU 2\nD 3\nL 1\nU 1\nR 2\nU 3\nD 1\nL 3\nU 1\nL 3\nD 1\nR 2\nL 3\nU 2\nL 2\nU 1\nL 1\nR 3\nD 3\nL 1\nR 2\nD 3\nR 2\nL 3\nD 1\nU 2\nR 3\nU 2\nR 3\nL 2\nU 1\nL 1\nD 3\nU 1\nD 1\nU 2\nD 3\nU 3\nR 1\nU 2\nL 1\nD 1\nL 2\nU 1\nD 1\nL 1\nR 3\nU 3\nL 3\nD 3\nL 2\nD 1\nU 1\nL 1\nU 3\nL 1\nD 3\nR 1\nL 1\nR 2\nU 1\nR 2\nU 3\nR 1\nD 3\nL 3\nU 2\nL 3\nR 1\nD 2\nU 3\nR 3\nD 3\nU 1\nR 3\nD 2\nR 3\nU 1\nD 1\nU 3\nR 1\nL 1\nU 2\nL 1\nU 1\nL 1\nR 1\nD 1\nR 3\nD 4\nL 2\nR 4\nU 3\nD 1\nR 4\nL 4\nR 1\nD 2\nU 3\nR 2\nD 2\nU 3\nR 4\nL 2\nD 3\nL 4\nD 4\nU 4\nR 2\nU 1\nL 3\nD 4\nU 2\nR 1\nL 3\nD 3\nR 4\nD 2\nR 2\nD 4\nU 4\nL 4\nD 4\nR 2\nD 2\nU 3\nD 3\nR 3\nL 2\nD 2\nL 2\nU 3\nL 4\nU 2\nR 4\nD 4\nL 3\nR 1\nU 1\nR 2\nL 1\nD 1\nU 2\nD 3\nL 4\nU 3\nL 1\nU 3\nR 2\nU 3\nR 1\nU 1\nR 2\nU 2\nR 1\nD 4\nU 4\nL 2\nU 2\nD 4\nU 1\nR 4\nL 4\nD 4\nU 3\nD 4\nL 4\nU 3\nL 1\nR 4\nL 3\nD 3\nU 1\nR 4\nD 3\nU 4\nL 2\nU 4\nD 3\nR 2\nD 3\nU 4\nR 4\nL 4\nR 3\nU 1\nR 4\nD 1\nL 3\nU 3\nL 3\nD 1\nL 4\nR 2\nU 1\nL 3\nR 2\nU 4\nL 1\nU 5\nR 2\nL 2\nU 2\nL 2\nR 4\nD 4\nL 5\nD 1\nR 4\nU 5\nL 1\nD 4\nU 1\nL 4\nU 3\nL 1\nR 3\nD 1\nR 2\nL 5\nU 3\nL 5\nU 4\nD 3\nL 5\nD 2\nR 4\nU 2\nL 1\nR 4\nL 5\nR 3\nL 1\nU 2\nL 4\nD 1\nR 2\nL 5\nU 3\nD 2\nR 5\nL 4\nD 2\nR 3\nD 2\nR 4\nD 3\nU 1\nD 1\nU 4\nD 2\nL 2\nR 1\nU 5\nD 5\nU 3\nR 2\nU 3\nL 5\nR 1\nU 3\nL 4\nR 2\nU 3\nD 1\nU 2\nD 2\nL 5\nR 5\nD 3\nL 1\nR 3\nU 2\nD 5\nU 1\nD 5\nR 3\nU 1\nL 5\nR 1\nD 4\nL 4\nD 1\nU 2\nL 4\nR 5\nU 5\nL 4\nD 2\nR 5\nU 1\nL 5\nD 1\nL 1\nU 5\nR 1\nL 2\nR 5\nL 5\nR 2\nL 1\nD 2\nU 1\nL 3\nR 1\nU 5\nD 1\nU 1\nL 5\nU 6\nR 6\nU 5\nD 5\nR 3\nU 1\nR 1\nL 6\nR 6\nD 5\nR 6\nD 4\nL 3\nR 6\nU 5\nD 6\nL 2\nR 1\nD 5\nR 3\nD 2\nL 1\nR 5\nU 5\nD 4\nU 3\nR 5\nU 5\nR 3\nD 5\nR 3\nU 3\nR 2\nD 5\nL 6\nD 3\nU 3\nD 6\nR 5\nU 4\nL 4\nD 3\nU 6\nL 2\nR 1\nU 4\nD 1\nL 2\nU 1\nL 3\nD 4\nU 1\nL 5\nD 1\nR 2\nU 3\nL 1\nR 1\nU 1\nL 4\nU 6\nR 6\nD 3\nU 4\nR 2\nD 5\nL 6\nD 2\nR 4\nD 2\nU 5\nD 3\nU 2\nR 6\nL 5\nR 6\nU 1\nL 5\nU 1\nL 6\nU 1\nD 4\nR 3\nD 3\nL 1\nD 3\nR 2\nL 1\nR 3\nD 4\nL 6\nU 2\nD 5\nU 3\nR 6\nU 2\nR 6\nL 5\nR 1\nU 2\nR 3\nU 1\nR 6\nL 2\nU 6\nR 5\nU 1\nR 4\nL 1\nR 3\nU 3\nL 5\nD 7\nR 2\nD 4\nR 5\nL 3\nR 6\nU 7\nL 7\nU 6\nL 2\nD 2\nR 7\nD 7\nU 3\nR 1\nU 7\nL 2\nD 3\nL 5\nR 4\nU 7\nR 3\nL 7\nD 2\nR 7\nD 5\nL 7\nD 1\nU 7\nR 5\nU 4\nD 4\nU 4\nD 4\nU 1\nR 7\nL 3\nD 3\nU 2\nL 3\nU 1\nD 7\nL 6\nR 3\nU 6\nD 1\nR 6\nD 1\nR 1\nD 7\nU 4\nR 1\nL 4\nU 3\nL 7\nR 2\nD 2\nL 6\nU 2\nR 2\nU 7\nR 5\nL 6\nR 5\nD 1\nR 4\nU 2\nL 1\nD 4\nU 5\nR 4\nD 7\nU 7\nR 1\nU 3\nR 4\nL 1\nR 6\nL 4\nU 4\nL 2\nU 3\nD 2\nL 2\nU 2\nL 6\nR 5\nD 7\nL 2\nU 5\nL 6\nR 7\nL 7\nD 2\nU 6\nD 3\nR 1\nU 1\nD 4\nL 7\nD 7\nU 5\nL 6\nD 1\nL 4\nD 1\nU 1\nL 5\nU 1\nR 5\nD 8\nR 7\nL 5\nU 3\nD 2\nR 2\nL 6\nR 1\nL 4\nR 8\nU 8\nL 5\nR 7\nD 7\nL 2\nR 5\nL 6\nD 2\nL 7\nU 3\nR 2\nD 3\nU 3\nL 6\nU 5\nD 3\nL 6\nU 5\nD 5\nL 1\nU 4\nL 8\nD 3\nL 3\nR 2\nU 6\nD 8\nU 7\nL 6\nD 2\nU 4\nD 1\nU 3\nL 6\nR 2\nD 2\nU 4\nR 8\nD 3\nR 6\nD 6\nU 1\nL 4\nU 3\nL 6\nD 8\nU 7\nD 5\nL 3\nU 4\nD 2\nR 7\nL 8\nU 1\nR 3\nD 5\nU 5\nD 6\nR 3\nL 2\nR 8\nL 3\nU 5\nL 3\nU 5\nL 7\nU 7\nL 2\nU 1\nL 5\nD 7\nU 7\nD 8\nR 2\nD 7\nU 4\nR 4\nU 6\nL 2\nU 8\nD 1\nU 1\nD 4\nL 4\nU 1\nL 5\nR 8\nU 5\nD 4\nR 2\nD 6\nL 1\nD 8\nU 4\nD 5\nL 8\nU 3\nL 5\nD 1\nU 3\nR 1\nD 7\nR 9\nU 8\nR 7\nL 3\nD 8\nU 9\nD 1\nU 7\nL 6\nU 4\nR 8\nU 1\nD 6\nR 3\nL 5\nD 3\nU 1\nL 3\nU 2\nL 3\nU 2\nR 7\nD 2\nR 7\nD 8\nR 1\nD 4\nR 1\nD 6\nR 8\nU 8\nL 2\nU 8\nL 5\nD 1\nU 7\nR 4\nL 9\nU 2\nL 3\nU 4\nL 2\nD 3\nL 4\nU 9\nD 2\nR 2\nU 9\nR 1\nL 4\nD 5\nR 9\nU 3\nL 3\nU 8\nR 5\nU 7\nD 5\nU 9\nR 3\nD 9\nR 1\nD 7\nR 3\nD 6\nL 6\nD 5\nU 9\nD 8\nU 4\nL 5\nR 6\nD 7\nR 6\nL 8\nR 3\nU 3\nR 9\nU 8\nR 6\nL 9\nU 2\nR 9\nL 8\nU 1\nR 9\nL 5\nD 5\nU 9\nL 6\nD 7\nU 3\nD 3\nR 4\nD 1\nL 4\nD 5\nL 4\nU 2\nR 9\nU 7\nL 4\nD 7\nU 9\nR 4\nD 7\nR 7\nD 4\nL 1\nD 2\nL 1\nU 7\nR 8\nD 2\nU 9\nD 6\nL 7\nR 5\nL 3\nR 5\nD 8\nL 3\nR 8\nL 6\nU 6\nL 6\nU 5\nR 7\nL 9\nU 1\nR 3\nD 7\nU 10\nD 1\nU 8\nD 8\nL 5\nU 6\nD 5\nU 9\nR 8\nD 8\nR 9\nD 2\nL 1\nD 8\nR 2\nD 6\nL 10\nR 1\nU 3\nL 8\nU 7\nR 7\nU 7\nD 6\nL 7\nU 3\nL 4\nR 9\nD 8\nU 4\nD 3\nL 8\nR 7\nU 1\nL 9\nD 10\nU 2\nL 5\nU 3\nL 4\nR 10\nL 2\nD 6\nL 6\nD 2\nU 5\nD 3\nR 9\nD 2\nU 1\nD 5\nU 9\nD 7\nR 2\nU 5\nR 4\nD 9\nU 6\nL 10\nD 3\nU 1\nL 1\nD 9\nU 2\nL 8\nU 3\nR 5\nU 7\nD 8\nR 5\nL 8\nU 9\nL 1\nD 5\nU 8\nR 4\nD 7\nU 4\nR 10\nU 9\nR 9\nU 7\nR 3\nU 10\nR 5\nU 7\nR 6\nU 10\nR 9\nU 8\nD 8\nL 1\nU 2\nD 1\nR 9\nU 10\nD 6\nL 3\nD 4\nR 11\nD 11\nR 7\nU 3\nL 3\nU 11\nD 11\nU 5\nR 9\nU 3\nR 4\nD 1\nU 11\nR 2\nL 7\nR 8\nL 2\nD 4\nU 4\nL 9\nR 10\nL 3\nR 7\nD 7\nL 9\nU 6\nL 5\nD 8\nU 4\nD 4\nL 4\nD 2\nU 8\nR 4\nD 4\nU 10\nD 5\nR 5\nD 5\nU 8\nD 2\nL 6\nU 8\nL 2\nD 8\nR 5\nD 5\nU 1\nD 1\nL 9\nR 7\nU 2\nL 7\nR 1\nL 1\nU 2\nR 10\nD 2\nU 8\nL 1\nR 6\nL 3\nR 4\nD 6\nL 2\nD 11\nR 8\nD 11\nL 3\nR 4\nL 10\nU 7\nL 9\nU 4\nL 8\nD 6\nU 8\nR 7\nL 5\nR 8\nL 4\nU 6\nR 5\nU 7\nD 3\nL 8\nU 2\nR 7\nD 3\nR 4\nD 11\nL 8\nU 6\nD 1\nR 11\nL 7\nD 4\nR 4\nL 4\nU 5\nL 8\nR 7\nU 8\nD 12\nR 9\nD 8\nR 7\nL 9\nR 2\nD 1\nL 7\nD 3\nL 1\nR 5\nU 1\nD 12\nR 4\nU 1\nD 4\nL 9\nU 10\nR 5\nD 11\nR 2\nL 3\nU 8\nR 5\nD 8\nU 12\nD 6\nR 2\nD 5\nR 12\nU 7\nL 2\nU 4\nD 5\nU 8\nR 12\nU 1\nL 11\nU 7\nD 10\nL 6\nD 6\nR 1\nL 6\nD 2\nU 5\nR 6\nD 8\nR 4\nL 7\nD 2\nL 7\nD 8\nU 10\nR 1\nU 6\nR 5\nL 4\nR 8\nD 9\nR 5\nU 11\nD 4\nU 11\nR 8\nL 8\nU 2\nR 11\nL 12\nU 7\nL 12\nU 10\nR 3\nD 11\nR 1\nL 8\nD 2\nL 5\nR 4\nU 3\nD 9\nR 8\nD 11\nL 12\nR 11\nL 4\nR 10\nD 6\nU 4\nR 6\nD 6\nU 9\nR 12\nU 5\nR 8\nL 10\nD 1\nU 7\nD 12\nL 3\nU 10\nD 2\nU 6\nD 12\nR 3\nU 2\nD 2\nU 3\nD 11\nR 2\nU 2\nD 9\nR 11\nD 4\nU 6\nR 11\nL 9\nR 10\nD 13\nL 2\nD 2\nL 4\nD 13\nR 7\nL 3\nU 8\nD 5\nR 7\nD 7\nU 13\nD 5\nU 5\nL 12\nD 9\nL 2\nD 6\nU 13\nR 2\nD 10\nL 9\nD 7\nR 2\nU 3\nD 2\nL 3\nR 9\nU 3\nL 12\nU 4\nR 1\nU 13\nR 4\nU 1\nD 10\nR 10\nD 10\nU 5\nL 7\nD 8\nR 1\nD 12\nL 13\nR 10\nD 6\nR 9\nU 4\nR 9\nU 1\nL 3\nR 3\nD 8\nU 6\nL 11\nR 8\nD 5\nU 2\nL 9\nR 7\nL 7\nD 8\nL 12\nR 12\nU 9\nD 9\nU 7\nD 10\nL 3\nR 3\nD 8\nL 11\nU 8\nD 7\nU 11\nL 4\nD 4\nR 3\nU 4\nR 6\nD 5\nU 5\nL 8\nU 4\nL 9\nU 6\nL 8\nD 3\nU 12\nD 5\nL 8\nD 11\nU 4\nR 12\nU 7\nL 5\nD 4\nR 4\nU 7\nR 9\nL 5\nU 2\nL 3\nD 2\nR 6\nU 2\nR 8\nD 10\nU 14\nL 3\nR 4\nU 10\nR 7\nL 11\nR 7\nU 4\nR 8\nL 14\nD 11\nU 12\nR 10\nL 8\nR 11\nL 4\nU 1\nL 2\nR 7\nU 13\nD 5\nU 2\nL 6\nD 7\nR 5\nU 4\nD 10\nU 5\nD 2\nR 9\nU 13\nL 10\nU 1\nR 10\nD 14\nL 5\nR 12\nL 7\nD 2\nL 2\nD 5\nR 4\nU 6\nD 7\nR 12\nU 9\nD 9\nR 6\nU 1\nD 14\nU 14\nD 14\nL 7\nR 10\nU 3\nD 2\nR 10\nL 14\nR 7\nD 5\nU 1\nL 8\nU 2\nD 3\nU 6\nL 11\nR 13\nL 10\nD 9\nU 5\nR 6\nU 2\nD 7\nU 5\nL 6\nR 8\nD 11\nR 14\nD 5\nR 10\nL 13\nR 1\nU 4\nL 9\nR 2\nD 1\nU 10\nD 6\nU 3\nR 13\nL 9\nR 2\nD 3\nR 14\nL 2\nR 14\nU 1\nR 7\nL 1\nU 6\nD 6\nR 10\nL 12\nD 10\nR 11\nD 13\nL 3\nR 2\nL 1\nR 10\nU 1\nL 7\nR 5\nD 6\nU 11\nL 8\nR 7\nD 10\nU 13\nR 12\nD 8\nL 7\nU 8\nR 14\nU 10\nL 6\nD 15\nR 8\nL 10\nU 13\nL 3\nU 13\nL 9\nR 4\nD 6\nR 12\nL 12\nR 4\nU 12\nD 6\nR 2\nL 11\nR 7\nD 1\nU 4\nR 14\nU 6\nD 1\nU 15\nD 14\nR 3\nD 14\nL 1\nR 9\nL 2\nD 11\nR 11\nD 10\nR 1\nL 9\nU 6\nR 8\nL 4\nR 9\nU 13\nD 15\nL 2\nD 2\nL 2\nD 6\nR 10\nU 10\nL 8\nD 11\nL 8\nR 10\nU 3\nR 7\nU 15\nR 11\nL 10\nU 2\nR 8\nL 8\nD 5\nR 11\nU 7\nL 13\nD 15\nU 4\nD 10\nR 11\nD 6\nL 2\nU 3\nR 2\nL 1\nD 14\nU 5\nL 14\nD 13\nR 12\nU 8\nR 1\nL 14\nU 6\nD 10\nR 3\nU 8\nL 2\nR 6\nD 10\nU 9\nL 2\nD 13\nL 13\nD 9\nL 10\nU 14\nR 11\nL 14\nU 10\nL 6\nD 14\nL 11\nR 4\nL 9\nU 3\nD 13\nL 5\nR 16\nU 3\nL 9\nD 6\nR 15\nU 6\nL 4\nR 10\nD 12\nR 4\nD 2\nU 11\nD 13\nR 6\nU 15\nR 14\nD 14\nU 8\nR 9\nL 5\nD 16\nU 9\nR 15\nD 13\nR 3\nL 2\nR 3\nU 14\nD 9\nU 2\nD 6\nU 9\nD 7\nL 13\nD 7\nL 5\nR 3\nU 2\nL 13\nR 9\nD 1\nL 16\nD 8\nR 12\nU 15\nR 4\nD 15\nL 12\nD 13\nR 4\nL 2\nU 5\nR 13\nL 15\nD 12\nL 3\nD 13\nL 5\nD 16\nL 11\nD 13\nU 1\nD 9\nL 10\nU 11\nD 12\nU 11\nD 14\nU 16\nD 14\nU 10\nR 7\nL 11\nR 14\nU 11\nR 5\nU 1\nD 16\nR 16\nL 9\nU 7\nD 7\nR 8\nD 7\nU 10\nL 7\nD 6\nU 4\nD 2\nL 3\nD 3\nL 2\nR 3\nL 9\nD 2\nR 12\nD 11\nU 11\nL 14\nU 4\nL 12\nR 13\nL 9\nD 11\nR 12\nL 15\nR 15\nL 15\nR 16\nU 17\nD 4\nL 9\nU 9\nD 17\nL 8\nU 15\nD 17\nU 9\nR 4\nU 6\nL 1\nD 7\nR 3\nD 11\nL 10\nR 1\nU 14\nD 13\nR 7\nL 8\nU 16\nL 16\nU 12\nD 3\nR 4\nD 8\nR 8\nD 1\nL 12\nR 16\nD 3\nR 7\nD 16\nU 11\nR 6\nD 13\nR 4\nL 3\nR 6\nU 1\nR 4\nD 4\nU 13\nL 7\nD 17\nR 4\nD 6\nL 1\nU 17\nL 16\nU 12\nL 11\nR 1\nL 11\nD 8\nU 15\nD 6\nL 5\nU 16\nR 6\nD 11\nL 17\nD 7\nL 1\nD 8\nL 16\nU 8\nR 4\nL 17\nD 2\nL 1\nU 16\nD 8\nL 17\nU 12\nD 4\nR 1\nD 12\nU 2\nL 11\nU 17\nL 3\nR 7\nL 10\nU 16\nR 8\nU 6\nL 3\nD 15\nL 11\nR 7\nU 14\nR 8\nL 9\nD 6\nL 3\nU 17\nR 1\nD 13\nU 15\nL 18\nU 11\nR 3\nU 16\nD 12\nR 1\nD 6\nL 17\nR 9\nL 6\nU 2\nD 2\nU 16\nD 12\nR 1\nU 17\nR 16\nD 12\nU 3\nD 11\nL 13\nU 11\nR 15\nU 13\nD 11\nU 8\nL 3\nD 1\nL 7\nR 9\nD 18\nR 17\nU 2\nL 16\nD 8\nL 14\nD 11\nR 4\nD 12\nU 5\nD 6\nU 2\nD 4\nL 1\nR 11\nD 3\nU 10\nL 1\nR 16\nU 10\nL 17\nD 5\nR 16\nU 18\nR 1\nD 16\nR 14\nD 11\nU 13\nD 13\nR 5\nL 9\nU 11\nR 18\nL 14\nU 14\nL 18\nR 15\nD 10\nU 10\nR 17\nU 12\nL 2\nD 11\nR 15\nU 3\nR 17\nL 1\nR 1\nU 1\nL 8\nU 15\nR 15\nL 12\nU 2\nR 1\nL 9\nR 3\nL 6\nR 18\nD 9\nR 12\nU 6\nL 17\nR 11\nD 8\nR 17\nD 12\nL 2\nR 9\nU 4\nD 17\nR 18\nD 12\nL 9\nR 16\nU 5\nR 1\nL 12\nR 9\nL 8\nU 8\nR 2\nU 9\nL 2\nD 18\nU 11\nL 17\nU 3\nL 11\nR 3\nL 5\nD 1\nR 4\nU 10\nD 18\nR 10\nD 10\nL 5\nR 7\nD 2\nR 2\nL 18\nU 4\nD 10\nU 16\nD 1\nL 2\nU 17\nD 5\nU 9\nR 13\nD 6\nR 14\nD 10\nL 15\nR 13\nL 6\nU 11\nR 6\nL 4\nR 19\nL 4\nD 13\nR 1\nL 9\nD 4\nR 12\nU 18\nD 3\nU 17\nR 19\nL 6\nR 6\nD 8\nR 12\nL 10\nR 3\nU 5\nL 2\nD 7\nL 9\nD 16\nL 16\nU 14\nL 12\nR 2\nL 17\nR 18\nD 12\nR 16\nD 19\nU 6\nD 19\nU 13\nR 4\nD 18\nL 14\nU 14\nD 12\nR 10\nL 13\nD 10\nU 10\nD 2\nU 18\nL 19\nR 15\nD 6\nL 1\nD 10\nR 7\nD 2\nR 19\nD 5\nU 19\nL 13\nU 17\nL 3\nR 19\nU 9\nL 7\nD 6\nR 12\nL 10\nR 9\nD 9\nU 13\nR 7\nL 1\nU 1\nD 17").unwrap();
+    let mut rope: Rope = Rope::new(10);
+    rope.run(&motions);
+    assert_eq!(6266, rope.visited[1].len());
+    assert_eq!(2369, rope.visited[9].len());
+
+    // every knot has its own visited positions
+    let mut rope: Rope = Rope::new(10);
+    rope.run(&motions_parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n").unwrap());
+    let counts: Vec<usize> = rope.visited.iter().map(|v| v.len()).collect();
+    assert_eq!(vec![96, 88, 80, 72, 64, 56, 50, 46, 41, 36], counts);
+    assert_eq!(Point { x: -11, y: 15 }, rope.knots[0]);
+    // a single knot is its own tail
+    assert_eq!(Ok(5), count_visited("R 4", 1));
+    let mut follower: Point = Point { x: 1, y: 1 };
+    assert!(!knot_follow(Point { x: 0, y: 0 }, &mut follower));
+    assert!(knot_follow(Point { x: 3, y: 2 }, &mut follower));
+    assert_eq!(Point { x: 2, y: 2 }, follower);
+    assert_eq!(
+        Err(String::from("line 2: invalid direction 'X'")),
+        motions_parse("R 1\nX 3")
+    );
+    assert_eq!(
+        Err(String::from("line 1: missing count after 'R'")),
+        motions_parse("R\n2")
+    );
+    assert_eq!(
+        Err(String::from("line 3: invalid count 'a'")),
+        motions_parse("R 1\n\nU a")
+    );
+
+    // frames as drawn in the puzzle
+    let example: Vec<Motion> = motions_parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap();
+    let viewport: Viewport = Viewport {
+        x_min: 0,
+        x_max: 5,
+        y_min: 0,
+        y_max: 4,
+    };
+    assert_eq!(
+        "== Initial State ==\n\n......\n......\n......\n......\nH.....  (H covers T, s)\n\n== R 4 ==\n\n......\n......\n......\n......\nTH....  (T covers s)\n\n......\n......\n......\n......\nsTH...\n\n......\n......\n......\n......\ns.TH..\n\n......\n......\n......\n......\ns..TH.\n",
+        frames_render(&example, Rope::new(2), 0..5, true, Some(viewport))
+    );
+    // the viewport fits the frames shown
+    assert_eq!(
+        "== Initial State ==\n\nH....  (H covers T, s)\n\n== R 4 ==\n\nTH...  (T covers s)\n\nsTH..\n\ns.TH.\n\ns..TH\n",
+        frames_render(&example, Rope::new(2), 0..5, true, None)
+    );
+    // a range in the middle of a motion keeps the title
+    assert_eq!(
+        "== U 4 ==\n\n......\n....H.\n....T.\n......\ns.....\n",
+        frames_render(&example, Rope::new(2), 7..8, true, Some(viewport))
+    );
+    let mut rope: Rope = Rope::new(2);
+    rope.run(&example);
+    assert_eq!(viewport, Viewport::fit(rope.visited[0].iter()));
+    assert_eq!(
+        "..##..\n...##.\n.####.\n....#.\ns###..\n",
+        visited_render(&rope.visited[1], &viewport)
+    );
+    let larger: Vec<Motion> = motions_parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20").unwrap();
+    let viewport: Viewport = Viewport {
+        x_min: -11,
+        x_max: 14,
+        y_min: -5,
+        y_max: 15,
+    };
+    let frames: String = frames_render(&larger, Rope::new(10), 0..14, false, Some(viewport));
+    let row: String = ".".repeat(26) + "\n";
+    assert_eq!(
+        format!(
+            "== Initial State ==\n\n{}...........H..............  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)\n{}\n== R 5 ==\n\n{}...........54321H.........  (5 covers 6, 7, 8, 9, s)\n{}\n== U 8 ==\n\n{}................H.........\n................1.........\n................2.........\n................3.........\n...............54.........\n..............6...........\n.............7............\n............8.............\n...........9..............  (9 covers s)\n{}",
+            row.repeat(15),
+            row.repeat(5),
+            row.repeat(15),
+            row.repeat(5),
+            row.repeat(7),
+            row.repeat(5)
+        ),
+        frames
+    );
+    let mut rope: Rope = Rope::new(10);
+    rope.run(&larger);
+    assert_eq!(
+        "..........................\n..........................\n..........................\n..........................\n..........................\n..........................\n..........................\n..........................\n..........................\n#.........................\n#.............###.........\n#............#...#........\n.#..........#.....#.......\n..#..........#.....#......\n...#........#.......#.....\n....#......s.........#....\n.....#..............#.....\n......#............#......\n.......#..........#.......\n........#........#........\n.........########.........\n",
+        visited_render(&rope.visited[9], &viewport)
+    );
+
+    // extended motions
+    let up: Point = Point { x: 0, y: 1 };
+    let down_left: Point = Point { x: -1, y: -1 };
+    assert_eq!(
+        Ok(vec![
+            (down_left, 2),
+            (down_left, 1),
+            (Point { x: 1, y: 1 }, 3),
+            (up, 0)
+        ]),
+        motions_parse("DL 2\nLD 1 # same direction\nDL -3\n# nothing\nU 0\n")
+    );
+    assert_eq!(
+        Ok(vec![
+            (Point { x: 1, y: 0 }, 2),
+            (up, 1),
+            (Point { x: 1, y: 0 }, 2),
+            (up, 1),
+            (down_left, 4),
+            (down_left, 4),
+            (down_left, 4),
+            (down_left, 4),
+        ]),
+        motions_parse("2x { R 2 U 1 }\n2x {\n  2x{DL 4}\n}")
+    );
+    assert_eq!(Ok(vec![]), motions_parse("0x { R 2 }"));
+    assert_eq!(
+        Some(&(Point { x: 1, y: -1 }, 2)),
+        motions_parse("3x { R 2 U 1 } UL -2").unwrap().get(6)
+    );
+    assert_eq!(
+        Err(String::from("line 2: unclosed repeat block")),
+        motions_parse("R 1\n3x { R 2\nU 1")
+    );
+    assert_eq!(
+        Err(String::from("line 1: unexpected '}'")),
+        motions_parse("R 1 }")
+    );
+    assert_eq!(
+        Err(String::from("line 1: expected '{' after '3x'")),
+        motions_parse("3x R 2")
+    );
+    assert_eq!(
+        Err(String::from("line 1: invalid repeat count '-3x'")),
+        motions_parse("-3x { R 2 }")
+    );
+    assert_eq!(
+        Err(String::from("line 2: invalid direction 'RL'")),
+        motions_parse("R 1\nRL 2")
+    );
+    assert_eq!(
+        Err(String::from("line 1: more than 10000000 motions")),
+        motions_parse("100000x { 1000x { R 1 } }")
+    );
+    // the head steps are capped too, whatever the number of motions
+    assert_eq!(
+        Err(String::from("line 1: more than 10000000 steps")),
+        motions_parse("R 9999999999")
+    );
+    assert_eq!(
+        Err(String::from("line 2: more than 10000000 steps")),
+        motions_parse("R 1\n1000x { R 100000 }")
+    );
+    assert_eq!(
+        Err(String::from("line 2: more than 10000000 steps")),
+        motions_parse("L -9999999\nU 2")
+    );
+    assert_eq!(
+        Err(String::from("line 1: more than 10000000 steps")),
+        motions_parse("1000x { R 100000000 }")
+    );
+    assert!(motions_parse("10x { R 1000000 }").is_ok());
+    assert_eq!("UR 3", motion_title(&(Point { x: 1, y: 1 }, 3)));
+    // a diagonal head drags the tail diagonally
+    let mut rope: Rope = Rope::new(2);
+    rope.run(&motions_parse("UR 3").unwrap());
+    assert_eq!(vec![Point { x: 3, y: 3 }, Point { x: 2, y: 2 }], rope.knots);
+    assert_eq!(3, rope.visited[1].len());
+
+    // follow rules
+    let tail_visited = |knots_count: usize, name: &str, motions: &[Motion]| -> usize {
+        let mut rope: Rope = Rope::with_rule(knots_count, follow_rule_get(name).unwrap());
+        rope.run(motions);
+        return rope.visited[knots_count - 1].len();
+    };
+    assert_eq!(13, tail_visited(2, "slack:1", &example));
+    assert_eq!(7, tail_visited(2, "slack:2", &example));
+    assert_eq!(10, tail_visited(2, "orthogonal", &example));
+    assert_eq!(13, tail_visited(2, "stretch:1", &example));
+    assert_eq!(10, tail_visited(2, "stretch:2", &example));
+    assert_eq!(36, tail_visited(10, "touching", &larger));
+    assert_eq!(6, tail_visited(10, "slack:2", &larger));
+    assert_eq!(14, tail_visited(10, "orthogonal", &larger));
+    assert_eq!(31, tail_visited(10, "stretch:3", &larger));
+    assert!(follow_rule_get("slack:0").is_none());
+    assert!(follow_rule_get("stretch").is_none());
+    assert_eq!(
+        "stretch up to 3",
+        follow_rule_get("stretch:3").unwrap().name()
+    );
+    // every cell crossed is visited, not only the one where the follower stops
+    let mut follower: Point = Point { x: 0, y: 0 };
+    let mut cells: Vec<Point> = Vec::new();
+    assert!(
+        Orthogonal {}.follow(Point { x: 2, y: 2 }, &mut follower, &mut |cell| {
+            cells.push(cell)
+        })
+    );
+    assert_eq!(vec![Point { x: 1, y: 0 }, Point { x: 1, y: 1 }], cells);
+    assert_eq!(Point { x: 1, y: 1 }, follower);
+    assert!(!Orthogonal {}.follow(Point { x: 2, y: 2 }, &mut follower, &mut |_| {}));
+    let mut cells: Vec<Point> = Vec::new();
+    let mut follower: Point = Point { x: 0, y: 0 };
+    assert!(
+        Stretch { lag: 3 }.follow(Point { x: 4, y: 1 }, &mut follower, &mut |cell| {
+            cells.push(cell)
+        })
+    );
+    assert_eq!(
+        vec![
+            Point { x: 1, y: 1 },
+            Point { x: 2, y: 1 },
+            Point { x: 3, y: 1 }
+        ],
+        cells
+    );
+    // the tail lags then snaps back behind the head
+    assert_eq!(
+        "== R 5 ==\n\nT..H..  (T covers s)\n\ns..TH.\n\ns..T.H\n",
+        frames_render(
+            &motions_parse("R 5").unwrap(),
+            Rope::with_rule(2, follow_rule_get("stretch:3").unwrap()),
+            3..6,
+            true,
+            None
+        )
+    );
 }
 
 /*
@@ -370,3 +1120,440 @@ So, there are 13 positions the tail visited at least once.
 
 Simulate your complete hypothetical series of motions. How many positions does the tail of the rope visit at least once?
 */
+
+/*
+--- Part Two ---
+
+A rope snaps! Suddenly, the river is getting a lot closer than you remember. The bridge is still there, but some of the ropes that broke are now whipping toward you as you fall through the air!
+
+The ropes are moving too quickly to grab; you only have a few seconds to choose how to arch your body to avoid being hit. Fortunately, your simulation can be extended to support longer ropes.
+
+Rather than two knots, you now must simulate a rope consisting of ten knots. One knot is still the head of the rope and moves according to the series of motions. Each knot further down the rope follows the knot in front of it using the same rules as before.
+
+Using the same series of motions as the above example, but with the knots marked H, 1, 2, ..., 9, the motions now occur as follows:
+
+== Initial State ==
+
+......
+......
+......
+......
+H.....  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
+
+== R 4 ==
+
+......
+......
+......
+......
+1H....  (1 covers 2, 3, 4, 5, 6, 7, 8, 9, s)
+
+......
+......
+......
+......
+21H...  (2 covers 3, 4, 5, 6, 7, 8, 9, s)
+
+......
+......
+......
+......
+321H..  (3 covers 4, 5, 6, 7, 8, 9, s)
+
+......
+......
+......
+......
+4321H.  (4 covers 5, 6, 7, 8, 9, s)
+
+== U 4 ==
+
+......
+......
+......
+....H.
+4321..  (4 covers 5, 6, 7, 8, 9, s)
+
+......
+......
+....H.
+.4321.
+5.....  (5 covers 6, 7, 8, 9, s)
+
+......
+....H.
+....1.
+.432..
+5.....  (5 covers 6, 7, 8, 9, s)
+
+....H.
+....1.
+..432.
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+== L 3 ==
+
+...H..
+....1.
+..432.
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+..H1..
+...2..
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+.H1...
+...2..
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+== D 1 ==
+
+..1...
+.H.2..
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+== R 4 ==
+
+..1...
+..H2..
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+..1...
+...H..  (H covers 2)
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+...1H.  (1 covers 2)
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+...21H
+..43..
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+== D 1 ==
+
+......
+...21.
+..43.H
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+== L 5 ==
+
+......
+...21.
+..43H.
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+...21.
+..4H..  (H covers 3)
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+...2..
+..H1..  (H covers 4; 1 covers 3)
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+...2..
+.H13..  (1 covers 4)
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+......
+H123..  (2 covers 4)
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+== R 2 ==
+
+......
+......
+.H23..  (H covers 1; 2 covers 4)
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+......
+......
+.1H3..  (H covers 2, 4)
+.5....
+6.....  (6 covers 7, 8, 9, s)
+
+Now, you need to keep track of the positions the new tail, 9, visits. In this example, the tail never moves, and so it only visits 1 position. However, be careful: more types of motion are possible than before, so you might want to visually compare your simulated rope to the one above.
+
+Here's a larger example:
+
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+
+These motions occur as follows (individual steps are not shown):
+
+== Initial State ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+...........H..............  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
+..........................
+..........................
+..........................
+..........................
+..........................
+
+== R 5 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+...........54321H.........  (5 covers 6, 7, 8, 9, s)
+..........................
+..........................
+..........................
+..........................
+..........................
+
+== U 8 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+................H.........
+................1.........
+................2.........
+................3.........
+...............54.........
+..............6...........
+.............7............
+............8.............
+...........9..............  (9 covers s)
+..........................
+..........................
+..........................
+..........................
+..........................
+
+== L 8 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+........H1234.............
+............5.............
+............6.............
+............7.............
+............8.............
+............9.............
+..........................
+..........................
+...........s..............
+..........................
+..........................
+..........................
+..........................
+..........................
+
+== D 3 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+.........2345.............
+........1...6.............
+........H...7.............
+............8.............
+............9.............
+..........................
+..........................
+...........s..............
+..........................
+..........................
+..........................
+..........................
+..........................
+
+== R 17 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+................987654321H
+..........................
+..........................
+..........................
+..........................
+...........s..............
+..........................
+..........................
+..........................
+..........................
+..........................
+
+== D 10 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+...........s.........98765
+.........................4
+.........................3
+.........................2
+.........................1
+.........................H
+
+== L 25 ==
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+...........s..............
+..........................
+..........................
+..........................
+..........................
+H123456789................
+
+== U 20 ==
+
+H.........................
+1.........................
+2.........................
+3.........................
+4.........................
+5.........................
+6.........................
+7.........................
+8.........................
+9.........................
+..........................
+..........................
+..........................
+..........................
+..........................
+...........s..............
+..........................
+..........................
+..........................
+..........................
+..........................
+
+Now, the tail (9) visits 36 positions (including s) at least once:
+
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+#.........................
+#.............###.........
+#............#...#........
+.#..........#.....#.......
+..#..........#.....#......
+...#........#.......#.....
+....#......s.........#....
+.....#..............#.....
+......#............#......
+.......#..........#.......
+........#........#........
+.........########.........
+
+Simulate your complete series of motions on a larger rope with ten knots. How many positions does the tail of the rope visit at least once?
+*/
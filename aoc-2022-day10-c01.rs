@@ -1,75 +1,303 @@
 // https://adventofcode.com/2022/day/10
 // (part 1)
 
-const COMMAND_ADD: &str = "addx ";
-const CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+const REGISTERS_COUNT: usize = 4;
+// x is the register of the puzzle, the others start at 0
+const REGISTERS_NAMES: [&str; REGISTERS_COUNT] = ["x", "y", "z", "w"];
 const X_START_VALUE: i64 = 1;
+// a program jumping back forever never halts
+const CYCLES_MAX: usize = 10_000_000;
 
-fn number_parse(number: &str) -> (i64, usize) {
-    let mut digit_count: usize = 0;
-    let negative: bool = number.chars().nth(0).unwrap() == '-';
-    let mut chars = number.chars();
-    if negative {
-        digit_count += 1;
-        chars.next();
-    }
-    for c in chars {
-        if c < '0' || c > '9' {
-            break;
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OperandKind {
+    Value,
+    Register,
+}
+
+// what the program counter does after an instruction
+enum Flow {
+    Next,
+    Jump(i64),
+}
+
+struct Instruction {
+    name: &'static str,
+    operands: &'static [OperandKind],
+    cycles: usize,
+    // applied at the end of the last cycle, register operands being given as indices, none when a
+    // register would overflow
+    effect: fn(&mut [i64; REGISTERS_COUNT], &[i64]) -> Option<Flow>,
+}
+
+// new instructions only need an entry here
+static INSTRUCTIONS: [Instruction; 6] = [
+    Instruction {
+        name: "noop",
+        operands: &[],
+        cycles: 1,
+        effect: |_, _| Some(Flow::Next),
+    },
+    Instruction {
+        name: "addx",
+        operands: &[OperandKind::Value],
+        cycles: 2,
+        effect: |registers, operands| {
+            registers[0] = registers[0].checked_add(operands[0])?;
+            return Some(Flow::Next);
+        },
+    },
+    Instruction {
+        name: "subx",
+        operands: &[OperandKind::Value],
+        cycles: 2,
+        effect: |registers, operands| {
+            registers[0] = registers[0].checked_sub(operands[0])?;
+            return Some(Flow::Next);
+        },
+    },
+    Instruction {
+        name: "mulx",
+        operands: &[OperandKind::Value],
+        cycles: 4,
+        effect: |registers, operands| {
+            registers[0] = registers[0].checked_mul(operands[0])?;
+            return Some(Flow::Next);
+        },
+    },
+    // adds a value to any register
+    Instruction {
+        name: "add",
+        operands: &[OperandKind::Register, OperandKind::Value],
+        cycles: 2,
+        effect: |registers, operands| {
+            let register: &mut i64 = &mut registers[operands[0] as usize];
+            *register = register.checked_add(operands[1])?;
+            return Some(Flow::Next);
+        },
+    },
+    // relative to the jump instruction
+    Instruction {
+        name: "jmp",
+        operands: &[OperandKind::Value],
+        cycles: 1,
+        effect: |_, operands| Some(Flow::Jump(operands[0])),
+    },
+];
+
+// instruction and operand values of every line
+type Program = Vec<(&'static Instruction, Vec<i64>)>;
+
+fn program_parse(input: &str) -> Result<Program, String> {
+    let mut program: Program = Vec::new();
+    for (i, line) in input.split('\n').enumerate() {
+        let mut words = line.split_whitespace();
+        let name: &str = match words.next() {
+            Some(name) => name,
+            None => continue,
+        };
+        let instruction: &Instruction = INSTRUCTIONS
+            .iter()
+            .find(|instruction| instruction.name == name)
+            .ok_or_else(|| format!("line {}: unknown instruction '{}'", i + 1, name))?;
+        let words: Vec<&str> = words.collect();
+        if words.len() != instruction.operands.len() {
+            return Err(format!(
+                "line {}: {} expects {} operands, not {}",
+                i + 1,
+                name,
+                instruction.operands.len(),
+                words.len()
+            ));
         }
-        digit_count += 1;
+        let mut operands: Vec<i64> = Vec::new();
+        for (word, kind) in words.iter().zip(instruction.operands) {
+            let operand: Option<i64> = match kind {
+                OperandKind::Value => word.parse::<i64>().ok(),
+                OperandKind::Register => REGISTERS_NAMES
+                    .iter()
+                    .position(|register| register == word)
+                    .map(|register| register as i64),
+            };
+            match operand {
+                Some(operand) => operands.push(operand),
+                None => {
+                    return Err(format!(
+                        "line {}: invalid {} '{}'",
+                        i + 1,
+                        match kind {
+                            OperandKind::Value => "value",
+                            OperandKind::Register => "register",
+                        },
+                        word
+                    ))
+                }
+            }
+        }
+        program.push((instruction, operands));
     }
-    let n = number.get(0..digit_count).unwrap().parse::<i64>().unwrap();
+    return Ok(program);
+}
 
-    return (n, digit_count);
+struct Cpu<'a> {
+    registers: [i64; REGISTERS_COUNT],
+    // index of the running instruction
+    pc: usize,
+    // cycles the running instruction has already taken
+    busy: usize,
+    // cycles completed
+    cycle: usize,
+    program: &'a Program,
 }
 
-fn signal_strengths(input: &str) -> i64 {
-    let mut strengths: [i64; CYCLES.len()] = [0; CYCLES.len()];
-
-    let commands: Vec<&str> = input.split('\n').collect();
-    let mut x: i64 = X_START_VALUE;
-    let mut strength_index: usize = 0;
-    let mut cycles_count: usize = 0;
-    for command in commands {
-        let mut value: Option<i64> = Option::None;
-        if command == "noop" {
-            cycles_count += 1;
-        } else if command.starts_with(COMMAND_ADD) {
-            let (add_value, chars_read_count) =
-                number_parse(command.get(COMMAND_ADD.len()..).unwrap());
-            assert!(command.len() == chars_read_count + COMMAND_ADD.len());
-            cycles_count += 2;
-            value = Some(add_value);
-        } else {
-            assert!(false);
+impl<'a> Cpu<'a> {
+    fn new(program: &'a Program) -> Cpu<'a> {
+        let mut registers: [i64; REGISTERS_COUNT] = [0; REGISTERS_COUNT];
+        registers[0] = X_START_VALUE;
+        return Cpu {
+            registers: registers,
+            pc: 0,
+            busy: 0,
+            cycle: 0,
+            program: program,
+        };
+    }
+
+    fn x(&self) -> i64 {
+        return self.registers[0];
+    }
+
+    fn halted(&self) -> bool {
+        return self.pc >= self.program.len();
+    }
+
+    // runs one cycle, the running instruction taking effect at the end of its last one
+    fn step(&mut self) -> Result<(), String> {
+        if self.halted() {
+            return Ok(());
         }
-        if cycles_count >= CYCLES[strength_index] {
-            strengths[strength_index] = x * CYCLES[strength_index] as i64;
-            strength_index += 1;
-            if strength_index == CYCLES.len() {
-                break;
+        self.cycle += 1;
+        self.busy += 1;
+        let (instruction, operands) = &self.program[self.pc];
+        if self.busy < instruction.cycles {
+            return Ok(());
+        }
+        self.busy = 0;
+        let flow: Flow = (instruction.effect)(&mut self.registers, operands).ok_or_else(|| {
+            format!(
+                "cycle {}: {} overflows a register",
+                self.cycle, instruction.name
+            )
+        })?;
+        match flow {
+            Flow::Next => self.pc += 1,
+            Flow::Jump(offset) => {
+                // jumping before the start halts as well as jumping after the end
+                self.pc = match (self.pc as i64).checked_add(offset) {
+                    Some(pc) if pc >= 0 => pc as usize,
+                    _ => usize::MAX,
+                };
             }
         }
-        if value.is_some() {
-            x += value.unwrap();
+        return Ok(());
+    }
+
+    // `on_cycle` sees the number and the state of every cycle while it runs, until the program
+    // halts
+    fn run(&mut self, on_cycle: &mut dyn FnMut(usize, &Cpu)) -> Result<(), String> {
+        while !self.halted() {
+            if self.cycle == CYCLES_MAX {
+                return Err(format!("still running after {} cycles", CYCLES_MAX));
+            }
+            on_cycle(self.cycle + 1, self);
+            self.step()?;
         }
+        return Ok(());
     }
+}
+
+const CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
 
+fn signal_strengths(input: &str) -> Result<i64, String> {
+    let program: Program = program_parse(input)?;
     let mut strengths_sum: i64 = 0;
-    for strength in &strengths {
-        strengths_sum += strength;
-    }
-    return strengths_sum;
+    Cpu::new(&program).run(&mut |cycle, cpu| {
+        if CYCLES.contains(&cycle) {
+            strengths_sum += cycle as i64 * cpu.x();
+        }
+    })?;
+    return Ok(strengths_sum);
+}
+
+// x during every cycle and once halted
+fn x_values(input: &str) -> Result<(Vec<i64>, i64), String> {
+    let program: Program = program_parse(input)?;
+    let mut cpu: Cpu = Cpu::new(&program);
+    let mut values: Vec<i64> = Vec::new();
+    cpu.run(&mut |_, cpu| values.push(cpu.x()))?;
+    return Ok((values, cpu.x()));
 }
 
 fn main() {
     // example
-    assert_eq!(13140, signal_strengths("addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop"));
+    assert_eq!(Ok(13140), signal_strengths("addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop"));
 
     assert_eq!(
-        14320,
+        Ok(14320),
         signal_strengths("noop\nnoop\nnoop\naddx 6\nnoop\naddx 30\naddx -26\nnoop\naddx 5\nnoop\nnoop\nnoop\nnoop\naddx 5\naddx -5\naddx 6\naddx 5\naddx -1\naddx 5\nnoop\nnoop\naddx -14\naddx -18\naddx 39\naddx -39\naddx 25\naddx -22\naddx 2\naddx 5\naddx 2\naddx 3\naddx -2\naddx 2\nnoop\naddx 3\naddx 2\naddx 2\nnoop\naddx 3\nnoop\naddx 3\naddx 2\naddx 5\naddx 4\naddx -18\naddx 17\naddx -38\naddx 5\naddx 2\naddx -5\naddx 27\naddx -19\nnoop\naddx 3\naddx 4\nnoop\nnoop\naddx 5\naddx -1\nnoop\nnoop\naddx 4\naddx 5\naddx 2\naddx -4\naddx 5\nnoop\naddx -11\naddx 16\naddx -36\nnoop\naddx 5\nnoop\naddx 28\naddx -23\nnoop\nnoop\nnoop\naddx 21\naddx -18\nnoop\naddx 3\naddx 2\naddx 2\naddx 5\naddx 1\nnoop\nnoop\naddx 4\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 8\naddx -40\nnoop\naddx 7\nnoop\naddx -2\naddx 5\naddx 2\naddx 25\naddx -31\naddx 9\naddx 5\naddx 2\naddx 2\naddx 3\naddx -2\nnoop\naddx 3\naddx 2\nnoop\naddx 7\naddx -2\naddx 5\naddx -40\naddx 20\naddx -12\nnoop\nnoop\nnoop\naddx -5\naddx 7\naddx 7\nnoop\naddx -1\naddx 1\naddx 5\naddx 3\naddx -2\naddx 2\nnoop\naddx 3\naddx 2\nnoop\nnoop\nnoop\nnoop\naddx 7\nnoop\nnoop\nnoop\nnoop"));
+    // small example, cycle by cycle
+    assert_eq!(
+        Ok((vec![1, 1, 1, 4, 4], -1)),
+        x_values("noop\naddx 3\naddx -5")
+    );
+    assert_eq!(Ok((vec![], 1)), x_values(""));
+    assert_eq!(Ok((vec![1, 1, 3, 3], -2)), x_values("addx 2\nsubx 5\n"));
+    assert_eq!(
+        Ok((vec![1, 1, 3, 3, 3, 3, 12], 12)),
+        x_values("addx 2\nmulx 4\nnoop")
+    );
+    // other registers are only seen through x
+    assert_eq!(Ok((vec![1, 1, 1], 1)), x_values("add y 7\nnoop"));
+    assert_eq!(Ok((vec![1, 1, 5], 5)), x_values("add x 4\nnoop"));
+    // jumps skip lines, loop back or halt before the start
+    assert_eq!(Ok((vec![1, 1, 1], 3)), x_values("jmp 2\naddx 5\naddx 2"));
+    assert_eq!(Ok((vec![1, 1], 1)), x_values("noop\njmp -2\nnoop"));
+    assert_eq!(
+        Err(String::from("still running after 10000000 cycles")),
+        x_values("addx 1\njmp -1")
+    );
+    // registers overflowing stop the program instead of wrapping
+    assert_eq!(
+        Err(String::from("cycle 201: mulx overflows a register")),
+        x_values("addx 1\nmulx 3\njmp -1")
+    );
+    assert_eq!(
+        Err(String::from("cycle 4: subx overflows a register")),
+        x_values("addx -9223372036854775807\nsubx 3")
+    );
+    assert_eq!(
+        Err(String::from("cycle 4: add overflows a register")),
+        x_values("add w 9223372036854775807\nadd w 1")
+    );
+    assert_eq!(
+        Err(String::from("line 2: unknown instruction 'divx'")),
+        x_values("noop\ndivx 2")
+    );
+    assert_eq!(
+        Err(String::from("line 1: addx expects 1 operands, not 0")),
+        x_values("addx")
+    );
+    assert_eq!(
+        Err(String::from("line 1: noop expects 0 operands, not 1")),
+        signal_strengths("noop 1")
+    );
+    assert_eq!(
+        Err(String::from("line 3: invalid value '1x'")),
+        x_values("noop\n\naddx 1x")
+    );
+    assert_eq!(
+        Err(String::from("line 1: invalid register 'v'")),
+        x_values("add v 1")
+    );
 }
 
 /*
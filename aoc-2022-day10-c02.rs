@@ -1,82 +1,313 @@
 // https://adventofcode.com/2022/day/10
 // (part 2)
 
-const COMMAND_ADD: &str = "addx ";
+const REGISTERS_COUNT: usize = 4;
+// x is the register of the puzzle, the others start at 0
+const REGISTERS_NAMES: [&str; REGISTERS_COUNT] = ["x", "y", "z", "w"];
 const X_START_VALUE: i64 = 1;
-const CRT_COLUMNS_COUNT: usize = 40;
-const CRT_ROWS_COUNT: usize = 6;
-const PIXEL_LIT: u8 = '#' as u8;
-const PIXEL_DARK: u8 = ' ' as u8;
+// a program jumping back forever never halts
+const CYCLES_MAX: usize = 10_000_000;
 
-fn number_parse(number: &str) -> (i64, usize) {
-    let mut digit_count: usize = 0;
-    let negative: bool = number.chars().nth(0).unwrap() == '-';
-    let mut chars = number.chars();
-    if negative {
-        digit_count += 1;
-        chars.next();
-    }
-    for c in chars {
-        if c < '0' || c > '9' {
-            break;
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OperandKind {
+    Value,
+    Register,
+}
+
+// what the program counter does after an instruction
+enum Flow {
+    Next,
+    Jump(i64),
+}
+
+struct Instruction {
+    name: &'static str,
+    operands: &'static [OperandKind],
+    cycles: usize,
+    // applied at the end of the last cycle, register operands being given as indices, none when a
+    // register would overflow
+    effect: fn(&mut [i64; REGISTERS_COUNT], &[i64]) -> Option<Flow>,
+}
+
+// new instructions only need an entry here
+static INSTRUCTIONS: [Instruction; 6] = [
+    Instruction {
+        name: "noop",
+        operands: &[],
+        cycles: 1,
+        effect: |_, _| Some(Flow::Next),
+    },
+    Instruction {
+        name: "addx",
+        operands: &[OperandKind::Value],
+        cycles: 2,
+        effect: |registers, operands| {
+            registers[0] = registers[0].checked_add(operands[0])?;
+            return Some(Flow::Next);
+        },
+    },
+    Instruction {
+        name: "subx",
+        operands: &[OperandKind::Value],
+        cycles: 2,
+        effect: |registers, operands| {
+            registers[0] = registers[0].checked_sub(operands[0])?;
+            return Some(Flow::Next);
+        },
+    },
+    Instruction {
+        name: "mulx",
+        operands: &[OperandKind::Value],
+        cycles: 4,
+        effect: |registers, operands| {
+            registers[0] = registers[0].checked_mul(operands[0])?;
+            return Some(Flow::Next);
+        },
+    },
+    // adds a value to any register
+    Instruction {
+        name: "add",
+        operands: &[OperandKind::Register, OperandKind::Value],
+        cycles: 2,
+        effect: |registers, operands| {
+            let register: &mut i64 = &mut registers[operands[0] as usize];
+            *register = register.checked_add(operands[1])?;
+            return Some(Flow::Next);
+        },
+    },
+    // relative to the jump instruction
+    Instruction {
+        name: "jmp",
+        operands: &[OperandKind::Value],
+        cycles: 1,
+        effect: |_, operands| Some(Flow::Jump(operands[0])),
+    },
+];
+
+// instruction and operand values of every line
+type Program = Vec<(&'static Instruction, Vec<i64>)>;
+
+fn program_parse(input: &str) -> Result<Program, String> {
+    let mut program: Program = Vec::new();
+    for (i, line) in input.split('\n').enumerate() {
+        let mut words = line.split_whitespace();
+        let name: &str = match words.next() {
+            Some(name) => name,
+            None => continue,
+        };
+        let instruction: &Instruction = INSTRUCTIONS
+            .iter()
+            .find(|instruction| instruction.name == name)
+            .ok_or_else(|| format!("line {}: unknown instruction '{}'", i + 1, name))?;
+        let words: Vec<&str> = words.collect();
+        if words.len() != instruction.operands.len() {
+            return Err(format!(
+                "line {}: {} expects {} operands, not {}",
+                i + 1,
+                name,
+                instruction.operands.len(),
+                words.len()
+            ));
+        }
+        let mut operands: Vec<i64> = Vec::new();
+        for (word, kind) in words.iter().zip(instruction.operands) {
+            let operand: Option<i64> = match kind {
+                OperandKind::Value => word.parse::<i64>().ok(),
+                OperandKind::Register => REGISTERS_NAMES
+                    .iter()
+                    .position(|register| register == word)
+                    .map(|register| register as i64),
+            };
+            match operand {
+                Some(operand) => operands.push(operand),
+                None => {
+                    return Err(format!(
+                        "line {}: invalid {} '{}'",
+                        i + 1,
+                        match kind {
+                            OperandKind::Value => "value",
+                            OperandKind::Register => "register",
+                        },
+                        word
+                    ))
+                }
+            }
         }
-        digit_count += 1;
+        program.push((instruction, operands));
     }
-    let n = number.get(0..digit_count).unwrap().parse::<i64>().unwrap();
+    return Ok(program);
+}
 
-    return (n, digit_count);
+struct Cpu<'a> {
+    registers: [i64; REGISTERS_COUNT],
+    // index of the running instruction
+    pc: usize,
+    // cycles the running instruction has already taken
+    busy: usize,
+    // cycles completed
+    cycle: usize,
+    program: &'a Program,
 }
 
-fn draw(input: &str) {
-    let mut crt: [u8; CRT_COLUMNS_COUNT * CRT_ROWS_COUNT] =
-        [PIXEL_DARK; CRT_COLUMNS_COUNT * CRT_ROWS_COUNT];
+impl<'a> Cpu<'a> {
+    fn new(program: &'a Program) -> Cpu<'a> {
+        let mut registers: [i64; REGISTERS_COUNT] = [0; REGISTERS_COUNT];
+        registers[0] = X_START_VALUE;
+        return Cpu {
+            registers: registers,
+            pc: 0,
+            busy: 0,
+            cycle: 0,
+            program: program,
+        };
+    }
 
-    let commands: Vec<&str> = input.split('\n').collect();
-    let mut x: i64 = X_START_VALUE;
-    let mut cycle_index: usize = 0;
-    for command in commands {
-        let mut value: Option<i64> = Option::None;
-        let mut command_cycles_count: usize = 0;
-        if command == "noop" {
-            command_cycles_count = 1;
-        } else if command.starts_with(COMMAND_ADD) {
-            let (add_value, chars_read_count) =
-                number_parse(command.get(COMMAND_ADD.len()..).unwrap());
-            assert!(command.len() == chars_read_count + COMMAND_ADD.len());
-            command_cycles_count = 2;
-            value = Some(add_value);
-        } else {
-            assert!(false);
-        }
+    fn x(&self) -> i64 {
+        return self.registers[0];
+    }
 
-        for _ in 0..command_cycles_count {
-            let col_index: i64 = (cycle_index % CRT_COLUMNS_COUNT) as i64;
-            let pixel: u8 = if col_index >= x - 1 && col_index as i64 <= x + 1 {
-                PIXEL_LIT
-            } else {
-                PIXEL_DARK
-            };
-            crt[cycle_index] = pixel;
-            cycle_index += 1;
+    fn halted(&self) -> bool {
+        return self.pc >= self.program.len();
+    }
+
+    // runs one cycle, the running instruction taking effect at the end of its last one
+    fn step(&mut self) -> Result<(), String> {
+        if self.halted() {
+            return Ok(());
+        }
+        self.cycle += 1;
+        self.busy += 1;
+        let (instruction, operands) = &self.program[self.pc];
+        if self.busy < instruction.cycles {
+            return Ok(());
+        }
+        self.busy = 0;
+        let flow: Flow = (instruction.effect)(&mut self.registers, operands).ok_or_else(|| {
+            format!(
+                "cycle {}: {} overflows a register",
+                self.cycle, instruction.name
+            )
+        })?;
+        match flow {
+            Flow::Next => self.pc += 1,
+            Flow::Jump(offset) => {
+                // jumping before the start halts as well as jumping after the end
+                self.pc = match (self.pc as i64).checked_add(offset) {
+                    Some(pc) if pc >= 0 => pc as usize,
+                    _ => usize::MAX,
+                };
+            }
         }
+        return Ok(());
+    }
 
-        if value.is_some() {
-            x += value.unwrap();
+    // `on_cycle` sees the number and the state of every cycle while it runs, until the program
+    // halts
+    fn run(&mut self, on_cycle: &mut dyn FnMut(usize, &Cpu)) -> Result<(), String> {
+        while !self.halted() {
+            if self.cycle == CYCLES_MAX {
+                return Err(format!("still running after {} cycles", CYCLES_MAX));
+            }
+            on_cycle(self.cycle + 1, self);
+            self.step()?;
         }
+        return Ok(());
     }
+}
+
+const CRT_COLUMNS_COUNT: usize = 40;
+const CRT_ROWS_COUNT: usize = 6;
+const PIXEL_LIT: u8 = '#' as u8;
+const PIXEL_DARK: u8 = ' ' as u8;
+
+// rows of the screen, a program running longer than the screen is not drawn past its end
+fn draw(input: &str) -> Result<String, String> {
+    let mut crt: [u8; CRT_COLUMNS_COUNT * CRT_ROWS_COUNT] =
+        [PIXEL_DARK; CRT_COLUMNS_COUNT * CRT_ROWS_COUNT];
+
+    let program: Program = program_parse(input)?;
+    Cpu::new(&program).run(&mut |cycle, cpu| {
+        let pixel_index: usize = cycle - 1;
+        if pixel_index >= crt.len() {
+            return;
+        }
+        let col_index: i64 = (pixel_index % CRT_COLUMNS_COUNT) as i64;
+        if cpu.x().abs_diff(col_index) <= 1 {
+            crt[pixel_index] = PIXEL_LIT;
+        }
+    })?;
 
+    let mut screen: String = String::new();
     for i in 0..CRT_ROWS_COUNT {
         let row =
             std::str::from_utf8(&crt[i * CRT_COLUMNS_COUNT..(i + 1) * CRT_COLUMNS_COUNT]).unwrap();
-        print!("{}\n", row);
+        screen += &format!("{}\n", row);
     }
+    return Ok(screen);
 }
 
 fn main() {
     // example
-    draw("addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop");
+    let screen: String = draw("addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop").unwrap();
+    print!("{}", screen);
+    assert_eq!(
+        concat!(
+            "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  \n",
+            "###   ###   ###   ###   ###   ###   ### \n",
+            "####    ####    ####    ####    ####    \n",
+            "#####     #####     #####     #####     \n",
+            "######      ######      ######      ####\n",
+            "#######       #######       #######     \n",
+        ),
+        screen
+    );
     print!("\n");
-    draw("noop\nnoop\nnoop\naddx 6\nnoop\naddx 30\naddx -26\nnoop\naddx 5\nnoop\nnoop\nnoop\nnoop\naddx 5\naddx -5\naddx 6\naddx 5\naddx -1\naddx 5\nnoop\nnoop\naddx -14\naddx -18\naddx 39\naddx -39\naddx 25\naddx -22\naddx 2\naddx 5\naddx 2\naddx 3\naddx -2\naddx 2\nnoop\naddx 3\naddx 2\naddx 2\nnoop\naddx 3\nnoop\naddx 3\naddx 2\naddx 5\naddx 4\naddx -18\naddx 17\naddx -38\naddx 5\naddx 2\naddx -5\naddx 27\naddx -19\nnoop\naddx 3\naddx 4\nnoop\nnoop\naddx 5\naddx -1\nnoop\nnoop\naddx 4\naddx 5\naddx 2\naddx -4\naddx 5\nnoop\naddx -11\naddx 16\naddx -36\nnoop\naddx 5\nnoop\naddx 28\naddx -23\nnoop\nnoop\nnoop\naddx 21\naddx -18\nnoop\naddx 3\naddx 2\naddx 2\naddx 5\naddx 1\nnoop\nnoop\naddx 4\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 8\naddx -40\nnoop\naddx 7\nnoop\naddx -2\naddx 5\naddx 2\naddx 25\naddx -31\naddx 9\naddx 5\naddx 2\naddx 2\naddx 3\naddx -2\nnoop\naddx 3\naddx 2\nnoop\naddx 7\naddx -2\naddx 5\naddx -40\naddx 20\naddx -12\nnoop\nnoop\nnoop\naddx -5\naddx 7\naddx 7\nnoop\naddx -1\naddx 1\naddx 5\naddx 3\naddx -2\naddx 2\nnoop\naddx 3\naddx 2\nnoop\nnoop\nnoop\nnoop\naddx 7\nnoop\nnoop\nnoop\nnoop");
+    let screen: String = draw("noop\nnoop\nnoop\naddx 6\nnoop\naddx 30\naddx -26\nnoop\naddx 5\nnoop\nnoop\nnoop\nnoop\naddx 5\naddx -5\naddx 6\naddx 5\naddx -1\naddx 5\nnoop\nnoop\naddx -14\naddx -18\naddx 39\naddx -39\naddx 25\naddx -22\naddx 2\naddx 5\naddx 2\naddx 3\naddx -2\naddx 2\nnoop\naddx 3\naddx 2\naddx 2\nnoop\naddx 3\nnoop\naddx 3\naddx 2\naddx 5\naddx 4\naddx -18\naddx 17\naddx -38\naddx 5\naddx 2\naddx -5\naddx 27\naddx -19\nnoop\naddx 3\naddx 4\nnoop\nnoop\naddx 5\naddx -1\nnoop\nnoop\naddx 4\naddx 5\naddx 2\naddx -4\naddx 5\nnoop\naddx -11\naddx 16\naddx -36\nnoop\naddx 5\nnoop\naddx 28\naddx -23\nnoop\nnoop\nnoop\naddx 21\naddx -18\nnoop\naddx 3\naddx 2\naddx 2\naddx 5\naddx 1\nnoop\nnoop\naddx 4\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 8\naddx -40\nnoop\naddx 7\nnoop\naddx -2\naddx 5\naddx 2\naddx 25\naddx -31\naddx 9\naddx 5\naddx 2\naddx 2\naddx 3\naddx -2\nnoop\naddx 3\naddx 2\nnoop\naddx 7\naddx -2\naddx 5\naddx -40\naddx 20\naddx -12\nnoop\nnoop\nnoop\naddx -5\naddx 7\naddx 7\nnoop\naddx -1\naddx 1\naddx 5\naddx 3\naddx -2\naddx 2\nnoop\naddx 3\naddx 2\nnoop\nnoop\nnoop\nnoop\naddx 7\nnoop\nnoop\nnoop\nnoop").unwrap();
+    print!("{}", screen);
+    assert_eq!(
+        concat!(
+            "###   ##  ###  ###  #  #  ##  ###    ## \n",
+            "#  # #  # #  # #  # # #  #  # #  #    # \n",
+            "#  # #    #  # ###  ##   #  # #  #    # \n",
+            "###  #    ###  #  # # #  #### ###     # \n",
+            "#    #  # #    #  # # #  #  # #    #  # \n",
+            "#     ##  #    ###  #  # #  # #     ##  \n",
+        ),
+        screen
+    );
+
+    // the sprite follows x whatever the instruction changing it
+    assert_eq!(
+        Some("###  #"),
+        draw("noop\nmulx 4\nnoop")
+            .unwrap()
+            .lines()
+            .next()
+            .map(|row| row.trim_end())
+    );
+    // cycles past the end of the screen are not drawn
+    assert_eq!(
+        Ok(6),
+        draw(&"addx 1\n".repeat(150)).map(|screen| screen.lines().count())
+    );
+    assert_eq!(
+        Err(String::from("line 1: unknown instruction 'addy'")),
+        draw("addy 1")
+    );
+    assert_eq!(
+        Err(String::from("still running after 10000000 cycles")),
+        draw("jmp 0")
+    );
+    assert_eq!(
+        Err(String::from("cycle 201: mulx overflows a register")),
+        draw("addx 1\nmulx 3\njmp -1")
+    );
+    // the sprite can be at the ends of x
+    assert_eq!(
+        Ok(String::from(" ")),
+        draw("addx 9223372036854775806\nnoop")
+            .map(|screen| screen.lines().next().unwrap()[2..3].to_string())
+    );
 }
 
 /*